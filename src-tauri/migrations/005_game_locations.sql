@@ -0,0 +1,17 @@
+-- Normalized location index per game (rebuilt on every import)
+CREATE TABLE IF NOT EXISTS game_locations (
+    game_id TEXT NOT NULL,
+    location_key TEXT NOT NULL,
+    name TEXT NOT NULL,
+    pokemon_count INTEGER NOT NULL DEFAULT 0,
+    item_count INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (game_id, location_key),
+    FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+);
+
+-- Link raw location strings to their normalized location
+ALTER TABLE game_pokemon_locations ADD COLUMN location_key TEXT;
+ALTER TABLE game_item_locations ADD COLUMN location_key TEXT;
+
+CREATE INDEX IF NOT EXISTS idx_gpl_location ON game_pokemon_locations(game_id, location_key);
+CREATE INDEX IF NOT EXISTS idx_gil_location ON game_item_locations(game_id, location_key);
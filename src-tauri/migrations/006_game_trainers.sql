@@ -0,0 +1,42 @@
+-- Trainer battles per game (trainer_id is the position in progression order)
+CREATE TABLE IF NOT EXISTS game_trainers (
+    game_id TEXT NOT NULL,
+    trainer_id INTEGER NOT NULL,
+    name TEXT NOT NULL,
+    trainer_class TEXT,
+    location TEXT,
+    location_key TEXT,
+    battle_type TEXT NOT NULL DEFAULT 'single',
+    level_cap INTEGER,
+    PRIMARY KEY (game_id, trainer_id),
+    FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+);
+
+-- Pokemon on a trainer's team (IVs/EVs stored as JSON stat spreads)
+CREATE TABLE IF NOT EXISTS game_trainer_pokemon (
+    game_id TEXT NOT NULL,
+    trainer_id INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    pokemon_name_key TEXT NOT NULL,
+    level INTEGER NOT NULL,
+    ability_key TEXT,
+    item_key TEXT,
+    nature_key TEXT,
+    ivs TEXT,
+    evs TEXT,
+    PRIMARY KEY (game_id, trainer_id, slot),
+    FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+);
+
+-- Moves of a trainer's pokemon
+CREATE TABLE IF NOT EXISTS game_trainer_pokemon_moves (
+    game_id TEXT NOT NULL,
+    trainer_id INTEGER NOT NULL,
+    slot INTEGER NOT NULL,
+    move_slot INTEGER NOT NULL,
+    move_name_key TEXT NOT NULL,
+    PRIMARY KEY (game_id, trainer_id, slot, move_slot),
+    FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_gt_location ON game_trainers(game_id, location_key);
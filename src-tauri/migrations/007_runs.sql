@@ -0,0 +1,30 @@
+-- Nuzlocke runs, each bound to a game
+CREATE TABLE IF NOT EXISTS runs (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    game_id TEXT NOT NULL,
+    name TEXT NOT NULL,
+    dupes_clause INTEGER NOT NULL DEFAULT 1,
+    shiny_clause INTEGER NOT NULL DEFAULT 1,
+    level_caps INTEGER NOT NULL DEFAULT 0,
+    last_trainer_id INTEGER NOT NULL DEFAULT 0,
+    created_at TEXT NOT NULL,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+);
+
+-- Encounters per run and location (status: caught, failed, skipped, dead, boxed)
+CREATE TABLE IF NOT EXISTS run_encounters (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    run_id INTEGER NOT NULL,
+    location_key TEXT NOT NULL,
+    pokemon_name_key TEXT,
+    nickname TEXT,
+    status TEXT NOT NULL,
+    is_shiny INTEGER NOT NULL DEFAULT 0,
+    notes TEXT,
+    updated_at TEXT NOT NULL,
+    FOREIGN KEY (run_id) REFERENCES runs(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_runs_game ON runs(game_id);
+CREATE INDEX IF NOT EXISTS idx_run_encounters_run ON run_encounters(run_id, location_key);
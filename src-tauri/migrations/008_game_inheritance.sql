@@ -0,0 +1,2 @@
+-- Game inheritance: a game falls back to another game or an official version group
+ALTER TABLE games ADD COLUMN extends TEXT;
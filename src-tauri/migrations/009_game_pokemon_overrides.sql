@@ -0,0 +1,9 @@
+-- Pokemon listed in a game data file, in file order (keeps entries with no
+-- learnset/abilities/locations and the evolution method, for export)
+CREATE TABLE IF NOT EXISTS game_pokemon_overrides (
+    game_id TEXT NOT NULL,
+    pokemon_name_key TEXT NOT NULL,
+    evolution_method TEXT,
+    PRIMARY KEY (game_id, pokemon_name_key),
+    FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+);
@@ -0,0 +1,15 @@
+-- In-app edits of game data, replayed after every reimport of the game.
+-- Not tied to games(id): the log must outlive the game's data.
+CREATE TABLE IF NOT EXISTS game_edits (
+    id INTEGER PRIMARY KEY AUTOINCREMENT,
+    game_id TEXT NOT NULL,
+    entity TEXT NOT NULL,
+    action TEXT NOT NULL,
+    target_key TEXT NOT NULL,
+    before_json TEXT,
+    after_json TEXT,
+    author TEXT NOT NULL DEFAULT 'local',
+    created_at TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_game_edits_game ON game_edits(game_id, id);
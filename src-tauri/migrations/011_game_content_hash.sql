@@ -0,0 +1,3 @@
+-- Content hash of the imported game data file, and the import format version it was read with
+ALTER TABLE games ADD COLUMN content_hash TEXT;
+ALTER TABLE games ADD COLUMN format_version INTEGER;
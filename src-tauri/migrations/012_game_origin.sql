@@ -0,0 +1,4 @@
+-- Where a game's data comes from: 'bundled', 'user' (games folder) or 'imported' (manual import).
+-- Official version groups registered by the sync have no origin.
+ALTER TABLE games ADD COLUMN origin TEXT;
+ALTER TABLE games ADD COLUMN origin_file TEXT;
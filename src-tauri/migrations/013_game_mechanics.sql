@@ -0,0 +1,7 @@
+-- Battle mechanics of a game (NULL = inherited / derived from the generation)
+ALTER TABLE games ADD COLUMN generation INTEGER;
+ALTER TABLE games ADD COLUMN damage_split TEXT;
+ALTER TABLE games ADD COLUMN has_fairy INTEGER;
+ALTER TABLE games ADD COLUMN crit_multiplier REAL;
+-- JSON array of {attacking_type, defending_type, damage_factor}
+ALTER TABLE games ADD COLUMN type_chart_overrides TEXT;
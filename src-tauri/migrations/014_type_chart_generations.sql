@@ -0,0 +1,11 @@
+-- Type chart as it was in each generation (only non-neutral cells, like type_efficacy)
+CREATE TABLE IF NOT EXISTS type_efficacy_generations (
+    generation INTEGER NOT NULL,
+    attacking_type_id INTEGER NOT NULL,
+    defending_type_id INTEGER NOT NULL,
+    damage_factor INTEGER NOT NULL,
+    PRIMARY KEY (generation, attacking_type_id, defending_type_id)
+);
+
+-- Generation a type was introduced in
+ALTER TABLE types ADD COLUMN generation INTEGER;
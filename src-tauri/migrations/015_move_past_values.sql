@@ -0,0 +1,12 @@
+-- Move values that applied before a version group changed them (NULL = unchanged then)
+CREATE TABLE IF NOT EXISTS move_past_values (
+    move_id       INTEGER NOT NULL,
+    version_group TEXT NOT NULL,
+    power         INTEGER,
+    accuracy      INTEGER,
+    pp            INTEGER,
+    type_key      TEXT,
+    effect_en     TEXT,
+    PRIMARY KEY (move_id, version_group),
+    FOREIGN KEY (move_id) REFERENCES moves(id) ON DELETE CASCADE
+);
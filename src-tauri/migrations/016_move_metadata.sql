@@ -0,0 +1,32 @@
+-- Move metadata (effect chance, target, meta stats, generation introduced)
+ALTER TABLE moves ADD COLUMN effect_chance INTEGER;
+ALTER TABLE moves ADD COLUMN target TEXT;
+ALTER TABLE moves ADD COLUMN crit_rate INTEGER;
+ALTER TABLE moves ADD COLUMN drain INTEGER;
+ALTER TABLE moves ADD COLUMN healing INTEGER;
+ALTER TABLE moves ADD COLUMN min_hits INTEGER;
+ALTER TABLE moves ADD COLUMN max_hits INTEGER;
+ALTER TABLE moves ADD COLUMN ailment TEXT;
+ALTER TABLE moves ADD COLUMN ailment_chance INTEGER;
+ALTER TABLE moves ADD COLUMN flinch_chance INTEGER;
+ALTER TABLE moves ADD COLUMN stat_chance INTEGER;
+ALTER TABLE moves ADD COLUMN generation INTEGER;
+
+-- Stat stage changes caused by a move
+CREATE TABLE IF NOT EXISTS move_stat_changes (
+    move_id INTEGER NOT NULL,
+    stat    TEXT NOT NULL,
+    change  INTEGER NOT NULL,
+    PRIMARY KEY (move_id, stat),
+    FOREIGN KEY (move_id) REFERENCES moves(id) ON DELETE CASCADE
+);
+
+-- Move flags ("contact", "sound", "punch", ...)
+CREATE TABLE IF NOT EXISTS move_flags (
+    move_id INTEGER NOT NULL,
+    flag    TEXT NOT NULL,
+    PRIMARY KEY (move_id, flag),
+    FOREIGN KEY (move_id) REFERENCES moves(id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_move_flags_flag ON move_flags(flag);
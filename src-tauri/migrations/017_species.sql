@@ -0,0 +1,31 @@
+-- Species-level data (shared by all forms of a pokemon)
+CREATE TABLE IF NOT EXISTS species (
+    species_id              INTEGER PRIMARY KEY,
+    name_key                TEXT NOT NULL,
+    name_en                 TEXT,
+    name_fr                 TEXT,
+    genus_en                TEXT,
+    genus_fr                TEXT,
+    capture_rate            INTEGER,
+    base_happiness          INTEGER,
+    -- Chance of being female in eighths, -1 for genderless
+    gender_rate             INTEGER,
+    growth_rate             TEXT,
+    hatch_counter           INTEGER,
+    is_legendary            INTEGER NOT NULL DEFAULT 0,
+    is_mythical             INTEGER NOT NULL DEFAULT 0,
+    is_baby                 INTEGER NOT NULL DEFAULT 0,
+    generation              INTEGER,
+    evolves_from_species_id INTEGER,
+    evolution_chain_id      INTEGER
+);
+
+CREATE TABLE IF NOT EXISTS species_egg_groups (
+    species_id INTEGER NOT NULL,
+    egg_group  TEXT NOT NULL,
+    PRIMARY KEY (species_id, egg_group),
+    FOREIGN KEY (species_id) REFERENCES species(species_id) ON DELETE CASCADE
+);
+
+CREATE INDEX IF NOT EXISTS idx_species_egg_groups_group ON species_egg_groups(egg_group);
+CREATE INDEX IF NOT EXISTS idx_species_name_key ON species(name_key);
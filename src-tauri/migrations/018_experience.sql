@@ -0,0 +1,16 @@
+-- Base experience and EV yield when defeated
+ALTER TABLE pokemon ADD COLUMN base_experience INTEGER;
+ALTER TABLE pokemon ADD COLUMN ev_hp INTEGER;
+ALTER TABLE pokemon ADD COLUMN ev_atk INTEGER;
+ALTER TABLE pokemon ADD COLUMN ev_def INTEGER;
+ALTER TABLE pokemon ADD COLUMN ev_spa INTEGER;
+ALTER TABLE pokemon ADD COLUMN ev_spd INTEGER;
+ALTER TABLE pokemon ADD COLUMN ev_spe INTEGER;
+
+-- Total experience needed for each level, per growth rate
+CREATE TABLE IF NOT EXISTS growth_rate_levels (
+    growth_rate TEXT NOT NULL,
+    level       INTEGER NOT NULL,
+    experience  INTEGER NOT NULL,
+    PRIMARY KEY (growth_rate, level)
+);
@@ -0,0 +1,33 @@
+-- One row per evolution detail (a species can evolve several ways)
+CREATE TABLE IF NOT EXISTS pokemon_evolutions (
+    chain_id                INTEGER NOT NULL,
+    from_species_id         INTEGER NOT NULL,
+    to_species_id           INTEGER NOT NULL,
+    detail_index            INTEGER NOT NULL,
+    trigger                 TEXT,
+    min_level               INTEGER,
+    item                    TEXT,
+    held_item               TEXT,
+    known_move              TEXT,
+    known_move_type         TEXT,
+    location                TEXT,
+    min_happiness           INTEGER,
+    min_affection           INTEGER,
+    min_beauty              INTEGER,
+    time_of_day             TEXT,
+    -- 1 = female, 2 = male
+    gender                  INTEGER,
+    party_species           TEXT,
+    party_type              TEXT,
+    trade_species           TEXT,
+    -- 1 = Atk > Def, 0 = Atk = Def, -1 = Atk < Def
+    relative_physical_stats INTEGER,
+    needs_overworld_rain    INTEGER NOT NULL DEFAULT 0,
+    turn_upside_down        INTEGER NOT NULL DEFAULT 0,
+    PRIMARY KEY (to_species_id, detail_index)
+);
+
+CREATE INDEX IF NOT EXISTS idx_pokemon_evolutions_chain ON pokemon_evolutions(chain_id);
+CREATE INDEX IF NOT EXISTS idx_pokemon_evolutions_from ON pokemon_evolutions(from_species_id);
+CREATE INDEX IF NOT EXISTS idx_pokemon_evolutions_trigger ON pokemon_evolutions(trigger);
+CREATE INDEX IF NOT EXISTS idx_pokemon_evolutions_item ON pokemon_evolutions(item);
@@ -0,0 +1,6 @@
+-- Localized names of PokéAPI locations (those referenced by evolution conditions)
+CREATE TABLE IF NOT EXISTS locations (
+    name_key TEXT PRIMARY KEY,
+    name_en  TEXT,
+    name_fr  TEXT
+);
@@ -0,0 +1,25 @@
+-- Every form of every pokemon (regional variants, megas, cosmetic forms, ...)
+CREATE TABLE IF NOT EXISTS pokemon_forms (
+    id             INTEGER PRIMARY KEY,
+    name_key       TEXT NOT NULL,
+    pokemon_id     INTEGER NOT NULL,
+    form_name      TEXT NOT NULL DEFAULT '',
+    name_en        TEXT,
+    name_fr        TEXT,
+    form_name_en   TEXT,
+    form_name_fr   TEXT,
+    -- Default form of its pokemon (not necessarily of the species)
+    is_default     INTEGER NOT NULL DEFAULT 0,
+    is_mega        INTEGER NOT NULL DEFAULT 0,
+    is_battle_only INTEGER NOT NULL DEFAULT 0,
+    -- default, mega, gigantamax, regional, battle, cosmetic or variant
+    category       TEXT NOT NULL,
+    -- alola, galar, hisui or paldea
+    regional_tag   TEXT,
+    version_group  TEXT,
+    form_order     INTEGER,
+    sprite_url     TEXT
+);
+
+CREATE INDEX IF NOT EXISTS idx_pokemon_forms_pokemon ON pokemon_forms(pokemon_id);
+CREATE INDEX IF NOT EXISTS idx_pokemon_forms_category ON pokemon_forms(category);
@@ -0,0 +1,12 @@
+-- Names and descriptions in every language PokéAPI provides
+-- (entity: species, move, item, type, ability or nature)
+CREATE TABLE IF NOT EXISTS localized_text (
+    entity    TEXT NOT NULL,
+    entity_id INTEGER NOT NULL,
+    field     TEXT NOT NULL,
+    lang      TEXT NOT NULL,
+    text      TEXT NOT NULL,
+    PRIMARY KEY (entity, entity_id, field, lang)
+);
+
+CREATE INDEX IF NOT EXISTS idx_localized_text_lang ON localized_text(entity, field, lang);
@@ -0,0 +1,12 @@
+-- Sprites downloaded into the app data dir for offline use
+-- (kind: pokemon = artwork, pokemon-sprite = front sprite, item)
+CREATE TABLE IF NOT EXISTS sprite_assets (
+    kind      TEXT NOT NULL,
+    entity_id INTEGER NOT NULL,
+    url       TEXT NOT NULL,
+    -- SHA-256 of the cached file (also its file name), NULL until downloaded
+    hash      TEXT,
+    PRIMARY KEY (kind, entity_id)
+);
+
+CREATE INDEX IF NOT EXISTS idx_sprite_assets_url ON sprite_assets(url);
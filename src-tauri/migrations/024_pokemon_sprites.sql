@@ -0,0 +1,16 @@
+-- Every sprite PokéAPI lists for a pokemon: shiny, female, back, animated and
+-- the per-game sets of each generation
+CREATE TABLE IF NOT EXISTS pokemon_sprites (
+    pokemon_id INTEGER NOT NULL,
+    -- default, official-artwork, home, showdown, or a game's set (emerald, black-white, ...)
+    source     TEXT NOT NULL,
+    -- generation of a game's set, NULL for the others
+    generation INTEGER,
+    animated   INTEGER NOT NULL DEFAULT 0,
+    -- front_default, front_shiny, back_female, ...
+    variant    TEXT NOT NULL,
+    url        TEXT NOT NULL,
+    PRIMARY KEY (pokemon_id, source, variant)
+);
+
+CREATE INDEX IF NOT EXISTS idx_pokemon_sprites_generation ON pokemon_sprites(pokemon_id, generation);
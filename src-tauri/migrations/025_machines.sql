@@ -0,0 +1,20 @@
+-- TM/HM/TR contents per version group (PokéAPI machines)
+CREATE TABLE IF NOT EXISTS machines (
+    id            INTEGER PRIMARY KEY,
+    version_group TEXT NOT NULL,
+    -- machine item: tm01, hm05, tr12
+    item          TEXT NOT NULL,
+    -- move name key
+    move          TEXT NOT NULL
+);
+
+CREATE INDEX IF NOT EXISTS idx_machines_version_group ON machines(version_group, move);
+
+-- TM/HM/TR list of a hackrom, from its item_locations
+CREATE TABLE IF NOT EXISTS game_machines (
+    game_id       TEXT NOT NULL,
+    item          TEXT NOT NULL,
+    move_name_key TEXT NOT NULL,
+    PRIMARY KEY (game_id, item),
+    FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+);
@@ -0,0 +1,8 @@
+-- Items listed in a game data file, in file order (keeps entries with no
+-- locations, for export)
+CREATE TABLE IF NOT EXISTS game_item_entries (
+    game_id TEXT NOT NULL,
+    item_name_key TEXT NOT NULL,
+    PRIMARY KEY (game_id, item_name_key),
+    FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+);
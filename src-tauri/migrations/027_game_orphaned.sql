@@ -0,0 +1,3 @@
+-- User games whose file left the games folder: unlisted, but their runs and edits are
+-- kept until the game is deleted (or the file comes back)
+ALTER TABLE games ADD COLUMN orphaned INTEGER NOT NULL DEFAULT 0;
@@ -0,0 +1,4 @@
+-- Version groups registered by the sync only as a layer for their learnsets (Colosseum,
+-- Let's Go, DLCs...): kept out of the game selector
+ALTER TABLE games ADD COLUMN hidden INTEGER NOT NULL DEFAULT 0;
+UPDATE games SET hidden = 1 WHERE origin IS NULL AND is_hackrom = 0 AND sort_order = 200
@@ -1,6 +1,7 @@
 use serde::Deserialize;
 
 use super::client::PokeApiClient;
+use super::localization::{localized, LocalizedText};
 
 #[derive(Debug, Deserialize)]
 pub struct ApiAbility {
@@ -67,6 +68,8 @@ pub struct ParsedAbility {
     pub short_effect_fr: Option<String>,
     pub generation: Option<i64>,
     pub pokemon: Vec<ParsedAbilityPokemon>,
+    /// Texts in every language PokéAPI provides.
+    pub texts: Vec<LocalizedText>,
 }
 
 #[derive(Debug, Clone)]
@@ -140,6 +143,26 @@ fn parse_ability(api: ApiAbility) -> ParsedAbility {
         })
         .collect();
 
+    let mut texts = localized("name", api.names.iter().map(|n| (n.language.name.as_str(), n.name.as_str())));
+    texts.extend(localized(
+        "effect",
+        api.effect_entries
+            .iter()
+            .filter_map(|e| Some((e.language.name.as_str(), e.effect.as_deref()?)))
+            .chain(
+                api.flavor_text_entries
+                    .iter()
+                    .rev()
+                    .map(|f| (f.language.name.as_str(), f.flavor_text.as_str())),
+            ),
+    ));
+    texts.extend(localized(
+        "short_effect",
+        api.effect_entries
+            .iter()
+            .filter_map(|e| Some((e.language.name.as_str(), e.short_effect.as_deref()?))),
+    ));
+
     ParsedAbility {
         id: api.id,
         name_key: api.name,
@@ -151,5 +174,6 @@ fn parse_ability(api: ApiAbility) -> ParsedAbility {
         short_effect_fr,
         generation,
         pokemon,
+        texts,
     }
 }
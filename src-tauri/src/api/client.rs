@@ -48,6 +48,18 @@ impl PokeApiClient {
         resp.json::<T>().await
     }
 
+    /// Fetch a plain text document (single attempt, no internal retry).
+    pub async fn get_text(&self, url: &str) -> Result<String, reqwest::Error> {
+        let resp = self.client.get(url).send().await?.error_for_status()?;
+        resp.text().await
+    }
+
+    /// Fetch a binary file such as a sprite (single attempt, no internal retry).
+    pub async fn get_bytes(&self, url: &str) -> Result<Vec<u8>, reqwest::Error> {
+        let resp = self.client.get(url).send().await?.error_for_status()?;
+        Ok(resp.bytes().await?.to_vec())
+    }
+
     /// Build a full API URL from a relative path.
     pub fn url(&self, path: &str) -> String {
         format!("{}/{}", self.base_url, path.trim_start_matches('/'))
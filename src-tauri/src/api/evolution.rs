@@ -1,7 +1,7 @@
 use serde::Deserialize;
 
 use super::client::PokeApiClient;
-use crate::models::EvolutionNode;
+use crate::models::{EvolutionCondition, EvolutionNode};
 
 // ── PokéAPI evolution chain response structs ────────────────────────
 
@@ -28,6 +28,18 @@ pub struct ApiEvolutionDetail {
     pub time_of_day: Option<String>,
     pub known_move: Option<ApiEvoResourceRef>,
     pub location: Option<ApiEvoResourceRef>,
+    pub gender: Option<i64>,
+    pub known_move_type: Option<ApiEvoResourceRef>,
+    pub min_affection: Option<i64>,
+    pub min_beauty: Option<i64>,
+    #[serde(default)]
+    pub needs_overworld_rain: bool,
+    pub party_species: Option<ApiEvoResourceRef>,
+    pub party_type: Option<ApiEvoResourceRef>,
+    pub relative_physical_stats: Option<i64>,
+    pub trade_species: Option<ApiEvoResourceRef>,
+    #[serde(default)]
+    pub turn_upside_down: bool,
 }
 
 #[derive(Debug, Deserialize)]
@@ -62,20 +74,49 @@ fn parse_chain_link(link: &ApiChainLink) -> EvolutionNode {
         (None, None)
     };
 
+    let conditions = link.evolution_details.iter().map(parse_condition).collect();
     let evolves_to = link.evolves_to.iter().map(parse_chain_link).collect();
 
     EvolutionNode {
         pokemon_id,
         name_key: link.species.name.clone(),
-        name_en: None,  // will be enriched from DB later
+        name_en: None,  // resolved from the pokemon table at query time
         name_fr: None,
         sprite_url: None,
         trigger,
         trigger_detail,
+        conditions,
         evolves_to,
     }
 }
 
+/// Convert one evolution detail into a structured condition.
+fn parse_condition(detail: &ApiEvolutionDetail) -> EvolutionCondition {
+    let name = |r: &Option<ApiEvoResourceRef>| r.as_ref().map(|r| r.name.clone());
+
+    EvolutionCondition {
+        trigger: name(&detail.trigger),
+        min_level: detail.min_level,
+        item: name(&detail.item),
+        held_item: name(&detail.held_item),
+        known_move: name(&detail.known_move),
+        known_move_type: name(&detail.known_move_type),
+        location: name(&detail.location),
+        min_happiness: detail.min_happiness,
+        min_affection: detail.min_affection,
+        min_beauty: detail.min_beauty,
+        time_of_day: detail.time_of_day.clone().filter(|t| !t.is_empty()),
+        gender: detail.gender,
+        party_species: name(&detail.party_species),
+        party_type: name(&detail.party_type),
+        trade_species: name(&detail.trade_species),
+        relative_physical_stats: detail.relative_physical_stats,
+        needs_overworld_rain: detail.needs_overworld_rain,
+        turn_upside_down: detail.turn_upside_down,
+        ..Default::default()
+    }
+}
+
 /// Build a human-readable trigger detail string.
 fn build_trigger_detail(detail: &ApiEvolutionDetail) -> Option<String> {
     let mut parts = Vec::new();
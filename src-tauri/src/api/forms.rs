@@ -0,0 +1,103 @@
+use serde::Deserialize;
+
+use super::client::PokeApiClient;
+
+// ── PokéAPI pokemon-form response structs ───────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct ApiPokemonForm {
+    pub id: i64,
+    pub name: String,
+    pub form_name: String,
+    #[serde(default)]
+    pub form_order: Option<i64>,
+    pub is_default: bool,
+    pub is_battle_only: bool,
+    pub is_mega: bool,
+    pub pokemon: ApiFormResourceRef,
+    pub version_group: Option<ApiFormResourceRef>,
+    pub sprites: ApiFormSprites,
+    #[serde(default)]
+    pub names: Vec<ApiFormName>,
+    #[serde(default)]
+    pub form_names: Vec<ApiFormName>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiFormResourceRef {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiFormSprites {
+    pub front_default: Option<String>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiFormName {
+    pub name: String,
+    pub language: ApiFormLangRef,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiFormLangRef {
+    pub name: String,
+}
+
+/// Extracted form data ready for caching.
+#[derive(Debug, Clone)]
+pub struct ParsedPokemonForm {
+    pub id: i64,
+    pub name_key: String,
+    pub pokemon_id: Option<i64>,
+    /// Suffix identifying the form ("alola", "mega-x", "gmax"); empty for base forms.
+    pub form_name: String,
+    /// Full names ("Alolan Raichu").
+    pub name_en: Option<String>,
+    pub name_fr: Option<String>,
+    /// Form-only names ("Alolan Form").
+    pub form_name_en: Option<String>,
+    pub form_name_fr: Option<String>,
+    pub is_default: bool,
+    pub is_mega: bool,
+    pub is_battle_only: bool,
+    pub version_group: Option<String>,
+    pub form_order: Option<i64>,
+    pub sprite_url: Option<String>,
+}
+
+impl PokeApiClient {
+    /// Fetch a single pokemon form by ID from PokéAPI.
+    pub async fn fetch_pokemon_form(&self, id: i64) -> Result<ParsedPokemonForm, reqwest::Error> {
+        let url = self.url(&format!("pokemon-form/{}", id));
+        let api: ApiPokemonForm = self.get_json(&url).await?;
+        Ok(parse_pokemon_form(api))
+    }
+}
+
+fn parse_pokemon_form(api: ApiPokemonForm) -> ParsedPokemonForm {
+    let name_in = |names: &[ApiFormName], lang: &str| {
+        names
+            .iter()
+            .find(|n| n.language.name == lang)
+            .map(|n| n.name.clone())
+    };
+
+    ParsedPokemonForm {
+        id: api.id,
+        pokemon_id: PokeApiClient::id_from_url(&api.pokemon.url),
+        name_en: name_in(&api.names, "en"),
+        name_fr: name_in(&api.names, "fr"),
+        form_name_en: name_in(&api.form_names, "en"),
+        form_name_fr: name_in(&api.form_names, "fr"),
+        is_default: api.is_default,
+        is_mega: api.is_mega,
+        is_battle_only: api.is_battle_only,
+        version_group: api.version_group.map(|vg| vg.name),
+        form_order: api.form_order,
+        sprite_url: api.sprites.front_default,
+        name_key: api.name,
+        form_name: api.form_name,
+    }
+}
@@ -1,6 +1,7 @@
 use serde::Deserialize;
 
 use super::client::PokeApiClient;
+use super::localization::{localized, LocalizedText};
 
 // ── PokéAPI item response structs ───────────────────────────────────
 
@@ -61,6 +62,8 @@ pub struct ParsedItem {
     pub effect_en: Option<String>,
     pub effect_fr: Option<String>,
     pub sprite_url: Option<String>,
+    /// Texts in every language PokéAPI provides.
+    pub texts: Vec<LocalizedText>,
 }
 
 impl PokeApiClient {
@@ -103,6 +106,18 @@ fn parse_item(api: ApiItem) -> ParsedItem {
                 .map(|f| f.text.clone())
         });
 
+    let mut texts = localized("name", api.names.iter().map(|n| (n.language.name.as_str(), n.name.as_str())));
+    texts.extend(localized(
+        "effect",
+        api.effect_entries
+            .iter()
+            .filter_map(|e| {
+                let text = e.short_effect.as_deref().or(e.effect.as_deref())?;
+                Some((e.language.name.as_str(), text))
+            })
+            .chain(api.flavor_text_entries.iter().map(|f| (f.language.name.as_str(), f.text.as_str()))),
+    ));
+
     ParsedItem {
         id: api.id,
         name_key: api.name,
@@ -112,5 +127,6 @@ fn parse_item(api: ApiItem) -> ParsedItem {
         effect_en,
         effect_fr,
         sprite_url: api.sprites.default,
+        texts,
     }
 }
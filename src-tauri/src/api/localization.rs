@@ -0,0 +1,27 @@
+/// Text of one field of an entity in one language (e.g. the German name of a move).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct LocalizedText {
+    pub field: &'static str,
+    pub lang: String,
+    pub text: String,
+}
+
+/// Collect (language, text) pairs of a field, keeping the first non-empty text per
+/// language: chain fallbacks (e.g. flavor texts after effects) after the preferred source.
+pub fn localized<'a>(
+    field: &'static str,
+    entries: impl IntoIterator<Item = (&'a str, &'a str)>,
+) -> Vec<LocalizedText> {
+    let mut texts: Vec<LocalizedText> = Vec::new();
+    for (lang, text) in entries {
+        if text.trim().is_empty() || texts.iter().any(|t| t.lang == lang) {
+            continue;
+        }
+        texts.push(LocalizedText {
+            field,
+            lang: lang.to_string(),
+            text: text.to_string(),
+        });
+    }
+    texts
+}
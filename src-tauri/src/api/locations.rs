@@ -0,0 +1,54 @@
+use serde::Deserialize;
+
+use super::client::PokeApiClient;
+
+// ── PokéAPI location response structs ───────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct ApiLocation {
+    pub name: String,
+    pub names: Vec<ApiLocationName>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiLocationName {
+    pub name: String,
+    pub language: ApiLocationLangRef,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiLocationLangRef {
+    pub name: String,
+}
+
+/// Extracted location names ready for caching.
+#[derive(Debug, Clone)]
+pub struct ParsedLocation {
+    pub name_key: String,
+    pub name_en: Option<String>,
+    pub name_fr: Option<String>,
+}
+
+impl PokeApiClient {
+    /// Fetch a location by name from PokéAPI.
+    pub async fn fetch_location(&self, name: &str) -> Result<ParsedLocation, reqwest::Error> {
+        let url = self.url(&format!("location/{}", name));
+        let api: ApiLocation = self.get_json(&url).await?;
+        Ok(parse_location(api))
+    }
+}
+
+fn parse_location(api: ApiLocation) -> ParsedLocation {
+    let name_in = |lang: &str| {
+        api.names
+            .iter()
+            .find(|n| n.language.name == lang)
+            .map(|n| n.name.clone())
+    };
+
+    ParsedLocation {
+        name_en: name_in("en"),
+        name_fr: name_in("fr"),
+        name_key: api.name,
+    }
+}
@@ -0,0 +1,43 @@
+use serde::Deserialize;
+
+use super::client::PokeApiClient;
+
+// ── PokéAPI machine response structs ────────────────────────────────
+
+#[derive(Debug, Deserialize)]
+pub struct ApiMachine {
+    pub id: i64,
+    pub item: ApiMachineResourceRef,
+    #[serde(rename = "move")]
+    pub move_info: ApiMachineResourceRef,
+    pub version_group: ApiMachineResourceRef,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiMachineResourceRef {
+    pub name: String,
+}
+
+/// The move a TM/HM/TR teaches in a version group.
+#[derive(Debug, Clone)]
+pub struct ParsedMachine {
+    pub id: i64,
+    pub version_group: String,
+    /// Machine item ("tm01", "hm05", "tr12").
+    pub item: String,
+    pub move_name_key: String,
+}
+
+impl PokeApiClient {
+    /// Fetch a single machine by ID from PokéAPI.
+    pub async fn fetch_machine(&self, id: i64) -> Result<ParsedMachine, reqwest::Error> {
+        let url = self.url(&format!("machine/{}", id));
+        let api: ApiMachine = self.get_json(&url).await?;
+        Ok(ParsedMachine {
+            id: api.id,
+            version_group: api.version_group.name,
+            item: api.item.name,
+            move_name_key: api.move_info.name,
+        })
+    }
+}
@@ -5,7 +5,11 @@ pub mod moves;
 pub mod items;
 pub mod types;
 pub mod evolution;
+pub mod locations;
+pub mod forms;
+pub mod localization;
 pub mod natures;
 pub mod abilities;
 
 pub use client::PokeApiClient;
+pub mod machines;
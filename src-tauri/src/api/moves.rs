@@ -1,6 +1,7 @@
 use serde::Deserialize;
 
 use super::client::PokeApiClient;
+use super::localization::{localized, LocalizedText};
 
 // ── PokéAPI move response structs ───────────────────────────────────
 
@@ -18,6 +19,47 @@ pub struct ApiMove {
     pub priority: Option<i64>,
     pub effect_entries: Vec<ApiMoveEffectEntry>,
     pub flavor_text_entries: Vec<ApiMoveFlavorText>,
+    #[serde(default)]
+    pub past_values: Vec<ApiMovePastValue>,
+    pub effect_chance: Option<i64>,
+    pub target: Option<ApiMoveResourceRef>,
+    pub meta: Option<ApiMoveMeta>,
+    #[serde(default)]
+    pub stat_changes: Vec<ApiMoveStatChange>,
+    pub generation: Option<ApiMoveResourceRef>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiMoveMeta {
+    pub ailment: Option<ApiMoveResourceRef>,
+    pub min_hits: Option<i64>,
+    pub max_hits: Option<i64>,
+    pub drain: Option<i64>,
+    pub healing: Option<i64>,
+    pub crit_rate: Option<i64>,
+    pub ailment_chance: Option<i64>,
+    pub flinch_chance: Option<i64>,
+    pub stat_chance: Option<i64>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiMoveStatChange {
+    pub change: i64,
+    pub stat: ApiMoveResourceRef,
+}
+
+/// Values a move had before `version_group` changed them (null = unchanged then).
+#[derive(Debug, Deserialize)]
+pub struct ApiMovePastValue {
+    pub power: Option<i64>,
+    pub accuracy: Option<i64>,
+    pub pp: Option<i64>,
+    pub effect_chance: Option<i64>,
+    #[serde(rename = "type")]
+    pub type_info: Option<ApiMoveResourceRef>,
+    #[serde(default)]
+    pub effect_entries: Vec<ApiMoveEffectEntry>,
+    pub version_group: ApiMoveResourceRef,
 }
 
 #[derive(Debug, Deserialize)]
@@ -65,8 +107,46 @@ pub struct ParsedMove {
     pub priority: i64,
     pub effect_en: Option<String>,
     pub effect_fr: Option<String>,
+    pub past_values: Vec<ParsedMovePastValue>,
+    pub effect_chance: Option<i64>,
+    pub target: Option<String>,
+    /// Critical hit stage bonus.
+    pub crit_rate: Option<i64>,
+    /// HP drained (positive) or recoil (negative), in % of damage dealt.
+    pub drain: Option<i64>,
+    /// HP healed, in % of max HP.
+    pub healing: Option<i64>,
+    pub min_hits: Option<i64>,
+    pub max_hits: Option<i64>,
+    pub ailment: Option<String>,
+    pub ailment_chance: Option<i64>,
+    pub flinch_chance: Option<i64>,
+    pub stat_chance: Option<i64>,
+    /// Generation the move was introduced in.
+    pub generation: Option<i64>,
+    /// (stat, stage change)
+    pub stat_changes: Vec<(String, i64)>,
+    /// Texts in every language PokéAPI provides.
+    pub texts: Vec<LocalizedText>,
 }
 
+/// Move values that applied before a version group.
+#[derive(Debug, Clone)]
+pub struct ParsedMovePastValue {
+    pub version_group: String,
+    pub power: Option<i64>,
+    pub accuracy: Option<i64>,
+    pub pp: Option<i64>,
+    pub type_key: Option<String>,
+    pub effect_en: Option<String>,
+}
+
+/// PokéAPI's source data for move flags, which the REST API doesn't expose.
+const MOVE_FLAGS_CSV_URL: &str =
+    "https://raw.githubusercontent.com/PokeAPI/pokeapi/master/data/v2/csv/move_flags.csv";
+const MOVE_FLAG_MAP_CSV_URL: &str =
+    "https://raw.githubusercontent.com/PokeAPI/pokeapi/master/data/v2/csv/move_flag_map.csv";
+
 impl PokeApiClient {
     /// Fetch a single move by ID from PokéAPI.
     pub async fn fetch_move(&self, id: i64) -> Result<ParsedMove, reqwest::Error> {
@@ -74,6 +154,43 @@ impl PokeApiClient {
         let api: ApiMove = self.get_json(&url).await?;
         Ok(parse_move(api))
     }
+
+    /// Fetch the flags of all moves ("contact", "sound", "punch", ...) as (move id, flag).
+    pub async fn fetch_move_flags(&self) -> Result<Vec<(i64, String)>, reqwest::Error> {
+        let flags = self.get_text(MOVE_FLAGS_CSV_URL).await?;
+        let flag_map = self.get_text(MOVE_FLAG_MAP_CSV_URL).await?;
+        Ok(parse_move_flags(&flags, &flag_map))
+    }
+}
+
+/// Join `move_flags.csv` (id,identifier) with `move_flag_map.csv` (move_id,move_flag_id).
+fn parse_move_flags(flags: &str, flag_map: &str) -> Vec<(i64, String)> {
+    let rows = |csv: &str| -> Vec<(i64, String)> {
+        csv.lines()
+            .skip(1)
+            .filter_map(|line| {
+                let (first, second) = line.trim().split_once(',')?;
+                Some((first.parse().ok()?, second.to_string()))
+            })
+            .collect()
+    };
+
+    let names: std::collections::HashMap<i64, String> = rows(flags).into_iter().collect();
+    rows(flag_map)
+        .into_iter()
+        .filter_map(|(move_id, flag_id)| {
+            let name = names.get(&flag_id.parse().ok()?)?;
+            Some((move_id, name.clone()))
+        })
+        .collect()
+}
+
+/// Resolve the `$effect_chance` placeholder of PokéAPI effect texts.
+fn substitute_effect_chance(text: String, effect_chance: Option<i64>) -> String {
+    match effect_chance {
+        Some(chance) => text.replace("$effect_chance", &chance.to_string()),
+        None => text,
+    }
 }
 
 fn parse_move(api: ApiMove) -> ParsedMove {
@@ -109,6 +226,47 @@ fn parse_move(api: ApiMove) -> ParsedMove {
                 .map(|f| f.flavor_text.clone())
         });
 
+    let effect_chance = api.effect_chance;
+    let effect_en = effect_en.map(|e| substitute_effect_chance(e, effect_chance));
+    let effect_fr = effect_fr.map(|e| substitute_effect_chance(e, effect_chance));
+
+    let mut texts = localized("name", api.names.iter().map(|n| (n.language.name.as_str(), n.name.as_str())));
+    let effects = localized(
+        "effect",
+        api.effect_entries
+            .iter()
+            .filter_map(|e| {
+                let text = e.short_effect.as_deref().or(e.effect.as_deref())?;
+                Some((e.language.name.as_str(), text))
+            })
+            .chain(api.flavor_text_entries.iter().map(|f| (f.language.name.as_str(), f.flavor_text.as_str()))),
+    );
+    texts.extend(effects.into_iter().map(|t| LocalizedText {
+        text: substitute_effect_chance(t.text, effect_chance),
+        ..t
+    }));
+
+    let past_values = api
+        .past_values
+        .into_iter()
+        .map(|pv| ParsedMovePastValue {
+            version_group: pv.version_group.name,
+            power: pv.power,
+            accuracy: pv.accuracy,
+            pp: pv.pp,
+            type_key: pv.type_info.map(|t| t.name),
+            effect_en: pv
+                .effect_entries
+                .iter()
+                .find(|e| e.language.name == "en")
+                .and_then(|e| e.short_effect.clone().or(e.effect.clone()))
+                .map(|e| substitute_effect_chance(e, pv.effect_chance.or(effect_chance))),
+        })
+        .collect();
+
+    let meta = api.meta;
+    let meta_value = |f: fn(&ApiMoveMeta) -> Option<i64>| meta.as_ref().and_then(f);
+
     ParsedMove {
         id: api.id,
         name_key: api.name,
@@ -122,5 +280,28 @@ fn parse_move(api: ApiMove) -> ParsedMove {
         priority: api.priority.unwrap_or(0),
         effect_en,
         effect_fr,
+        past_values,
+        effect_chance,
+        target: api.target.map(|t| t.name),
+        crit_rate: meta_value(|m| m.crit_rate),
+        drain: meta_value(|m| m.drain),
+        healing: meta_value(|m| m.healing),
+        min_hits: meta_value(|m| m.min_hits),
+        max_hits: meta_value(|m| m.max_hits),
+        ailment: meta
+            .as_ref()
+            .and_then(|m| m.ailment.as_ref())
+            .map(|a| a.name.clone())
+            .filter(|a| a != "none"),
+        ailment_chance: meta_value(|m| m.ailment_chance),
+        flinch_chance: meta_value(|m| m.flinch_chance),
+        stat_chance: meta_value(|m| m.stat_chance),
+        generation: api.generation.as_ref().and_then(|g| PokeApiClient::id_from_url(&g.url)),
+        stat_changes: api
+            .stat_changes
+            .into_iter()
+            .map(|sc| (sc.stat.name, sc.change))
+            .collect(),
+        texts,
     }
 }
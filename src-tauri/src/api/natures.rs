@@ -1,6 +1,7 @@
 use serde::Deserialize;
 
 use super::client::PokeApiClient;
+use super::localization::{localized, LocalizedText};
 
 #[derive(Debug, Deserialize)]
 pub struct ApiNature {
@@ -44,6 +45,8 @@ pub struct ParsedNature {
     pub decreased_stat: Option<String>,
     pub likes_flavor: Option<String>,
     pub hates_flavor: Option<String>,
+    /// Texts in every language PokéAPI provides.
+    pub texts: Vec<LocalizedText>,
 }
 
 impl PokeApiClient {
@@ -67,6 +70,8 @@ fn parse_nature(api: ApiNature) -> ParsedNature {
         .find(|n| n.language.name == "fr")
         .map(|n| n.name.clone());
 
+    let texts = localized("name", api.names.iter().map(|n| (n.language.name.as_str(), n.name.as_str())));
+
     ParsedNature {
         id: api.id,
         name_key: api.name,
@@ -76,5 +81,6 @@ fn parse_nature(api: ApiNature) -> ParsedNature {
         decreased_stat: api.decreased_stat.map(|s| s.name),
         likes_flavor: api.likes_flavor.map(|f| f.name),
         hates_flavor: api.hates_flavor.map(|f| f.name),
+        texts,
     }
 }
@@ -10,6 +10,7 @@ pub struct ApiPokemon {
     pub name: String,
     pub height: Option<i64>,
     pub weight: Option<i64>,
+    pub base_experience: Option<i64>,
     pub types: Vec<ApiPokemonTypeSlot>,
     pub stats: Vec<ApiPokemonStat>,
     pub abilities: Vec<ApiPokemonAbilitySlot>,
@@ -28,6 +29,9 @@ pub struct ApiPokemonTypeSlot {
 #[derive(Debug, Deserialize)]
 pub struct ApiPokemonStat {
     pub base_stat: i64,
+    /// EVs yielded when defeated.
+    #[serde(default)]
+    pub effort: i64,
     pub stat: ApiResourceRef,
 }
 
@@ -56,17 +60,25 @@ pub struct ApiVersionGroupDetail {
 pub struct ApiSprites {
     pub front_default: Option<String>,
     pub other: Option<ApiOtherSprites>,
+    /// Shiny, female and back sprites, and the per-game sets under `versions`.
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct ApiOtherSprites {
     #[serde(rename = "official-artwork")]
     pub official_artwork: Option<ApiOfficialArtwork>,
+    /// Other sets: home, dream_world, showdown, ...
+    #[serde(flatten)]
+    pub extra: serde_json::Map<String, serde_json::Value>,
 }
 
 #[derive(Debug, Deserialize)]
 pub struct ApiOfficialArtwork {
     pub front_default: Option<String>,
+    #[serde(default)]
+    pub front_shiny: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -92,10 +104,27 @@ pub struct ParsedPokemon {
     pub sprite_url: Option<String>,
     pub height: Option<i64>,
     pub weight: Option<i64>,
+    pub base_experience: Option<i64>,
+    /// EV yield per stat, in hp/atk/def/spa/spd/spe order.
+    pub ev_yield: [i64; 6],
     pub species_url: String,
     pub abilities: Vec<ParsedAbility>,
     pub moves: Vec<ParsedPokemonMove>,
     pub version_group_moves: Vec<ParsedVersionGroupMove>,
+    pub sprites: Vec<ParsedSpriteVariant>,
+}
+
+/// One sprite of a pokemon, e.g. the shiny front sprite of the Emerald set.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ParsedSpriteVariant {
+    /// "default", "official-artwork", "home", "showdown", or a game's set ("emerald", ...).
+    pub source: String,
+    /// Generation of a game's set.
+    pub generation: Option<i64>,
+    pub animated: bool,
+    /// "front_default", "front_shiny", "back_female", ...
+    pub variant: String,
+    pub url: String,
 }
 
 #[derive(Debug, Clone)]
@@ -151,6 +180,22 @@ fn parse_pokemon(api: ApiPokemon) -> ParsedPokemon {
     let spd = stat("special-defense");
     let spe = stat("speed");
 
+    let effort = |name: &str| -> i64 {
+        api.stats
+            .iter()
+            .find(|s| s.stat.name == name)
+            .map(|s| s.effort)
+            .unwrap_or(0)
+    };
+    let ev_yield = [
+        effort("hp"),
+        effort("attack"),
+        effort("defense"),
+        effort("special-attack"),
+        effort("special-defense"),
+        effort("speed"),
+    ];
+
     // Prefer official artwork, fall back to front_default
     let sprite_url = api
         .sprites
@@ -158,7 +203,8 @@ fn parse_pokemon(api: ApiPokemon) -> ParsedPokemon {
         .as_ref()
         .and_then(|o| o.official_artwork.as_ref())
         .and_then(|a| a.front_default.clone())
-        .or(api.sprites.front_default);
+        .or(api.sprites.front_default.clone());
+    let sprites = parse_sprite_variants(&api.sprites);
 
     let abilities = api
         .abilities
@@ -215,9 +261,87 @@ fn parse_pokemon(api: ApiPokemon) -> ParsedPokemon {
         sprite_url,
         height: api.height,
         weight: api.weight,
+        base_experience: api.base_experience,
+        ev_yield,
         species_url: api.species.url,
         abilities,
         moves,
         version_group_moves,
+        sprites,
+    }
+}
+
+/// Roman numerals of PokéAPI's generation names ("generation-iii").
+const GENERATION_NUMERALS: &[&str] = &["i", "ii", "iii", "iv", "v", "vi", "vii", "viii", "ix"];
+
+/// Flatten every sprite URL: the default set, the `other` sets and the per-game sets of
+/// `versions` (animated sub-sets, like Black/White's, become their own "<game>-animated" set).
+fn parse_sprite_variants(sprites: &ApiSprites) -> Vec<ParsedSpriteVariant> {
+    let mut variants = Vec::new();
+    let mut push_set = |source: &str,
+                        generation: Option<i64>,
+                        animated: bool,
+                        set: &serde_json::Map<String, serde_json::Value>| {
+        for (variant, url) in set {
+            if let Some(url) = url.as_str() {
+                variants.push(ParsedSpriteVariant {
+                    source: source.to_string(),
+                    generation,
+                    animated,
+                    variant: variant.clone(),
+                    url: url.to_string(),
+                });
+            }
+        }
+    };
+
+    let mut default_set = sprites.extra.clone();
+    if let Some(url) = &sprites.front_default {
+        default_set.insert("front_default".to_string(), serde_json::Value::from(url.as_str()));
     }
+    push_set("default", None, false, &default_set);
+
+    if let Some(other) = &sprites.other {
+        if let Some(artwork) = &other.official_artwork {
+            let mut set = serde_json::Map::new();
+            for (variant, url) in [("front_default", &artwork.front_default), ("front_shiny", &artwork.front_shiny)] {
+                if let Some(url) = url {
+                    set.insert(variant.to_string(), serde_json::Value::from(url.as_str()));
+                }
+            }
+            push_set("official-artwork", None, false, &set);
+        }
+        for (name, set) in &other.extra {
+            if let Some(set) = set.as_object() {
+                let source = name.replace('_', "-");
+                push_set(&source, None, source == "showdown", set);
+            }
+        }
+    }
+
+    let versions = sprites.extra.get("versions").and_then(|v| v.as_object());
+    for (generation_name, games) in versions.into_iter().flatten() {
+        let generation = generation_name
+            .strip_prefix("generation-")
+            .and_then(|numeral| GENERATION_NUMERALS.iter().position(|n| *n == numeral))
+            .map(|index| index as i64 + 1);
+        for (game, set) in games.as_object().into_iter().flatten() {
+            let set = match set.as_object() {
+                Some(set) => set,
+                None => continue,
+            };
+            // Menu icons exist for several generations under the same name
+            let source = if game == "icons" {
+                format!("{}-icons", generation_name)
+            } else {
+                game.clone()
+            };
+            push_set(&source, generation, false, set);
+            if let Some(animated) = set.get("animated").and_then(|a| a.as_object()) {
+                push_set(&format!("{}-animated", source), generation, true, animated);
+            }
+        }
+    }
+
+    variants
 }
@@ -1,6 +1,7 @@
 use serde::Deserialize;
 
 use super::client::PokeApiClient;
+use super::localization::{localized, LocalizedText};
 
 // ── PokéAPI species response structs ────────────────────────────────
 
@@ -11,6 +12,39 @@ pub struct ApiPokemonSpecies {
     pub names: Vec<ApiName>,
     pub flavor_text_entries: Vec<ApiFlavorText>,
     pub evolution_chain: Option<ApiEvolutionChainRef>,
+    #[serde(default)]
+    pub capture_rate: Option<i64>,
+    #[serde(default)]
+    pub base_happiness: Option<i64>,
+    #[serde(default)]
+    pub gender_rate: Option<i64>,
+    #[serde(default)]
+    pub hatch_counter: Option<i64>,
+    pub growth_rate: Option<ApiNamedRef>,
+    #[serde(default)]
+    pub egg_groups: Vec<ApiNamedRef>,
+    #[serde(default)]
+    pub genera: Vec<ApiGenus>,
+    #[serde(default)]
+    pub is_legendary: bool,
+    #[serde(default)]
+    pub is_mythical: bool,
+    #[serde(default)]
+    pub is_baby: bool,
+    pub generation: Option<ApiNamedRef>,
+    pub evolves_from_species: Option<ApiNamedRef>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiNamedRef {
+    pub name: String,
+    pub url: String,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiGenus {
+    pub genus: String,
+    pub language: ApiLanguageRef,
 }
 
 #[derive(Debug, Deserialize)]
@@ -44,6 +78,43 @@ pub struct ParsedSpecies {
     pub description_en: Option<String>,
     pub description_fr: Option<String>,
     pub evolution_chain_id: Option<i64>,
+    pub name_key: String,
+    pub capture_rate: Option<i64>,
+    pub base_happiness: Option<i64>,
+    /// Chance of being female in eighths, -1 for genderless.
+    pub gender_rate: Option<i64>,
+    pub growth_rate: Option<String>,
+    pub hatch_counter: Option<i64>,
+    pub genus_en: Option<String>,
+    pub genus_fr: Option<String>,
+    pub is_legendary: bool,
+    pub is_mythical: bool,
+    pub is_baby: bool,
+    pub generation: Option<i64>,
+    pub evolves_from_species_id: Option<i64>,
+    pub egg_groups: Vec<String>,
+    /// Texts in every language PokéAPI provides.
+    pub texts: Vec<LocalizedText>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiGrowthRate {
+    pub name: String,
+    pub levels: Vec<ApiGrowthRateLevel>,
+}
+
+#[derive(Debug, Deserialize)]
+pub struct ApiGrowthRateLevel {
+    pub level: i64,
+    pub experience: i64,
+}
+
+/// Total experience needed for each level of a growth rate.
+#[derive(Debug, Clone)]
+pub struct ParsedGrowthRate {
+    pub name: String,
+    /// (level, total experience)
+    pub levels: Vec<(i64, i64)>,
 }
 
 impl PokeApiClient {
@@ -53,6 +124,16 @@ impl PokeApiClient {
         Ok(parse_species(api))
     }
 
+    /// Fetch a growth rate's experience table by ID.
+    pub async fn fetch_growth_rate(&self, id: i64) -> Result<ParsedGrowthRate, reqwest::Error> {
+        let url = self.url(&format!("growth-rate/{}", id));
+        let api: ApiGrowthRate = self.get_json(&url).await?;
+        Ok(ParsedGrowthRate {
+            name: api.name,
+            levels: api.levels.into_iter().map(|l| (l.level, l.experience)).collect(),
+        })
+    }
+
     /// Fetch species data by pokemon ID.
     pub async fn fetch_species(&self, id: i64) -> Result<ParsedSpecies, reqwest::Error> {
         let url = self.url(&format!("pokemon-species/{}", id));
@@ -92,6 +173,24 @@ fn parse_species(api: ApiPokemonSpecies) -> ParsedSpecies {
         .as_ref()
         .and_then(|ec| PokeApiClient::id_from_url(&ec.url));
 
+    let genus = |lang: &str| {
+        api.genera
+            .iter()
+            .find(|g| g.language.name == lang)
+            .map(|g| g.genus.clone())
+    };
+    let genus_en = genus("en");
+    let genus_fr = genus("fr");
+
+    let mut texts = localized("name", api.names.iter().map(|n| (n.language.name.as_str(), n.name.as_str())));
+    let descriptions: Vec<(&str, String)> = api
+        .flavor_text_entries
+        .iter()
+        .map(|f| (f.language.name.as_str(), clean_flavor_text(&f.flavor_text)))
+        .collect();
+    texts.extend(localized("description", descriptions.iter().map(|(lang, text)| (*lang, text.as_str()))));
+    texts.extend(localized("genus", api.genera.iter().map(|g| (g.language.name.as_str(), g.genus.as_str()))));
+
     ParsedSpecies {
         id: api.id,
         name_en,
@@ -99,6 +198,24 @@ fn parse_species(api: ApiPokemonSpecies) -> ParsedSpecies {
         description_en,
         description_fr,
         evolution_chain_id,
+        name_key: api.name,
+        capture_rate: api.capture_rate,
+        base_happiness: api.base_happiness,
+        gender_rate: api.gender_rate,
+        growth_rate: api.growth_rate.map(|g| g.name),
+        hatch_counter: api.hatch_counter,
+        genus_en,
+        genus_fr,
+        is_legendary: api.is_legendary,
+        is_mythical: api.is_mythical,
+        is_baby: api.is_baby,
+        generation: api.generation.as_ref().and_then(|g| PokeApiClient::id_from_url(&g.url)),
+        evolves_from_species_id: api
+            .evolves_from_species
+            .as_ref()
+            .and_then(|s| PokeApiClient::id_from_url(&s.url)),
+        egg_groups: api.egg_groups.into_iter().map(|e| e.name).collect(),
+        texts,
     }
 }
 
@@ -1,6 +1,7 @@
 use serde::Deserialize;
 
 use super::client::PokeApiClient;
+use super::localization::{localized, LocalizedText};
 
 // ── PokéAPI type response structs ───────────────────────────────────
 
@@ -10,6 +11,16 @@ pub struct ApiType {
     pub name: String,
     pub names: Vec<ApiTypeName>,
     pub damage_relations: ApiDamageRelations,
+    #[serde(default)]
+    pub past_damage_relations: Vec<ApiPastDamageRelations>,
+    pub generation: Option<ApiTypeRef>,
+}
+
+/// Damage relations as they were up to (and including) `generation`.
+#[derive(Debug, Deserialize)]
+pub struct ApiPastDamageRelations {
+    pub generation: ApiTypeRef,
+    pub damage_relations: ApiDamageRelations,
 }
 
 #[derive(Debug, Deserialize)]
@@ -46,6 +57,27 @@ pub struct ParsedType {
     pub name_key: String,
     pub name_en: Option<String>,
     pub name_fr: Option<String>,
+    /// Generation the type was introduced in.
+    pub generation: Option<i64>,
+    /// Texts in every language PokéAPI provides.
+    pub texts: Vec<LocalizedText>,
+}
+
+/// Damage factors of one type, as attacker (`to`) and as defender (`from`), by other type id.
+#[derive(Debug, Clone, Default)]
+pub struct ParsedDamageRelations {
+    pub to: Vec<(i64, i64)>,
+    pub from: Vec<(i64, i64)>,
+}
+
+/// Current and past damage relations of a type, used to build per-generation charts.
+#[derive(Debug, Clone)]
+pub struct ParsedTypeRelations {
+    pub type_id: i64,
+    pub generation: Option<i64>,
+    pub current: ParsedDamageRelations,
+    /// (last generation the relations applied to, relations), oldest first.
+    pub past: Vec<(i64, ParsedDamageRelations)>,
 }
 
 /// Parsed type efficacy entry.
@@ -58,10 +90,59 @@ pub struct ParsedTypeEfficacy {
 
 impl PokeApiClient {
     /// Fetch a single type by ID from PokéAPI.
-    pub async fn fetch_type(&self, id: i64) -> Result<(ParsedType, Vec<ParsedTypeEfficacy>), reqwest::Error> {
+    pub async fn fetch_type(
+        &self,
+        id: i64,
+    ) -> Result<(ParsedType, Vec<ParsedTypeEfficacy>, ParsedTypeRelations), reqwest::Error> {
         let url = self.url(&format!("type/{}", id));
         let api: ApiType = self.get_json(&url).await?;
-        Ok(parse_type(api))
+        let relations = parse_type_relations(&api);
+        let (parsed_type, efficacies) = parse_type(api);
+        Ok((parsed_type, efficacies, relations))
+    }
+}
+
+fn parse_type_relations(api: &ApiType) -> ParsedTypeRelations {
+    let mut past: Vec<(i64, ParsedDamageRelations)> = api
+        .past_damage_relations
+        .iter()
+        .filter_map(|p| {
+            let generation = PokeApiClient::id_from_url(&p.generation.url)?;
+            Some((generation, parse_damage_relations(&p.damage_relations)))
+        })
+        .collect();
+    past.sort_by_key(|(generation, _)| *generation);
+
+    ParsedTypeRelations {
+        type_id: api.id,
+        generation: api.generation.as_ref().and_then(|g| PokeApiClient::id_from_url(&g.url)),
+        current: parse_damage_relations(&api.damage_relations),
+        past,
+    }
+}
+
+fn parse_damage_relations(relations: &ApiDamageRelations) -> ParsedDamageRelations {
+    let factors = |groups: [(&Vec<ApiTypeRef>, i64); 3]| -> Vec<(i64, i64)> {
+        groups
+            .iter()
+            .flat_map(|(refs, factor)| {
+                refs.iter()
+                    .filter_map(move |r| PokeApiClient::id_from_url(&r.url).map(|id| (id, *factor)))
+            })
+            .collect()
+    };
+
+    ParsedDamageRelations {
+        to: factors([
+            (&relations.double_damage_to, 200),
+            (&relations.half_damage_to, 50),
+            (&relations.no_damage_to, 0),
+        ]),
+        from: factors([
+            (&relations.double_damage_from, 200),
+            (&relations.half_damage_from, 50),
+            (&relations.no_damage_from, 0),
+        ]),
     }
 }
 
@@ -83,6 +164,8 @@ fn parse_type(api: ApiType) -> (ParsedType, Vec<ParsedTypeEfficacy>) {
         name_key: api.name,
         name_en,
         name_fr,
+        generation: api.generation.as_ref().and_then(|g| PokeApiClient::id_from_url(&g.url)),
+        texts: localized("name", api.names.iter().map(|n| (n.language.name.as_str(), n.name.as_str()))),
     };
 
     // Build efficacy entries from damage_relations
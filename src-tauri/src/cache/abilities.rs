@@ -28,6 +28,8 @@ pub async fn upsert_ability(pool: &SqlitePool, ability: &ParsedAbility) -> Resul
     .execute(pool)
     .await?;
 
+    super::localization::replace_localized_texts(pool, "ability", ability.id, &ability.texts).await?;
+
     Ok(())
 }
 
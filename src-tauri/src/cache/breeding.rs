@@ -0,0 +1,189 @@
+use std::collections::{HashMap, VecDeque};
+
+use sqlx::SqlitePool;
+
+use crate::models::{BreedingCompatibility, BreedingStep};
+
+/// Egg group of species that can't breed at all.
+pub const NO_EGGS_GROUP: &str = "no-eggs";
+/// Egg group of Ditto, which breeds with anything else that can breed.
+pub const DITTO_GROUP: &str = "ditto";
+/// Learn method of egg moves.
+pub const EGG_METHOD: &str = "egg";
+
+/// Breeding-relevant species data of a pokemon.
+#[derive(Debug, Clone)]
+pub struct BreedingProfile {
+    /// Chance of being female in eighths, -1 for genderless.
+    pub gender_rate: i64,
+    pub egg_groups: Vec<String>,
+}
+
+impl BreedingProfile {
+    pub fn can_be_male(&self) -> bool {
+        (0..=7).contains(&self.gender_rate)
+    }
+
+    pub fn can_be_female(&self) -> bool {
+        (1..=8).contains(&self.gender_rate)
+    }
+
+    fn can_breed(&self) -> bool {
+        !self.egg_groups.is_empty() && !self.egg_groups.iter().any(|g| g == NO_EGGS_GROUP)
+    }
+
+    fn is_ditto(&self) -> bool {
+        self.egg_groups.iter().any(|g| g == DITTO_GROUP)
+    }
+
+    fn shared_egg_groups(&self, other: &BreedingProfile) -> Vec<String> {
+        self.egg_groups
+            .iter()
+            .filter(|g| other.egg_groups.contains(g))
+            .cloned()
+            .collect()
+    }
+
+    /// Whether `self` as father and `mother` share an egg group and have matching genders.
+    pub fn can_father(&self, mother: &BreedingProfile) -> bool {
+        self.can_breed()
+            && mother.can_breed()
+            && !self.is_ditto()
+            && !mother.is_ditto()
+            && self.can_be_male()
+            && mother.can_be_female()
+            && !self.shared_egg_groups(mother).is_empty()
+    }
+}
+
+/// Load the breeding profile of every pokemon with synced species data, by pokemon name key.
+pub async fn load_breeding_profiles(
+    pool: &SqlitePool,
+) -> Result<HashMap<String, BreedingProfile>, sqlx::Error> {
+    let rows: Vec<(String, i64, Option<String>)> = sqlx::query_as(
+        "SELECT p.name_key, s.gender_rate, seg.egg_group
+         FROM pokemon p
+         JOIN species s ON s.species_id = COALESCE(p.species_id, p.id)
+         LEFT JOIN species_egg_groups seg ON seg.species_id = s.species_id
+         WHERE s.gender_rate IS NOT NULL
+         ORDER BY p.name_key, seg.egg_group"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    let mut profiles: HashMap<String, BreedingProfile> = HashMap::new();
+    for (pokemon, gender_rate, egg_group) in rows {
+        let profile = profiles.entry(pokemon).or_insert_with(|| BreedingProfile {
+            gender_rate,
+            egg_groups: Vec::new(),
+        });
+        if let Some(egg_group) = egg_group {
+            profile.egg_groups.push(egg_group);
+        }
+    }
+
+    Ok(profiles)
+}
+
+/// Whether two pokemon can produce an egg together (in either role, or with Ditto).
+pub fn compatibility(a: &BreedingProfile, b: &BreedingProfile) -> BreedingCompatibility {
+    let shared_egg_groups = a.shared_egg_groups(b);
+    let reason = if !a.can_breed() || !b.can_breed() {
+        Some("One of them can't breed".to_string())
+    } else if a.is_ditto() && b.is_ditto() {
+        Some("Two Ditto can't breed".to_string())
+    } else if a.is_ditto() || b.is_ditto() {
+        None
+    } else if shared_egg_groups.is_empty() {
+        Some("No shared egg group".to_string())
+    } else if !a.can_father(b) && !b.can_father(a) {
+        Some("No male/female pairing possible".to_string())
+    } else {
+        None
+    };
+
+    BreedingCompatibility {
+        compatible: reason.is_none(),
+        shared_egg_groups,
+        reason,
+    }
+}
+
+/// Shortest breeding chain passing a move from a pokemon learning it by other means to
+/// `target`. Each step has a father knowing the move and a mother whose species gets it
+/// as an egg move; the offspring can then father the next step.
+/// `learners` maps pokemon to their learn methods for the move.
+pub fn shortest_breeding_chain(
+    target: &str,
+    learners: &HashMap<String, Vec<String>>,
+    profiles: &HashMap<String, BreedingProfile>,
+) -> Option<Vec<BreedingStep>> {
+    let learns_directly = |pokemon: &str| {
+        learners
+            .get(pokemon)
+            .and_then(|methods| methods.iter().find(|m| *m != EGG_METHOD))
+    };
+    let has_egg_move = |pokemon: &str| {
+        learners
+            .get(pokemon)
+            .is_some_and(|methods| methods.iter().any(|m| m == EGG_METHOD))
+    };
+
+    if learns_directly(target).is_some() {
+        return Some(Vec::new());
+    }
+    let target_profile = profiles.get(target)?;
+    if !has_egg_move(target) || !target_profile.can_be_female() {
+        return None;
+    }
+
+    // BFS from every pokemon knowing the move without breeding; a node's parent is the
+    // father it got the move from.
+    let mut sorted: Vec<&String> = learners.keys().collect();
+    sorted.sort();
+    let mut parent: HashMap<&str, Option<&str>> = HashMap::new();
+    let mut queue: VecDeque<&str> = VecDeque::new();
+    for pokemon in &sorted {
+        if learns_directly(pokemon).is_some() && profiles.contains_key(pokemon.as_str()) {
+            parent.insert(pokemon.as_str(), None);
+            queue.push_back(pokemon.as_str());
+        }
+    }
+
+    while let Some(father) = queue.pop_front() {
+        let father_profile = &profiles[father];
+        for mother in &sorted {
+            let mother = mother.as_str();
+            if parent.contains_key(mother) || !has_egg_move(mother) {
+                continue;
+            }
+            let mother_profile = match profiles.get(mother) {
+                Some(p) => p,
+                None => continue,
+            };
+            if !father_profile.can_father(mother_profile) {
+                continue;
+            }
+
+            parent.insert(mother, Some(father));
+            if mother == target {
+                let mut steps = Vec::new();
+                let mut child = mother;
+                while let Some(Some(father)) = parent.get(child) {
+                    let method = learns_directly(father).map(String::as_str).unwrap_or(EGG_METHOD);
+                    steps.push(BreedingStep {
+                        father: father.to_string(),
+                        mother: child.to_string(),
+                        father_learn_method: method.to_string(),
+                    });
+                    child = father;
+                }
+                steps.reverse();
+                return Some(steps);
+            }
+            queue.push_back(mother);
+        }
+    }
+
+    None
+}
@@ -0,0 +1,137 @@
+use crate::models::CatchConditions;
+
+/// Ball modifiers that don't depend on the encounter.
+const FLAT_BALL_MODIFIERS: &[(&str, f64)] = &[
+    ("poke-ball", 1.0),
+    ("great-ball", 1.5),
+    ("ultra-ball", 2.0),
+    ("safari-ball", 1.5),
+    ("sport-ball", 1.5),
+    ("premier-ball", 1.0),
+    ("luxury-ball", 1.0),
+    ("heal-ball", 1.0),
+    ("cherish-ball", 1.0),
+    ("friend-ball", 1.0),
+    ("beast-ball", 0.1),
+];
+
+/// Balls that always catch.
+const GUARANTEED_BALLS: &[&str] = &["master-ball", "park-ball"];
+
+/// What a throw targets, for ball modifiers.
+pub struct CatchTarget<'a> {
+    pub level: i64,
+    pub types: &'a [String],
+    pub status: Option<&'a str>,
+}
+
+/// Whether a ball catches regardless of the odds.
+pub fn is_guaranteed_ball(ball: &str) -> bool {
+    GUARANTEED_BALLS.contains(&ball)
+}
+
+/// Modifier of a ball on a given turn (1-based) under a generation's rules.
+/// Unknown balls count as a Poké Ball.
+pub fn ball_modifier(
+    ball: &str,
+    generation: i64,
+    turn: i64,
+    target: &CatchTarget,
+    conditions: &CatchConditions,
+) -> f64 {
+    let has_type = |t: &str| target.types.iter().any(|k| k == t);
+    let turns_passed = (turn - 1).max(0) as f64;
+
+    match ball {
+        "net-ball" if has_type("water") || has_type("bug") => {
+            if generation >= 7 { 3.5 } else { 3.0 }
+        }
+        "dive-ball" if conditions.on_water => 3.5,
+        "nest-ball" => {
+            let modifier = if generation <= 4 {
+                (40 - target.level) as f64 / 10.0
+            } else {
+                (41 - target.level) as f64 / 10.0
+            };
+            modifier.max(1.0)
+        }
+        "repeat-ball" if conditions.already_caught => {
+            if generation >= 7 { 3.5 } else { 3.0 }
+        }
+        "timer-ball" => {
+            if generation <= 4 {
+                ((turns_passed + 10.0) / 10.0).min(4.0)
+            } else {
+                (1.0 + turns_passed * 1229.0 / 4096.0).min(4.0)
+            }
+        }
+        "quick-ball" if turn <= 1 => {
+            if generation <= 4 { 4.0 } else { 5.0 }
+        }
+        "dusk-ball" if conditions.is_night || conditions.in_cave => {
+            if generation >= 7 { 3.0 } else { 3.5 }
+        }
+        "lure-ball" if conditions.fishing => 3.0,
+        "dream-ball" if target.status == Some("sleep") && generation >= 8 => 4.0,
+        _ => FLAT_BALL_MODIFIERS
+            .iter()
+            .find(|(name, _)| *name == ball)
+            .map(|(_, modifier)| *modifier)
+            .unwrap_or(1.0),
+    }
+}
+
+/// Status modifier: sleep and freeze weigh more than the other major statuses.
+pub fn status_modifier(status: Option<&str>, generation: i64) -> f64 {
+    match status {
+        Some("sleep") | Some("freeze") => {
+            if generation <= 4 { 2.0 } else { 2.5 }
+        }
+        Some("paralysis") | Some("poison") | Some("burn") => 1.5,
+        _ => 1.0,
+    }
+}
+
+/// Chance of one throw catching, Gen 3/4 formula: the modified rate `a` out of 255,
+/// then four shake checks of 65536 against `b`.
+pub fn catch_chance_gen3(capture_rate: i64, hp_percent: f64, ball: f64, status: f64) -> f64 {
+    let hp = (hp_percent / 100.0).clamp(0.01, 1.0);
+    let a = ((3.0 - 2.0 * hp) * capture_rate as f64 * ball / 3.0).floor() * status;
+    if a >= 255.0 {
+        return 1.0;
+    }
+    if a <= 0.0 {
+        return 0.0;
+    }
+
+    let b = (1048560.0 / (16711680.0 / a).sqrt().sqrt().floor()).floor();
+    (b / 65536.0).powi(4).min(1.0)
+}
+
+/// Chance of one throw catching, Gen 5+ formula: the rate on a 4096 scale (with the low
+/// level bonus of Gen 8+), then three shake checks in Gen 5 and four from Gen 6.
+pub fn catch_chance_gen5(
+    capture_rate: i64,
+    hp_percent: f64,
+    level: i64,
+    ball: f64,
+    status: f64,
+    generation: i64,
+) -> f64 {
+    let hp = (hp_percent / 100.0).clamp(0.01, 1.0);
+    let mut a = (3.0 - 2.0 * hp) * 4096.0 * capture_rate as f64 * ball / 3.0 * status;
+    if generation >= 8 && level < 20 {
+        a *= (30 - level) as f64 / 10.0;
+    }
+    let a = a.floor();
+    if a >= 1044480.0 {
+        return 1.0;
+    }
+    if a <= 0.0 {
+        return 0.0;
+    }
+
+    let b = (65536.0 / (1044480.0 / a).powf(0.1875)).floor();
+    let checks = if generation <= 5 { 3 } else { 4 };
+    (b / 65536.0).powi(checks).min(1.0)
+}
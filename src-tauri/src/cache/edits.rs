@@ -0,0 +1,366 @@
+use serde::Serialize;
+use sqlx::{SqliteConnection, SqlitePool};
+
+use super::{games, inheritance, machines};
+use crate::models::games::{AbilityOverrideEntry, ItemLocationEntry, LearnsetEntry};
+use crate::models::{GameEdit, GameEditChange};
+
+/// Raw game_edits row; before/after are stored as JSON.
+#[derive(sqlx::FromRow)]
+struct GameEditRow {
+    id: i64,
+    game_id: String,
+    entity: String,
+    action: String,
+    target_key: String,
+    before_json: Option<String>,
+    after_json: Option<String>,
+    author: String,
+    created_at: String,
+}
+
+impl GameEditChange {
+    pub fn entity(&self) -> &'static str {
+        match self {
+            GameEditChange::Learnset { .. } => "learnset",
+            GameEditChange::Ability { .. } => "ability",
+            GameEditChange::PokemonLocation { .. } => "pokemon_location",
+            GameEditChange::MoveOverride { .. } => "move_override",
+            GameEditChange::ItemLocation { .. } => "item_location",
+        }
+    }
+
+    pub fn target_key(&self) -> &str {
+        match self {
+            GameEditChange::Learnset { pokemon_name_key, .. }
+            | GameEditChange::Ability { pokemon_name_key, .. }
+            | GameEditChange::PokemonLocation { pokemon_name_key, .. } => pokemon_name_key,
+            GameEditChange::MoveOverride { move_name_key, .. } => move_name_key,
+            GameEditChange::ItemLocation { item_name_key, .. } => item_name_key,
+        }
+    }
+
+    /// Before/after values as JSON.
+    fn values(&self) -> (Option<String>, Option<String>) {
+        fn json<T: Serialize>(value: &Option<T>) -> Option<String> {
+            value.as_ref().and_then(|v| serde_json::to_string(v).ok())
+        }
+
+        match self {
+            GameEditChange::Learnset { before, after, .. } => (json(before), json(after)),
+            GameEditChange::Ability { before, after, .. } => (json(before), json(after)),
+            GameEditChange::PokemonLocation { before, after, .. } => (json(before), json(after)),
+            GameEditChange::MoveOverride { before, after, .. } => (json(before), json(after)),
+            GameEditChange::ItemLocation { before, after, .. } => (json(before), json(after)),
+        }
+    }
+
+    pub fn action(&self) -> &'static str {
+        match self.values() {
+            (None, _) => "add",
+            (_, None) => "remove",
+            _ => "update",
+        }
+    }
+}
+
+impl GameEditRow {
+    /// Rebuild the typed change from the stored columns.
+    fn change(&self) -> Result<GameEditChange, serde_json::Error> {
+        let value = serde_json::json!({
+            "entity": self.entity,
+            "pokemon_name_key": self.target_key,
+            "move_name_key": self.target_key,
+            "item_name_key": self.target_key,
+            "before": parse_json(self.before_json.as_deref()),
+            "after": parse_json(self.after_json.as_deref()),
+        });
+        serde_json::from_value(value)
+    }
+}
+
+fn parse_json(json: Option<&str>) -> Option<serde_json::Value> {
+    json.and_then(|s| serde_json::from_str(s).ok())
+}
+
+/// Apply a change to a game's data tables, through `conn` (e.g. a transaction).
+/// Removing something that isn't there (or re-adding something present) is a no-op.
+/// Call `materialize_inherited` first so the edit doesn't hide inherited rows.
+pub async fn apply_edit(
+    conn: &mut SqliteConnection,
+    game_id: &str,
+    change: &GameEditChange,
+) -> Result<(), sqlx::Error> {
+    match change {
+        GameEditChange::Learnset { pokemon_name_key, before, after } => {
+            if let Some(entry) = before {
+                sqlx::query(
+                    "DELETE FROM game_pokemon_moves
+                     WHERE game_id = ?1 AND pokemon_name_key = ?2 AND move_name_key = ?3
+                       AND learn_method = ?4 AND level_learned_at = ?5"
+                )
+                .bind(game_id)
+                .bind(pokemon_name_key)
+                .bind(&entry.move_name_key)
+                .bind(&entry.learn_method)
+                .bind(entry.level)
+                .execute(&mut *conn)
+                .await?;
+            }
+            if let Some(entry) = after {
+                games::import_pokemon_move(&mut *conn, game_id, pokemon_name_key, entry).await?;
+            }
+        }
+        GameEditChange::Ability { pokemon_name_key, before, after } => {
+            if let Some(ability) = before {
+                sqlx::query(
+                    "DELETE FROM game_pokemon_abilities
+                     WHERE game_id = ?1 AND pokemon_name_key = ?2 AND slot = ?3"
+                )
+                .bind(game_id)
+                .bind(pokemon_name_key)
+                .bind(ability.slot)
+                .execute(&mut *conn)
+                .await?;
+            }
+            if let Some(ability) = after {
+                games::import_pokemon_ability(&mut *conn, game_id, pokemon_name_key, ability).await?;
+            }
+        }
+        GameEditChange::PokemonLocation { pokemon_name_key, before, after } => {
+            if let Some(location) = before {
+                sqlx::query(
+                    "DELETE FROM game_pokemon_locations
+                     WHERE game_id = ?1 AND pokemon_name_key = ?2 AND location = ?3"
+                )
+                .bind(game_id)
+                .bind(pokemon_name_key)
+                .bind(location)
+                .execute(&mut *conn)
+                .await?;
+            }
+            if let Some(location) = after {
+                games::import_pokemon_location(&mut *conn, game_id, pokemon_name_key, location).await?;
+            }
+        }
+        GameEditChange::MoveOverride { move_name_key, before, after } => {
+            if before.is_some() {
+                sqlx::query("DELETE FROM game_move_overrides WHERE game_id = ?1 AND move_name_key = ?2")
+                    .bind(game_id)
+                    .bind(move_name_key)
+                    .execute(&mut *conn)
+                    .await?;
+            }
+            if let Some(mo) = after {
+                games::import_move_override(&mut *conn, game_id, mo).await?;
+            }
+        }
+        GameEditChange::ItemLocation { item_name_key, before, after } => {
+            if let Some(location) = before {
+                sqlx::query(
+                    "DELETE FROM game_item_locations
+                     WHERE game_id = ?1 AND item_name_key = ?2 AND location = ?3"
+                )
+                .bind(game_id)
+                .bind(item_name_key)
+                .bind(location)
+                .execute(&mut *conn)
+                .await?;
+                machines::prune_game_machine(&mut *conn, game_id, item_name_key).await?;
+                sqlx::query(
+                    "DELETE FROM game_item_entries WHERE game_id = ?1 AND item_name_key = ?2
+                       AND NOT EXISTS (SELECT 1 FROM game_item_locations
+                                       WHERE game_id = ?1 AND item_name_key = ?2)"
+                )
+                .bind(game_id)
+                .bind(item_name_key)
+                .execute(&mut *conn)
+                .await?;
+            }
+            if let Some(location) = after {
+                let entry = ItemLocationEntry {
+                    name_key: item_name_key.clone(),
+                    locations: vec![location.clone()],
+                    machine: None,
+                };
+                games::import_item_locations(&mut *conn, game_id, &entry).await?;
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Before the first learnset or ability edit of a pokemon the game doesn't list itself,
+/// copy what it inherits into the game's layer (the resolvers use the first non-empty layer).
+/// Runs on the edit's connection, so the copy is kept only if the edit is.
+pub async fn materialize_inherited(
+    conn: &mut SqliteConnection,
+    game_id: &str,
+    change: &GameEditChange,
+) -> Result<(), sqlx::Error> {
+    match change {
+        GameEditChange::Learnset { pokemon_name_key, .. } => {
+            materialize_learnset(conn, game_id, pokemon_name_key).await
+        }
+        GameEditChange::Ability { pokemon_name_key, .. } => {
+            materialize_abilities(conn, game_id, pokemon_name_key).await
+        }
+        _ => Ok(()),
+    }
+}
+
+/// Copy the learnset a pokemon inherits into the game's layer, unless the game lists it.
+async fn materialize_learnset(
+    conn: &mut SqliteConnection,
+    game_id: &str,
+    pokemon_name_key: &str,
+) -> Result<(), sqlx::Error> {
+    let own: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM game_pokemon_moves WHERE game_id = ?1 AND pokemon_name_key = ?2"
+    )
+    .bind(game_id)
+    .bind(pokemon_name_key)
+    .fetch_one(&mut *conn)
+    .await?;
+    if own > 0 {
+        return Ok(());
+    }
+
+    let chain = inheritance::resolve_game_chain_on(&mut *conn, game_id).await?;
+    let inherited = inheritance::resolve_learnset_on(&mut *conn, &chain[1..], pokemon_name_key).await?;
+    for row in inherited {
+        let move_name_key = match row.name_key {
+            Some(key) => key,
+            None => continue,
+        };
+        let entry = LearnsetEntry {
+            move_name_key,
+            learn_method: row.learn_method,
+            level: row.level_learned_at,
+        };
+        games::import_pokemon_move(&mut *conn, game_id, pokemon_name_key, &entry).await?;
+    }
+
+    Ok(())
+}
+
+/// Copy the abilities a pokemon inherits into the game's layer, unless the game lists them.
+async fn materialize_abilities(
+    conn: &mut SqliteConnection,
+    game_id: &str,
+    pokemon_name_key: &str,
+) -> Result<(), sqlx::Error> {
+    let own: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM game_pokemon_abilities WHERE game_id = ?1 AND pokemon_name_key = ?2"
+    )
+    .bind(game_id)
+    .bind(pokemon_name_key)
+    .fetch_one(&mut *conn)
+    .await?;
+    if own > 0 {
+        return Ok(());
+    }
+
+    let chain = inheritance::resolve_game_chain_on(&mut *conn, game_id).await?;
+    let inherited = inheritance::resolve_pokemon_abilities_on(&mut *conn, &chain[1..], pokemon_name_key).await?;
+    for row in inherited {
+        let ability = AbilityOverrideEntry {
+            ability_key: row.ability_key,
+            slot: row.slot,
+            is_hidden: row.is_hidden != 0,
+        };
+        games::import_pokemon_ability(&mut *conn, game_id, pokemon_name_key, &ability).await?;
+    }
+
+    Ok(())
+}
+
+/// Append a change to the game edit log.
+pub async fn record_edit(
+    conn: &mut SqliteConnection,
+    game_id: &str,
+    change: &GameEditChange,
+    author: &str,
+) -> Result<i64, sqlx::Error> {
+    let (before_json, after_json) = change.values();
+    let now = chrono::Utc::now().to_rfc3339();
+
+    let id = sqlx::query(
+        "INSERT INTO game_edits (game_id, entity, action, target_key, before_json, after_json, author, created_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)"
+    )
+    .bind(game_id)
+    .bind(change.entity())
+    .bind(change.action())
+    .bind(change.target_key())
+    .bind(&before_json)
+    .bind(&after_json)
+    .bind(author)
+    .bind(&now)
+    .execute(&mut *conn)
+    .await?
+    .last_insert_rowid();
+
+    Ok(id)
+}
+
+/// Get the edit log of a game, oldest first.
+pub async fn get_game_edits(
+    pool: &SqlitePool,
+    game_id: &str,
+) -> Result<Vec<GameEdit>, sqlx::Error> {
+    let rows: Vec<GameEditRow> = sqlx::query_as(
+        "SELECT id, game_id, entity, action, target_key, before_json, after_json, author, created_at
+         FROM game_edits WHERE game_id = ?1 ORDER BY id"
+    )
+    .bind(game_id)
+    .fetch_all(pool)
+    .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|row| GameEdit {
+            before: parse_json(row.before_json.as_deref()),
+            after: parse_json(row.after_json.as_deref()),
+            id: row.id,
+            game_id: row.game_id,
+            entity: row.entity,
+            action: row.action,
+            target_key: row.target_key,
+            author: row.author,
+            created_at: row.created_at,
+        })
+        .collect())
+}
+
+/// Re-apply every logged edit of a game, in order (after its data was reimported,
+/// in the import's transaction).
+pub async fn replay_game_edits(
+    conn: &mut SqliteConnection,
+    game_id: &str,
+) -> Result<(), sqlx::Error> {
+    let rows: Vec<GameEditRow> = sqlx::query_as(
+        "SELECT id, game_id, entity, action, target_key, before_json, after_json, author, created_at
+         FROM game_edits WHERE game_id = ?1 ORDER BY id"
+    )
+    .bind(game_id)
+    .fetch_all(&mut *conn)
+    .await?;
+
+    for row in &rows {
+        match row.change() {
+            Ok(change) => {
+                materialize_inherited(&mut *conn, game_id, &change).await?;
+                apply_edit(&mut *conn, game_id, &change).await?;
+            }
+            Err(e) => log::warn!("Skipping unreadable edit {} of game '{}': {}", row.id, game_id, e),
+        }
+    }
+
+    if !rows.is_empty() {
+        log::info!("Replayed {} edits on game '{}'", rows.len(), game_id);
+    }
+
+    Ok(())
+}
@@ -1,8 +1,24 @@
+use std::collections::{HashMap, HashSet};
+
 use sqlx::SqlitePool;
 
-use crate::models::EvolutionNode;
+use crate::api::locations::ParsedLocation;
+use crate::models::{EvolutionCondition, EvolutionNode};
+
+/// English and French names keyed by name key (or id).
+type Names = HashMap<String, (Option<String>, Option<String>)>;
+
+/// Display data of the pokemon behind an evolution node.
+#[derive(sqlx::FromRow)]
+struct NodeRow {
+    id: i64,
+    name_en: Option<String>,
+    name_fr: Option<String>,
+    sprite_url: Option<String>,
+}
 
-/// Upsert an evolution chain record (stores the tree as JSON).
+/// Upsert an evolution chain record (stores the tree as JSON) and replace its
+/// structured evolution rows.
 pub async fn upsert_evolution_chain(
     pool: &SqlitePool,
     chain_id: i64,
@@ -10,6 +26,8 @@ pub async fn upsert_evolution_chain(
 ) -> Result<(), sqlx::Error> {
     let json = serde_json::to_string(root).unwrap_or_default();
 
+    let mut tx = pool.begin().await?;
+
     sqlx::query(
         "INSERT INTO evolution_chains (id, data)
          VALUES (?1, ?2)
@@ -18,12 +36,79 @@ pub async fn upsert_evolution_chain(
     )
     .bind(chain_id)
     .bind(&json)
-    .execute(pool)
+    .execute(&mut *tx)
     .await?;
 
+    sqlx::query("DELETE FROM pokemon_evolutions WHERE chain_id = ?1")
+        .bind(chain_id)
+        .execute(&mut *tx)
+        .await?;
+
+    let mut steps = Vec::new();
+    collect_steps(root, &mut steps);
+    for (from, to, index, c) in steps {
+        sqlx::query(
+            "INSERT OR REPLACE INTO pokemon_evolutions
+                (chain_id, from_species_id, to_species_id, detail_index, trigger, min_level,
+                 item, held_item, known_move, known_move_type, location, min_happiness,
+                 min_affection, min_beauty, time_of_day, gender, party_species, party_type,
+                 trade_species, relative_physical_stats, needs_overworld_rain, turn_upside_down)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16,
+                     ?17, ?18, ?19, ?20, ?21, ?22)"
+        )
+        .bind(chain_id)
+        .bind(from)
+        .bind(to)
+        .bind(index as i64)
+        .bind(&c.trigger)
+        .bind(c.min_level)
+        .bind(&c.item)
+        .bind(&c.held_item)
+        .bind(&c.known_move)
+        .bind(&c.known_move_type)
+        .bind(&c.location)
+        .bind(c.min_happiness)
+        .bind(c.min_affection)
+        .bind(c.min_beauty)
+        .bind(&c.time_of_day)
+        .bind(c.gender)
+        .bind(&c.party_species)
+        .bind(&c.party_type)
+        .bind(&c.trade_species)
+        .bind(c.relative_physical_stats)
+        .bind(c.needs_overworld_rain)
+        .bind(c.turn_upside_down)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
     Ok(())
 }
 
+/// Flatten a tree into (from species, to species, detail index, condition) steps.
+fn collect_steps<'a>(
+    node: &'a EvolutionNode,
+    steps: &mut Vec<(i64, i64, usize, &'a EvolutionCondition)>,
+) {
+    for child in &node.evolves_to {
+        if let (Some(from), Some(to)) = (node.pokemon_id, child.pokemon_id) {
+            for (index, condition) in child.conditions.iter().enumerate() {
+                steps.push((from, to, index, condition));
+            }
+        }
+        collect_steps(child, steps);
+    }
+}
+
+/// Whether structured evolution rows have been synced.
+pub async fn has_evolution_details(pool: &SqlitePool) -> Result<bool, sqlx::Error> {
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM pokemon_evolutions")
+        .fetch_one(pool)
+        .await?;
+    Ok(count > 0)
+}
+
 /// Retrieve an evolution chain by ID.
 pub async fn get_evolution_chain(
     pool: &SqlitePool,
@@ -38,3 +123,165 @@ pub async fn get_evolution_chain(
 
     Ok(row.and_then(|(data,)| serde_json::from_str(&data).ok()))
 }
+
+/// Upsert the localized names of a location.
+pub async fn upsert_location(pool: &SqlitePool, location: &ParsedLocation) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO locations (name_key, name_en, name_fr)
+         VALUES (?1, ?2, ?3)
+         ON CONFLICT(name_key) DO UPDATE SET
+           name_en = excluded.name_en,
+           name_fr = excluded.name_fr"
+    )
+    .bind(&location.name_key)
+    .bind(&location.name_en)
+    .bind(&location.name_fr)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Locations referenced by evolution conditions whose names aren't cached yet.
+pub async fn missing_evolution_locations(pool: &SqlitePool) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT DISTINCT e.location FROM pokemon_evolutions e
+         WHERE e.location IS NOT NULL
+           AND NOT EXISTS (SELECT 1 FROM locations l WHERE l.name_key = e.location)
+         ORDER BY e.location"
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// Fill in the names and sprites of every node from `pokemon` (joined by species id),
+/// and localize the names referenced by their conditions.
+pub async fn resolve_evolution_chain(
+    pool: &SqlitePool,
+    root: &mut EvolutionNode,
+) -> Result<(), sqlx::Error> {
+    let mut ids = Vec::new();
+    collect_ids(root, &mut ids);
+    let ids_json = serde_json::to_string(&ids).unwrap_or_default();
+
+    let rows: Vec<NodeRow> = sqlx::query_as(
+        "SELECT id, name_en, name_fr, sprite_url FROM pokemon
+         WHERE id IN (SELECT value FROM json_each(?1))"
+    )
+    .bind(&ids_json)
+    .fetch_all(pool)
+    .await?;
+    let pokemon: HashMap<i64, NodeRow> = rows.into_iter().map(|row| (row.id, row)).collect();
+
+    let mut conditions = Vec::new();
+    fill_nodes(root, &pokemon, &mut conditions);
+    localize_conditions(pool, conditions).await
+}
+
+fn collect_ids(node: &EvolutionNode, ids: &mut Vec<i64>) {
+    ids.extend(node.pokemon_id);
+    for child in &node.evolves_to {
+        collect_ids(child, ids);
+    }
+}
+
+fn fill_nodes<'a>(
+    node: &'a mut EvolutionNode,
+    pokemon: &HashMap<i64, NodeRow>,
+    conditions: &mut Vec<&'a mut EvolutionCondition>,
+) {
+    if let Some(row) = node.pokemon_id.and_then(|id| pokemon.get(&id)) {
+        node.name_en = row.name_en.clone();
+        node.name_fr = row.name_fr.clone();
+        node.sprite_url = row.sprite_url.clone();
+    }
+    conditions.extend(node.conditions.iter_mut());
+    for child in node.evolves_to.iter_mut() {
+        fill_nodes(child, pokemon, conditions);
+    }
+}
+
+/// Fill in the English and French names of the items, moves, types, locations and
+/// species referenced by evolution conditions.
+pub async fn localize_conditions(
+    pool: &SqlitePool,
+    conditions: Vec<&mut EvolutionCondition>,
+) -> Result<(), sqlx::Error> {
+    let mut items = HashSet::new();
+    let mut moves = HashSet::new();
+    let mut types = HashSet::new();
+    let mut locations = HashSet::new();
+    let mut species = HashSet::new();
+    for c in &conditions {
+        items.extend(c.item.clone());
+        items.extend(c.held_item.clone());
+        moves.extend(c.known_move.clone());
+        types.extend(c.known_move_type.clone());
+        types.extend(c.party_type.clone());
+        locations.extend(c.location.clone());
+        species.extend(c.party_species.clone());
+        species.extend(c.trade_species.clone());
+    }
+
+    let items = load_names(pool, "SELECT name_key, name_en, name_fr FROM items", &items).await?;
+    let moves = load_names(pool, "SELECT name_key, name_en, name_fr FROM moves", &moves).await?;
+    let types = load_names(pool, "SELECT name_key, name_en, name_fr FROM types", &types).await?;
+    let species = load_names(pool, "SELECT name_key, name_en, name_fr FROM species", &species).await?;
+    // Game files only carry one display name, used when PokéAPI names are missing
+    let locations = load_names(
+        pool,
+        "SELECT name_key, name_en, name_fr FROM locations
+         UNION ALL
+         SELECT location_key, MIN(name), NULL FROM game_locations
+         WHERE location_key NOT IN (SELECT name_key FROM locations)
+         GROUP BY location_key",
+        &locations,
+    )
+    .await?;
+
+    let lookup = |names: &Names, key: &Option<String>| {
+        key.as_ref()
+            .and_then(|k| names.get(k))
+            .cloned()
+            .unwrap_or_default()
+    };
+    for c in conditions {
+        (c.item_en, c.item_fr) = lookup(&items, &c.item);
+        (c.held_item_en, c.held_item_fr) = lookup(&items, &c.held_item);
+        (c.known_move_en, c.known_move_fr) = lookup(&moves, &c.known_move);
+        (c.known_move_type_en, c.known_move_type_fr) = lookup(&types, &c.known_move_type);
+        (c.location_en, c.location_fr) = lookup(&locations, &c.location);
+        (c.party_species_en, c.party_species_fr) = lookup(&species, &c.party_species);
+        (c.party_type_en, c.party_type_fr) = lookup(&types, &c.party_type);
+        (c.trade_species_en, c.trade_species_fr) = lookup(&species, &c.trade_species);
+    }
+
+    Ok(())
+}
+
+/// Load (name_key, name_en, name_fr) rows of `source` for the given keys.
+async fn load_names(
+    pool: &SqlitePool,
+    source: &str,
+    keys: &HashSet<String>,
+) -> Result<Names, sqlx::Error> {
+    if keys.is_empty() {
+        return Ok(Names::new());
+    }
+    let keys_json = serde_json::to_string(keys).unwrap_or_default();
+    let sql = format!(
+        "SELECT name_key, name_en, name_fr FROM ({})
+         WHERE name_key IN (SELECT value FROM json_each(?1))",
+        source
+    );
+
+    let rows: Vec<(String, Option<String>, Option<String>)> = sqlx::query_as(&sql)
+        .bind(&keys_json)
+        .fetch_all(pool)
+        .await?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(key, name_en, name_fr)| (key, (name_en, name_fr)))
+        .collect())
+}
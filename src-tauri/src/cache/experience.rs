@@ -0,0 +1,139 @@
+use sqlx::SqlitePool;
+
+use crate::api::species::ParsedGrowthRate;
+
+/// Replace the experience table of a growth rate.
+pub async fn replace_growth_rate(pool: &SqlitePool, growth_rate: &ParsedGrowthRate) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM growth_rate_levels WHERE growth_rate = ?1")
+        .bind(&growth_rate.name)
+        .execute(&mut *tx)
+        .await?;
+
+    for (level, experience) in &growth_rate.levels {
+        sqlx::query(
+            "INSERT INTO growth_rate_levels (growth_rate, level, experience) VALUES (?1, ?2, ?3)"
+        )
+        .bind(&growth_rate.name)
+        .bind(level)
+        .bind(experience)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Whether growth rate tables have been synced.
+pub async fn has_growth_rates(pool: &SqlitePool) -> Result<bool, sqlx::Error> {
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM growth_rate_levels")
+        .fetch_one(pool)
+        .await?;
+    Ok(count > 0)
+}
+
+/// Whether cached pokemon carry EV yields (synced before they were stored otherwise).
+pub async fn has_ev_yields(pool: &SqlitePool) -> Result<bool, sqlx::Error> {
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM pokemon WHERE ev_hp IS NOT NULL")
+        .fetch_one(pool)
+        .await?;
+    Ok(count > 0)
+}
+
+/// Options of an experience gain calculation.
+#[derive(Debug, Clone, Copy)]
+pub struct ExpGainFactors {
+    pub trainer_battle: bool,
+    pub participants: i64,
+    pub lucky_egg: bool,
+    pub traded: bool,
+}
+
+/// Experience from defeating a pokemon, flat formula of Gen 1-4 and Gen 6: base experience
+/// times level over 7, split between participants up to Gen 4, then 1.5x per bonus.
+pub fn exp_gain_flat(
+    base_experience: i64,
+    defeated_level: i64,
+    generation: i64,
+    factors: ExpGainFactors,
+) -> i64 {
+    let mut exp = base_experience * defeated_level / 7;
+    if generation <= 4 {
+        exp /= factors.participants.max(1);
+    }
+    for bonus in [factors.trainer_battle, factors.traded, factors.lucky_egg] {
+        if bonus {
+            exp = exp * 3 / 2;
+        }
+    }
+    exp.max(1)
+}
+
+/// Experience from defeating a pokemon, scaled formula of Gen 5 and Gen 7 onwards: the level
+/// gap between the winner and the defeated pokemon scales the gain. Participants only split
+/// it in Gen 5, and the trainer bonus is gone from Gen 7.
+pub fn exp_gain_scaled(
+    base_experience: i64,
+    defeated_level: i64,
+    winner_level: i64,
+    generation: i64,
+    factors: ExpGainFactors,
+) -> i64 {
+    let trainer = if factors.trainer_battle && generation <= 6 { 1.5 } else { 1.0 };
+    let participants = if generation <= 5 { factors.participants.max(1) } else { 1 };
+
+    let base = trainer * (base_experience * defeated_level) as f64 / (5 * participants) as f64;
+    let scale = ((2 * defeated_level + 10) as f64 / (defeated_level + winner_level + 10) as f64).powf(2.5);
+    let mut exp = (base * scale).floor() as i64 + 1;
+    for bonus in [factors.traded, factors.lucky_egg] {
+        if bonus {
+            exp = exp * 3 / 2;
+        }
+    }
+    exp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const WILD: ExpGainFactors = ExpGainFactors {
+        trainer_battle: false,
+        participants: 1,
+        lucky_egg: false,
+        traded: false,
+    };
+
+    // Bulbasaur (base experience 64) defeated at level 10
+
+    #[test]
+    fn flat_formula() {
+        assert_eq!(exp_gain_flat(64, 10, 3, WILD), 91);
+        assert_eq!(exp_gain_flat(64, 10, 3, ExpGainFactors { trainer_battle: true, ..WILD }), 136);
+        assert_eq!(exp_gain_flat(64, 10, 4, ExpGainFactors { participants: 2, ..WILD }), 45);
+        assert_eq!(
+            exp_gain_flat(64, 10, 4, ExpGainFactors { trainer_battle: true, lucky_egg: true, ..WILD }),
+            204
+        );
+    }
+
+    #[test]
+    fn flat_formula_gen6_does_not_split() {
+        assert_eq!(exp_gain_flat(64, 10, 6, ExpGainFactors { participants: 2, ..WILD }), 91);
+    }
+
+    #[test]
+    fn scaled_formula() {
+        assert_eq!(exp_gain_scaled(64, 10, 10, 5, WILD), 129);
+        assert_eq!(exp_gain_scaled(64, 10, 5, 5, WILD), 202);
+        assert_eq!(exp_gain_scaled(64, 10, 5, 5, ExpGainFactors { trainer_battle: true, ..WILD }), 303);
+        assert_eq!(exp_gain_scaled(64, 10, 10, 5, ExpGainFactors { participants: 2, ..WILD }), 65);
+    }
+
+    #[test]
+    fn scaled_formula_gen7_drops_trainer_bonus_and_split() {
+        let factors = ExpGainFactors { trainer_battle: true, participants: 2, ..WILD };
+        assert_eq!(exp_gain_scaled(64, 10, 10, 7, factors), 129);
+    }
+}
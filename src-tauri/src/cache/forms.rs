@@ -0,0 +1,107 @@
+use sqlx::SqlitePool;
+
+use crate::api::forms::ParsedPokemonForm;
+
+/// PokéAPI numbers non-default varieties (Alolan Raichu, Mega Charizard X, ...) from here.
+const FIRST_ALTERNATE_POKEMON_ID: i64 = 10001;
+
+/// Regions whose variants are tagged in form names ("alola", "galar-zen", ...).
+const REGIONS: &[&str] = &["alola", "galar", "hisui", "paldea"];
+
+/// Region of a regional variant, from its form name.
+pub fn regional_tag(form_name: &str) -> Option<&'static str> {
+    // Pikachu's caps ("alola-cap") are costumes, not regional variants
+    if form_name.ends_with("-cap") {
+        return None;
+    }
+    let first = form_name.split('-').next().unwrap_or_default();
+    REGIONS.iter().find(|region| **region == first).copied()
+}
+
+/// Category of a form: default, mega, gigantamax, regional, battle, cosmetic
+/// (same pokemon, different look) or variant (another pokemon of the species).
+pub fn form_category(form: &ParsedPokemonForm) -> &'static str {
+    let alternate_pokemon = form
+        .pokemon_id
+        .map(|id| id >= FIRST_ALTERNATE_POKEMON_ID)
+        .unwrap_or(false);
+
+    if form.is_mega {
+        "mega"
+    } else if form.form_name == "gmax" || form.form_name.ends_with("-gmax") {
+        "gigantamax"
+    } else if regional_tag(&form.form_name).is_some() {
+        "regional"
+    } else if form.is_battle_only {
+        "battle"
+    } else if alternate_pokemon {
+        "variant"
+    } else if form.is_default {
+        "default"
+    } else {
+        "cosmetic"
+    }
+}
+
+/// Upsert a pokemon form, with its category and regional tag.
+pub async fn upsert_pokemon_form(
+    pool: &SqlitePool,
+    form: &ParsedPokemonForm,
+) -> Result<(), sqlx::Error> {
+    let pokemon_id = match form.pokemon_id {
+        Some(id) => id,
+        None => return Ok(()),
+    };
+
+    sqlx::query(
+        "INSERT INTO pokemon_forms (id, name_key, pokemon_id, form_name, name_en, name_fr,
+                                    form_name_en, form_name_fr, is_default, is_mega,
+                                    is_battle_only, category, regional_tag, version_group,
+                                    form_order, sprite_url)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)
+         ON CONFLICT(id) DO UPDATE SET
+           name_key = excluded.name_key,
+           pokemon_id = excluded.pokemon_id,
+           form_name = excluded.form_name,
+           name_en = excluded.name_en,
+           name_fr = excluded.name_fr,
+           form_name_en = excluded.form_name_en,
+           form_name_fr = excluded.form_name_fr,
+           is_default = excluded.is_default,
+           is_mega = excluded.is_mega,
+           is_battle_only = excluded.is_battle_only,
+           category = excluded.category,
+           regional_tag = excluded.regional_tag,
+           version_group = excluded.version_group,
+           form_order = excluded.form_order,
+           sprite_url = excluded.sprite_url"
+    )
+    .bind(form.id)
+    .bind(&form.name_key)
+    .bind(pokemon_id)
+    .bind(&form.form_name)
+    .bind(&form.name_en)
+    .bind(&form.name_fr)
+    .bind(&form.form_name_en)
+    .bind(&form.form_name_fr)
+    .bind(form.is_default)
+    .bind(form.is_mega)
+    .bind(form.is_battle_only)
+    .bind(form_category(form))
+    .bind(regional_tag(&form.form_name))
+    .bind(&form.version_group)
+    .bind(form.form_order)
+    .bind(&form.sprite_url)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Whether pokemon forms have been synced.
+pub async fn has_pokemon_forms(pool: &SqlitePool) -> Result<bool, sqlx::Error> {
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM pokemon_forms")
+        .fetch_one(pool)
+        .await?;
+    Ok(count > 0)
+}
@@ -1,20 +1,153 @@
-use sqlx::SqlitePool;
+use sha2::{Digest, Sha256};
+use sqlx::{SqliteConnection, SqlitePool};
 
+use super::{edits, inheritance, locations, machines, mechanics, trainers};
 use crate::models::games::{
-    AbilityOverrideEntry, GameDataFile, ItemLocationEntry, LearnsetEntry, MoveOverrideEntry,
-    PokemonOverride,
+    AbilityOverrideEntry, GameDataFile, GameMechanics, GameMeta, ItemLocationEntry, LearnsetEntry,
+    MoveOverrideEntry, PokemonOverride, TypeChartOverride,
 };
 
-/// Upsert a game record.
-pub async fn upsert_game(
+/// Version of the game data import. Bump it when the import logic or schema changes
+/// so that unchanged files get reimported anyway.
+pub const GAME_DATA_FORMAT_VERSION: i64 = 3;
+
+/// SHA-256 of a game data file, hex-encoded.
+pub fn content_hash(json: &str) -> String {
+    format!("{:x}", Sha256::digest(json.as_bytes()))
+}
+
+/// Whether a game was last imported from this exact content with the current format.
+pub async fn is_import_current(
+    pool: &SqlitePool,
+    game_id: &str,
+    hash: &str,
+) -> Result<bool, sqlx::Error> {
+    let count: i64 = sqlx::query_scalar(
+        "SELECT COUNT(*) FROM games WHERE id = ?1 AND content_hash = ?2 AND format_version = ?3"
+    )
+    .bind(game_id)
+    .bind(hash)
+    .bind(GAME_DATA_FORMAT_VERSION)
+    .fetch_one(pool)
+    .await?;
+
+    Ok(count > 0)
+}
+
+/// Origin of games embedded in the app.
+pub const ORIGIN_BUNDLED: &str = "bundled";
+/// Origin of games loaded from the user games folder.
+pub const ORIGIN_USER: &str = "user";
+/// Origin of games imported manually from the frontend.
+pub const ORIGIN_IMPORTED: &str = "imported";
+
+/// Record where a game's imported data file came from, with its content hash
+/// (and the current format version).
+pub async fn record_game_import(
     pool: &SqlitePool,
-    game: &crate::models::games::GameMeta,
+    game_id: &str,
+    hash: &str,
+    origin: &str,
+    origin_file: Option<&str>,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE games SET content_hash = ?2, format_version = ?3, origin = ?4, origin_file = ?5,
+           orphaned = 0
+         WHERE id = ?1"
+    )
+    .bind(game_id)
+    .bind(hash)
+    .bind(GAME_DATA_FORMAT_VERSION)
+    .bind(origin)
+    .bind(origin_file)
+    .execute(pool)
+    .await?;
+
+    Ok(())
+}
+
+/// Unlist a user game whose file is gone (or list it again when the file is back),
+/// keeping its data, runs and edits.
+pub async fn set_game_orphaned(
+    pool: &SqlitePool,
+    game_id: &str,
+    orphaned: bool,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("UPDATE games SET orphaned = ?2 WHERE id = ?1 AND orphaned != ?2")
+        .bind(game_id)
+        .bind(orphaned)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Check a game data file before importing it.
+pub fn validate_game_data(data: &GameDataFile) -> Result<(), String> {
+    let game = &data.game;
+
+    let valid_id = !game.id.is_empty()
+        && game.id.chars().all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '-' || c == '_');
+    if !valid_id {
+        return Err(format!(
+            "Invalid game id '{}': use lowercase letters, digits, '-' and '_'",
+            game.id
+        ));
+    }
+    if inheritance::is_official_version(&game.id) {
+        return Err(format!("Game id '{}' is reserved for an official version", game.id));
+    }
+    if game.name_en.trim().is_empty() {
+        return Err(format!("Game '{}' has no name", game.id));
+    }
+    if game.coverage != "full" && game.coverage != "changes_only" {
+        return Err(format!(
+            "Game '{}' has an unknown coverage '{}' (expected 'full' or 'changes_only')",
+            game.id, game.coverage
+        ));
+    }
+    if game.extends.as_deref() == Some(game.id.as_str()) {
+        return Err(format!("Game '{}' extends itself", game.id));
+    }
+    if let Some(mechanics) = &game.mechanics {
+        if mechanics.generation.is_some_and(|g| !(1..=mechanics::LATEST_GENERATION).contains(&g)) {
+            return Err(format!("Game '{}' has an unknown generation", game.id));
+        }
+        let split = mechanics.damage_split.as_deref();
+        if split.is_some_and(|s| s != mechanics::SPLIT_BY_TYPE && s != mechanics::SPLIT_BY_MOVE) {
+            return Err(format!(
+                "Game '{}' has an unknown damage split (expected 'type' or 'move')",
+                game.id
+            ));
+        }
+        if mechanics.crit_multiplier.is_some_and(|m| m <= 0.0) {
+            return Err(format!("Game '{}' has a non-positive crit multiplier", game.id));
+        }
+        if mechanics.type_chart_overrides.iter().any(|c| c.damage_factor < 0) {
+            return Err(format!("Game '{}' has a negative type chart damage factor", game.id));
+        }
+    }
+
+    Ok(())
+}
+
+/// Upsert a game record. The content hash is cleared until the import completes.
+pub async fn upsert_game(
+    conn: &mut SqliteConnection,
+    game: &GameMeta,
 ) -> Result<(), sqlx::Error> {
     let now = chrono::Utc::now().to_rfc3339();
+    let mechanics = game.mechanics.clone().unwrap_or_default();
+    let type_chart_overrides = if mechanics.type_chart_overrides.is_empty() {
+        None
+    } else {
+        serde_json::to_string(&mechanics.type_chart_overrides).ok()
+    };
 
     sqlx::query(
-        "INSERT INTO games (id, name_en, name_fr, base_rom, version, author, is_hackrom, sort_order, coverage, imported_at)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)
+        "INSERT INTO games (id, name_en, name_fr, base_rom, version, author, is_hackrom, sort_order, coverage, extends, imported_at,
+                            generation, damage_split, has_fairy, crit_multiplier, type_chart_overrides)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16)
          ON CONFLICT(id) DO UPDATE SET
            name_en = excluded.name_en,
            name_fr = excluded.name_fr,
@@ -24,7 +157,15 @@ pub async fn upsert_game(
            is_hackrom = excluded.is_hackrom,
            sort_order = excluded.sort_order,
            coverage = excluded.coverage,
-           imported_at = excluded.imported_at"
+           extends = excluded.extends,
+           imported_at = excluded.imported_at,
+           generation = excluded.generation,
+           damage_split = excluded.damage_split,
+           has_fairy = excluded.has_fairy,
+           crit_multiplier = excluded.crit_multiplier,
+           type_chart_overrides = excluded.type_chart_overrides,
+           content_hash = NULL,
+           format_version = NULL"
     )
     .bind(&game.id)
     .bind(&game.name_en)
@@ -35,8 +176,14 @@ pub async fn upsert_game(
     .bind(game.is_hackrom as i64)
     .bind(game.sort_order)
     .bind(&game.coverage)
+    .bind(&game.extends)
     .bind(&now)
-    .execute(pool)
+    .bind(mechanics.generation)
+    .bind(&mechanics.damage_split)
+    .bind(mechanics.has_fairy.map(|f| f as i64))
+    .bind(mechanics.crit_multiplier)
+    .bind(&type_chart_overrides)
+    .execute(&mut *conn)
     .await?;
 
     Ok(())
@@ -49,56 +196,314 @@ pub async fn import_game_data(
 ) -> Result<String, sqlx::Error> {
     let game_id = &data.game.id;
 
+    // The reimport and the edit replay land together, or the previous data stays
+    let mut tx = pool.begin().await?;
+
     // Delete existing data for this game first
-    delete_game_data(pool, game_id).await?;
+    delete_game_data(&mut tx, game_id).await?;
 
     // Upsert the game record
-    upsert_game(pool, &data.game).await?;
+    upsert_game(&mut tx, &data.game).await?;
 
     // Import pokemon overrides in batches
     for pokemon in &data.pokemon_overrides {
-        import_pokemon_override(pool, game_id, pokemon).await?;
+        import_pokemon_override(&mut tx, game_id, pokemon).await?;
     }
 
     // Import move overrides
     for mo in &data.move_overrides {
-        import_move_override(pool, game_id, mo).await?;
+        import_move_override(&mut tx, game_id, mo).await?;
     }
 
     // Import item locations
     for il in &data.item_locations {
-        import_item_locations(pool, game_id, il).await?;
+        import_item_locations(&mut tx, game_id, il).await?;
     }
 
+    // Import trainers (file order is progression order)
+    for (index, trainer) in data.trainers.iter().enumerate() {
+        trainers::import_trainer(&mut tx, game_id, index as i64 + 1, trainer).await?;
+    }
+
+    // Re-apply in-app edits on top of the imported data
+    edits::replay_game_edits(&mut tx, game_id).await?;
+
+    // Build the reverse location index
+    locations::refresh_game_location_counts(&mut tx, game_id).await?;
+
+    tx.commit().await?;
     Ok(game_id.clone())
 }
 
+/// Raw games row, as needed to rebuild `GameMeta`.
+#[derive(sqlx::FromRow)]
+struct GameRow {
+    id: String,
+    name_en: String,
+    name_fr: Option<String>,
+    base_rom: Option<String>,
+    version: Option<String>,
+    author: Option<String>,
+    is_hackrom: i64,
+    sort_order: i64,
+    coverage: String,
+    extends: Option<String>,
+    #[sqlx(flatten)]
+    mechanics: GameMechanicsRow,
+}
+
+/// Mechanics columns of a games row.
+#[derive(sqlx::FromRow)]
+pub struct GameMechanicsRow {
+    pub generation: Option<i64>,
+    pub damage_split: Option<String>,
+    pub has_fairy: Option<i64>,
+    pub crit_multiplier: Option<f64>,
+    pub type_chart_overrides: Option<String>,
+}
+
+impl GameMechanicsRow {
+    /// The stored mechanics block, None when the game sets none.
+    pub fn into_mechanics(self) -> Option<GameMechanics> {
+        let type_chart_overrides: Vec<TypeChartOverride> = self
+            .type_chart_overrides
+            .and_then(|json| serde_json::from_str(&json).ok())
+            .unwrap_or_default();
+
+        let is_empty = self.generation.is_none()
+            && self.damage_split.is_none()
+            && self.has_fairy.is_none()
+            && self.crit_multiplier.is_none()
+            && type_chart_overrides.is_empty();
+        if is_empty {
+            return None;
+        }
+
+        Some(GameMechanics {
+            generation: self.generation,
+            damage_split: self.damage_split,
+            has_fairy: self.has_fairy.map(|f| f != 0),
+            crit_multiplier: self.crit_multiplier,
+            type_chart_overrides,
+        })
+    }
+}
+
+/// Rebuild a game data file from the database (inverse of `import_game_data`).
+/// Entries keep their import order. Returns None if the game doesn't exist.
+pub async fn export_game_data(
+    pool: &SqlitePool,
+    game_id: &str,
+) -> Result<Option<GameDataFile>, sqlx::Error> {
+    let game: Option<GameRow> = sqlx::query_as(
+        "SELECT id, name_en, name_fr, base_rom, version, author, is_hackrom, sort_order, coverage, extends,
+                generation, damage_split, has_fairy, crit_multiplier, type_chart_overrides
+         FROM games WHERE id = ?1"
+    )
+    .bind(game_id)
+    .fetch_optional(pool)
+    .await?;
+
+    let game = match game {
+        Some(row) => GameMeta {
+            id: row.id,
+            name_en: row.name_en,
+            name_fr: row.name_fr,
+            base_rom: row.base_rom,
+            version: row.version,
+            author: row.author,
+            is_hackrom: row.is_hackrom != 0,
+            sort_order: row.sort_order,
+            coverage: row.coverage,
+            extends: row.extends,
+            mechanics: row.mechanics.into_mechanics(),
+        },
+        None => return Ok(None),
+    };
+
+    // Pokemon in file order; rows from older imports without an entry are appended
+    let listed: Vec<(String, Option<String>)> = sqlx::query_as(
+        "SELECT pokemon_name_key, evolution_method FROM game_pokemon_overrides
+         WHERE game_id = ?1 ORDER BY rowid"
+    )
+    .bind(game_id)
+    .fetch_all(pool)
+    .await?;
+
+    let mut pokemon_overrides: Vec<PokemonOverride> = listed
+        .into_iter()
+        .map(|(name_key, evolution_method)| PokemonOverride {
+            name_key,
+            learnset: Vec::new(),
+            abilities: Vec::new(),
+            evolution_method,
+            locations: Vec::new(),
+        })
+        .collect();
+
+    fn entry<'a>(list: &'a mut Vec<PokemonOverride>, name_key: &str) -> &'a mut PokemonOverride {
+        let index = match list.iter().position(|p| p.name_key == name_key) {
+            Some(index) => index,
+            None => {
+                list.push(PokemonOverride {
+                    name_key: name_key.to_string(),
+                    learnset: Vec::new(),
+                    abilities: Vec::new(),
+                    evolution_method: None,
+                    locations: Vec::new(),
+                });
+                list.len() - 1
+            }
+        };
+        &mut list[index]
+    }
+
+    let learnset: Vec<(String, String, String, i64)> = sqlx::query_as(
+        "SELECT pokemon_name_key, move_name_key, learn_method, COALESCE(level_learned_at, 0)
+         FROM game_pokemon_moves WHERE game_id = ?1 ORDER BY rowid"
+    )
+    .bind(game_id)
+    .fetch_all(pool)
+    .await?;
+    for (pokemon_name_key, move_name_key, learn_method, level) in learnset {
+        entry(&mut pokemon_overrides, &pokemon_name_key).learnset.push(LearnsetEntry {
+            move_name_key,
+            learn_method,
+            level,
+        });
+    }
+
+    let abilities: Vec<(String, String, i64, i64)> = sqlx::query_as(
+        "SELECT pokemon_name_key, ability_key, slot, is_hidden
+         FROM game_pokemon_abilities WHERE game_id = ?1 ORDER BY rowid"
+    )
+    .bind(game_id)
+    .fetch_all(pool)
+    .await?;
+    for (pokemon_name_key, ability_key, slot, is_hidden) in abilities {
+        entry(&mut pokemon_overrides, &pokemon_name_key).abilities.push(AbilityOverrideEntry {
+            ability_key,
+            slot,
+            is_hidden: is_hidden != 0,
+        });
+    }
+
+    let pokemon_locations: Vec<(String, String)> = sqlx::query_as(
+        "SELECT pokemon_name_key, location
+         FROM game_pokemon_locations WHERE game_id = ?1 ORDER BY rowid"
+    )
+    .bind(game_id)
+    .fetch_all(pool)
+    .await?;
+    for (pokemon_name_key, location) in pokemon_locations {
+        entry(&mut pokemon_overrides, &pokemon_name_key).locations.push(location);
+    }
+
+    let move_overrides: Vec<MoveOverrideEntry> = sqlx::query_as(
+        "SELECT move_name_key AS name_key, power, accuracy, type_key, pp, damage_class, effect_en
+         FROM game_move_overrides WHERE game_id = ?1 ORDER BY rowid"
+    )
+    .bind(game_id)
+    .fetch_all(pool)
+    .await?;
+
+    // Items in file order (with or without locations); older imports without entries are appended
+    let listed_items: Vec<String> = sqlx::query_scalar(
+        "SELECT item_name_key FROM game_item_entries WHERE game_id = ?1 ORDER BY rowid"
+    )
+    .bind(game_id)
+    .fetch_all(pool)
+    .await?;
+    let mut item_locations: Vec<ItemLocationEntry> = listed_items
+        .into_iter()
+        .map(|name_key| ItemLocationEntry {
+            name_key,
+            locations: Vec::new(),
+            machine: None,
+        })
+        .collect();
+
+    let item_rows: Vec<(String, String)> = sqlx::query_as(
+        "SELECT item_name_key, location
+         FROM game_item_locations WHERE game_id = ?1 ORDER BY rowid"
+    )
+    .bind(game_id)
+    .fetch_all(pool)
+    .await?;
+    for (name_key, location) in item_rows {
+        match item_locations.iter_mut().find(|il| il.name_key == name_key) {
+            Some(il) => il.locations.push(location),
+            None => item_locations.push(ItemLocationEntry {
+                name_key,
+                locations: vec![location],
+                machine: None,
+            }),
+        }
+    }
+
+    let machine_rows: Vec<(String, String)> = sqlx::query_as(
+        "SELECT move_name_key, item FROM game_machines WHERE game_id = ?1"
+    )
+    .bind(game_id)
+    .fetch_all(pool)
+    .await?;
+    for (move_name_key, item) in machine_rows {
+        if let Some(il) = item_locations.iter_mut().find(|il| il.name_key == move_name_key) {
+            // Only written when the location labels don't already say it
+            let labelled = il.locations.iter().find_map(|l| locations::machine_label(l));
+            if labelled.as_deref() != Some(item.as_str()) {
+                il.machine = Some(item);
+            }
+        }
+    }
+
+    let trainers = trainers::export_game_trainers(pool, game_id).await?;
+
+    Ok(Some(GameDataFile {
+        game,
+        pokemon_overrides,
+        move_overrides,
+        item_locations,
+        trainers,
+    }))
+}
+
 /// Import a single pokemon's override data (learnset, abilities, locations).
 async fn import_pokemon_override(
-    pool: &SqlitePool,
+    conn: &mut SqliteConnection,
     game_id: &str,
     pokemon: &PokemonOverride,
 ) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT OR IGNORE INTO game_pokemon_overrides (game_id, pokemon_name_key, evolution_method)
+         VALUES (?1, ?2, ?3)"
+    )
+    .bind(game_id)
+    .bind(&pokemon.name_key)
+    .bind(&pokemon.evolution_method)
+    .execute(&mut *conn)
+    .await?;
+
     // Learnset
     for entry in &pokemon.learnset {
-        import_pokemon_move(pool, game_id, &pokemon.name_key, entry).await?;
+        import_pokemon_move(&mut *conn, game_id, &pokemon.name_key, entry).await?;
     }
 
     // Abilities
     for ability in &pokemon.abilities {
-        import_pokemon_ability(pool, game_id, &pokemon.name_key, ability).await?;
+        import_pokemon_ability(&mut *conn, game_id, &pokemon.name_key, ability).await?;
     }
 
     // Locations
     for location in &pokemon.locations {
-        import_pokemon_location(pool, game_id, &pokemon.name_key, location).await?;
+        import_pokemon_location(&mut *conn, game_id, &pokemon.name_key, location).await?;
     }
 
     Ok(())
 }
 
-async fn import_pokemon_move(
-    pool: &SqlitePool,
+pub async fn import_pokemon_move(
+    conn: &mut SqliteConnection,
     game_id: &str,
     pokemon_name_key: &str,
     entry: &LearnsetEntry,
@@ -112,14 +517,14 @@ async fn import_pokemon_move(
     .bind(&entry.move_name_key)
     .bind(&entry.learn_method)
     .bind(entry.level)
-    .execute(pool)
+    .execute(&mut *conn)
     .await?;
 
     Ok(())
 }
 
-async fn import_pokemon_ability(
-    pool: &SqlitePool,
+pub async fn import_pokemon_ability(
+    conn: &mut SqliteConnection,
     game_id: &str,
     pokemon_name_key: &str,
     ability: &AbilityOverrideEntry,
@@ -133,33 +538,39 @@ async fn import_pokemon_ability(
     .bind(&ability.ability_key)
     .bind(ability.slot)
     .bind(ability.is_hidden as i64)
-    .execute(pool)
+    .execute(&mut *conn)
     .await?;
 
     Ok(())
 }
 
-async fn import_pokemon_location(
-    pool: &SqlitePool,
+pub async fn import_pokemon_location(
+    conn: &mut SqliteConnection,
     game_id: &str,
     pokemon_name_key: &str,
     location: &str,
 ) -> Result<(), sqlx::Error> {
+    let normalized = locations::normalize_location(location);
+    if let Some(ref loc) = normalized {
+        locations::upsert_game_location(&mut *conn, game_id, loc).await?;
+    }
+
     sqlx::query(
-        "INSERT OR IGNORE INTO game_pokemon_locations (game_id, pokemon_name_key, location)
-         VALUES (?1, ?2, ?3)"
+        "INSERT OR IGNORE INTO game_pokemon_locations (game_id, pokemon_name_key, location, location_key)
+         VALUES (?1, ?2, ?3, ?4)"
     )
     .bind(game_id)
     .bind(pokemon_name_key)
     .bind(location)
-    .execute(pool)
+    .bind(normalized.as_ref().map(|l| &l.key))
+    .execute(&mut *conn)
     .await?;
 
     Ok(())
 }
 
-async fn import_move_override(
-    pool: &SqlitePool,
+pub async fn import_move_override(
+    conn: &mut SqliteConnection,
     game_id: &str,
     mo: &MoveOverrideEntry,
 ) -> Result<(), sqlx::Error> {
@@ -175,47 +586,130 @@ async fn import_move_override(
     .bind(mo.pp)
     .bind(&mo.damage_class)
     .bind(&mo.effect_en)
-    .execute(pool)
+    .execute(&mut *conn)
     .await?;
 
     Ok(())
 }
 
-async fn import_item_locations(
-    pool: &SqlitePool,
+pub async fn import_item_locations(
+    conn: &mut SqliteConnection,
     game_id: &str,
     il: &ItemLocationEntry,
 ) -> Result<(), sqlx::Error> {
+    sqlx::query("INSERT OR IGNORE INTO game_item_entries (game_id, item_name_key) VALUES (?1, ?2)")
+        .bind(game_id)
+        .bind(&il.name_key)
+        .execute(&mut *conn)
+        .await?;
+
     for location in &il.locations {
+        let normalized = locations::normalize_location(location);
+        if let Some(ref loc) = normalized {
+            locations::upsert_game_location(&mut *conn, game_id, loc).await?;
+        }
+
         sqlx::query(
-            "INSERT OR IGNORE INTO game_item_locations (game_id, item_name_key, location)
-             VALUES (?1, ?2, ?3)"
+            "INSERT OR IGNORE INTO game_item_locations (game_id, item_name_key, location, location_key)
+             VALUES (?1, ?2, ?3, ?4)"
         )
         .bind(game_id)
         .bind(&il.name_key)
         .bind(location)
-        .execute(pool)
+        .bind(normalized.as_ref().map(|l| &l.key))
+        .execute(&mut *conn)
         .await?;
     }
 
+    // TMs are listed by move, with the machine given or in the "TM01 - Move: place" label
+    let machine = il
+        .machine
+        .clone()
+        .or_else(|| il.locations.iter().find_map(|l| locations::machine_label(l)));
+    if let Some(item) = machine {
+        machines::upsert_game_machine(&mut *conn, game_id, &item, &il.name_key).await?;
+    }
+
     Ok(())
 }
 
 /// Delete all data for a specific game.
 pub async fn delete_game_data(
-    pool: &SqlitePool,
+    conn: &mut SqliteConnection,
     game_id: &str,
 ) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM game_pokemon_overrides WHERE game_id = ?1")
+        .bind(game_id).execute(&mut *conn).await?;
     sqlx::query("DELETE FROM game_pokemon_moves WHERE game_id = ?1")
-        .bind(game_id).execute(pool).await?;
+        .bind(game_id).execute(&mut *conn).await?;
     sqlx::query("DELETE FROM game_pokemon_abilities WHERE game_id = ?1")
-        .bind(game_id).execute(pool).await?;
+        .bind(game_id).execute(&mut *conn).await?;
     sqlx::query("DELETE FROM game_pokemon_locations WHERE game_id = ?1")
-        .bind(game_id).execute(pool).await?;
+        .bind(game_id).execute(&mut *conn).await?;
     sqlx::query("DELETE FROM game_move_overrides WHERE game_id = ?1")
-        .bind(game_id).execute(pool).await?;
+        .bind(game_id).execute(&mut *conn).await?;
+    sqlx::query("DELETE FROM game_item_entries WHERE game_id = ?1")
+        .bind(game_id).execute(&mut *conn).await?;
     sqlx::query("DELETE FROM game_item_locations WHERE game_id = ?1")
-        .bind(game_id).execute(pool).await?;
+        .bind(game_id).execute(&mut *conn).await?;
+    sqlx::query("DELETE FROM game_machines WHERE game_id = ?1")
+        .bind(game_id).execute(&mut *conn).await?;
+    sqlx::query("DELETE FROM game_locations WHERE game_id = ?1")
+        .bind(game_id).execute(&mut *conn).await?;
+    trainers::delete_game_trainers(&mut *conn, game_id).await?;
+
+    Ok(())
+}
+
+/// Delete a game and all of its data in one transaction. Dependent tables cascade
+/// from the games row (runs included); the edit log has no foreign key and is
+/// cleared explicitly.
+pub async fn delete_game(
+    pool: &SqlitePool,
+    game_id: &str,
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM game_edits WHERE game_id = ?1")
+        .bind(game_id)
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("DELETE FROM games WHERE id = ?1")
+        .bind(game_id)
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Make sure an official version group has a games row (placeholder name until
+/// register_official_games runs). The row stays hidden unless it's a supported game.
+pub async fn ensure_version_group_game(
+    pool: &SqlitePool,
+    version_group: &str,
+) -> Result<(), sqlx::Error> {
+    let name = version_group
+        .split('-')
+        .map(|word| {
+            let mut chars = word.chars();
+            match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            }
+        })
+        .collect::<Vec<String>>()
+        .join(" ");
+
+    sqlx::query(
+        "INSERT OR IGNORE INTO games (id, name_en, name_fr, is_hackrom, sort_order, coverage, generation, hidden)
+         VALUES (?1, ?2, ?2, 0, 200, 'full', ?3, 1)"
+    )
+    .bind(version_group)
+    .bind(&name)
+    .bind(super::mechanics::generation_for(version_group))
+    .execute(pool)
+    .await?;
 
     Ok(())
 }
@@ -243,3 +737,102 @@ pub async fn upsert_game_pokemon_move(
 
     Ok(())
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sqlx::sqlite::SqlitePoolOptions;
+
+    async fn test_pool() -> SqlitePool {
+        let pool = SqlitePoolOptions::new()
+            .max_connections(1)
+            .connect("sqlite::memory:")
+            .await
+            .unwrap();
+        crate::db::run_migrations(&pool).await.unwrap();
+        pool
+    }
+
+    #[tokio::test]
+    async fn export_round_trips_import() {
+        let pool = test_pool().await;
+        let file: GameDataFile = serde_json::from_str(
+            r#"{
+              "game": {
+                "id": "test-hack", "name_en": "Test Hack", "name_fr": "Hack Test",
+                "base_rom": "emerald", "version": "1.2", "author": "someone",
+                "is_hackrom": true, "sort_order": 4, "coverage": "changes_only",
+                "extends": "emerald",
+                "mechanics": {
+                  "generation": 3, "damage_split": "move", "has_fairy": true, "crit_multiplier": 1.5,
+                  "type_chart_overrides": [
+                    {"attacking_type": "fairy", "defending_type": "steel", "damage_factor": 100}
+                  ]
+                }
+              },
+              "pokemon_overrides": [
+                {
+                  "name_key": "treecko",
+                  "learnset": [
+                    {"move_name_key": "pound", "learn_method": "level-up", "level": 1},
+                    {"move_name_key": "absorb", "learn_method": "level-up", "level": 6},
+                    {"move_name_key": "absorb", "learn_method": "level-up", "level": 21},
+                    {"move_name_key": "bullet-seed", "learn_method": "machine", "level": 0}
+                  ],
+                  "abilities": [
+                    {"ability_key": "overgrow", "slot": 1, "is_hidden": false},
+                    {"ability_key": "unburden", "slot": 3, "is_hidden": true}
+                  ],
+                  "evolution_method": "Level 16",
+                  "locations": ["Route 101", "Petalburg Woods"]
+                },
+                {
+                  "name_key": "zigzagoon", "learnset": [], "abilities": [],
+                  "evolution_method": null, "locations": []
+                }
+              ],
+              "move_overrides": [
+                {"name_key": "absorb", "power": 40, "accuracy": null, "type_key": null,
+                 "pp": 15, "damage_class": null, "effect_en": "Drains half the damage dealt."},
+                {"name_key": "bite", "power": null, "accuracy": null, "type_key": "dark",
+                 "pp": null, "damage_class": "physical", "effect_en": null}
+              ],
+              "item_locations": [
+                {"name_key": "potion", "locations": ["Route 101", "Oldale Town"]},
+                {"name_key": "bullet-seed", "locations": ["TM09 - Bullet Seed: Route 104"]},
+                {"name_key": "surf", "locations": ["Petalburg City"], "machine": "hm03"},
+                {"name_key": "master-ball", "locations": []}
+              ],
+              "trainers": [
+                {
+                  "name": "Youngster Calvin", "trainer_class": "Youngster", "location": "Route 102",
+                  "battle_type": "single", "level_cap": 15,
+                  "team": [
+                    {"name_key": "zigzagoon", "level": 5, "ability_key": "pickup", "item_key": "oran-berry",
+                     "nature_key": "jolly", "moves": ["tackle", "growl"],
+                     "ivs": {"hp": 31, "atk": 31, "def": 31, "spa": 0, "spd": 31, "spe": 31},
+                     "evs": {"hp": 0, "atk": 252, "def": 0, "spa": 0, "spd": 4, "spe": 252}}
+                  ]
+                },
+                {
+                  "name": "Roxanne", "trainer_class": "Leader", "location": "Rustboro Gym",
+                  "battle_type": "double", "level_cap": null,
+                  "team": [
+                    {"name_key": "geodude", "level": 12, "ability_key": null, "item_key": null,
+                     "nature_key": null, "moves": [], "ivs": null, "evs": null}
+                  ]
+                }
+              ]
+            }"#,
+        )
+        .unwrap();
+
+        import_game_data(&pool, &file).await.unwrap();
+        let exported = export_game_data(&pool, "test-hack").await.unwrap().unwrap();
+
+        assert_eq!(
+            serde_json::to_value(&exported).unwrap(),
+            serde_json::to_value(&file).unwrap()
+        );
+    }
+}
@@ -0,0 +1,412 @@
+use std::collections::{HashMap, HashSet};
+
+use sqlx::{SqliteConnection, SqlitePool};
+
+use super::{machines, mechanics};
+use crate::models::games::GameMoveOverride;
+use crate::models::{PokemonAbility, PokemonMoveEntry};
+
+/// Source label for rows that come from the global (latest generation) tables.
+pub const GLOBAL_SOURCE: &str = "global";
+
+/// Guard against malformed chains (cycles are also detected explicitly).
+const MAX_CHAIN_DEPTH: usize = 16;
+
+/// Official versions mapped to the PokeAPI version group holding their data.
+const VERSION_GROUPS: &[(&str, &str)] = &[
+    ("red", "red-blue"),
+    ("blue", "red-blue"),
+    ("yellow", "yellow"),
+    ("gold", "gold-silver"),
+    ("silver", "gold-silver"),
+    ("crystal", "crystal"),
+    ("ruby", "ruby-sapphire"),
+    ("sapphire", "ruby-sapphire"),
+    ("emerald", "emerald"),
+    ("firered", "firered-leafgreen"),
+    ("leafgreen", "firered-leafgreen"),
+    ("diamond", "diamond-pearl"),
+    ("pearl", "diamond-pearl"),
+    ("platinum", "platinum"),
+    ("heartgold", "heartgold-soulsilver"),
+    ("soulsilver", "heartgold-soulsilver"),
+    ("black", "black-white"),
+    ("white", "black-white"),
+    ("black-2", "black-2-white-2"),
+    ("white-2", "black-2-white-2"),
+    ("x", "x-y"),
+    ("y", "x-y"),
+    ("omega-ruby", "omega-ruby-alpha-sapphire"),
+    ("alpha-sapphire", "omega-ruby-alpha-sapphire"),
+    ("sun", "sun-moon"),
+    ("moon", "sun-moon"),
+    ("ultra-sun", "ultra-sun-ultra-moon"),
+    ("ultra-moon", "ultra-sun-ultra-moon"),
+    ("sword", "sword-shield"),
+    ("shield", "sword-shield"),
+    ("scarlet", "scarlet-violet"),
+    ("violet", "scarlet-violet"),
+];
+
+/// Map a version name ("firered") to its version group ("firered-leafgreen").
+/// Unknown names are returned as-is (they may already be a version group or a game id).
+pub fn version_group_for(name: &str) -> &str {
+    VERSION_GROUPS
+        .iter()
+        .find(|(version, _)| *version == name)
+        .map(|(_, group)| *group)
+        .unwrap_or(name)
+}
+
+/// Whether a name is an official version or version group ("emerald", "firered-leafgreen").
+pub fn is_official_version(name: &str) -> bool {
+    VERSION_GROUPS
+        .iter()
+        .any(|(version, group)| *version == name || *group == name)
+}
+
+/// Resolve the inheritance chain of a game, nearest layer first.
+///
+/// A game inherits from its `extends` target, or from its `base_rom` when it only
+/// lists changes. Targets are other games or official version groups; the chain
+/// stops at the first layer without a parent. The global tables are the implicit
+/// last layer and are not part of the returned list.
+pub async fn resolve_game_chain(
+    pool: &SqlitePool,
+    game_id: &str,
+) -> Result<Vec<String>, sqlx::Error> {
+    let mut conn = pool.acquire().await?;
+    resolve_game_chain_on(&mut conn, game_id).await
+}
+
+/// `resolve_game_chain` through `conn` (e.g. inside an edit's transaction).
+pub async fn resolve_game_chain_on(
+    conn: &mut SqliteConnection,
+    game_id: &str,
+) -> Result<Vec<String>, sqlx::Error> {
+    let mut chain = vec![game_id.to_string()];
+
+    while chain.len() < MAX_CHAIN_DEPTH {
+        let current = chain.last().map(String::as_str).unwrap_or_default();
+
+        let parent: Option<Option<String>> = sqlx::query_scalar(
+            "SELECT COALESCE(extends, CASE WHEN coverage = 'changes_only' THEN base_rom END)
+             FROM games WHERE id = ?1"
+        )
+        .bind(current)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+        let parent = match parent.flatten() {
+            Some(p) if !p.trim().is_empty() => p,
+            _ => break,
+        };
+
+        let parent = resolve_parent(&mut *conn, parent.trim()).await?;
+        if chain.contains(&parent) {
+            log::warn!("Inheritance cycle in game '{}' at '{}'", game_id, parent);
+            break;
+        }
+        chain.push(parent);
+    }
+
+    Ok(chain)
+}
+
+/// A parent reference is a game id if one exists, otherwise an official version (group).
+async fn resolve_parent(conn: &mut SqliteConnection, parent: &str) -> Result<String, sqlx::Error> {
+    let is_game: Option<String> = sqlx::query_scalar("SELECT id FROM games WHERE id = ?1")
+        .bind(parent)
+        .fetch_optional(&mut *conn)
+        .await?;
+
+    match is_game {
+        Some(id) => Ok(id),
+        None => Ok(version_group_for(parent).to_string()),
+    }
+}
+
+/// Resolve a pokemon's learnset through a chain, with move overrides of the whole chain
+/// applied and damage classes following the game's physical/special split.
+pub async fn resolve_pokemon_moves(
+    pool: &SqlitePool,
+    chain: &[String],
+    pokemon_name_key: &str,
+) -> Result<Vec<PokemonMoveEntry>, sqlx::Error> {
+    let mut rows = resolve_learnset(pool, chain, pokemon_name_key).await?;
+    let overrides = load_move_overrides(pool, chain).await?;
+    apply_move_overrides(&mut rows, &overrides);
+
+    let mechanics = mechanics::resolve_game_mechanics(pool, chain).await?;
+    mechanics::apply_damage_split(&mut rows, &mechanics, &overrides);
+
+    let machines = machines::resolve_machines(pool, chain).await?;
+    machines::apply_machine_numbers(&mut rows, &machines);
+
+    Ok(rows)
+}
+
+/// Resolve a pokemon's learnset through a chain: the nearest layer listing the pokemon
+/// wins, then the global table. Move stats are the global ones.
+pub async fn resolve_learnset(
+    pool: &SqlitePool,
+    chain: &[String],
+    pokemon_name_key: &str,
+) -> Result<Vec<PokemonMoveEntry>, sqlx::Error> {
+    let mut conn = pool.acquire().await?;
+    resolve_learnset_on(&mut conn, chain, pokemon_name_key).await
+}
+
+/// `resolve_learnset` through `conn` (e.g. inside an edit's transaction).
+pub async fn resolve_learnset_on(
+    conn: &mut SqliteConnection,
+    chain: &[String],
+    pokemon_name_key: &str,
+) -> Result<Vec<PokemonMoveEntry>, sqlx::Error> {
+    let mut rows: Vec<PokemonMoveEntry> = Vec::new();
+    for layer in chain {
+        rows = sqlx::query_as(
+            "SELECT
+               0 AS pokemon_id,
+               COALESCE(m.id, 0) AS move_id,
+               gpm.learn_method,
+               gpm.level_learned_at,
+               gpm.move_name_key AS name_key,
+               m.name_en,
+               m.name_fr,
+               m.type_key,
+               m.damage_class,
+               m.power,
+               m.accuracy,
+               m.pp,
+               gpm.game_id AS source
+             FROM game_pokemon_moves gpm
+             LEFT JOIN moves m ON m.name_key = gpm.move_name_key
+             WHERE gpm.game_id = ?1 AND gpm.pokemon_name_key = ?2
+             ORDER BY gpm.learn_method, gpm.level_learned_at, gpm.move_name_key"
+        )
+        .bind(layer)
+        .bind(pokemon_name_key)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        if !rows.is_empty() {
+            break;
+        }
+    }
+
+    if rows.is_empty() {
+        rows = sqlx::query_as(
+            "SELECT pm.pokemon_id, pm.move_id, pm.learn_method, pm.level_learned_at,
+                    m.name_key, m.name_en, m.name_fr, m.type_key, m.damage_class,
+                    m.power, m.accuracy, m.pp,
+                    ?2 AS source
+             FROM pokemon_moves pm
+             JOIN pokemon p ON p.id = pm.pokemon_id
+             JOIN moves m ON pm.move_id = m.id
+             WHERE p.name_key = ?1
+             ORDER BY pm.learn_method, pm.level_learned_at, m.name_key"
+        )
+        .bind(pokemon_name_key)
+        .bind(GLOBAL_SOURCE)
+        .fetch_all(&mut *conn)
+        .await?;
+    }
+
+    Ok(rows)
+}
+
+/// Resolve which pokemon learn a move through a chain, with their learn methods.
+/// Same layering as `resolve_learnset`: a pokemon's learnset comes from the nearest
+/// layer listing it, then the global table.
+pub async fn resolve_move_learners(
+    pool: &SqlitePool,
+    chain: &[String],
+    move_name_key: &str,
+) -> Result<HashMap<String, Vec<String>>, sqlx::Error> {
+    let mut decided: HashSet<String> = HashSet::new();
+    let mut learners: HashMap<String, Vec<String>> = HashMap::new();
+
+    for layer in chain {
+        let listed: Vec<String> = sqlx::query_scalar(
+            "SELECT DISTINCT pokemon_name_key FROM game_pokemon_moves WHERE game_id = ?1"
+        )
+        .bind(layer)
+        .fetch_all(pool)
+        .await?;
+
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            "SELECT DISTINCT pokemon_name_key, learn_method FROM game_pokemon_moves
+             WHERE game_id = ?1 AND move_name_key = ?2"
+        )
+        .bind(layer)
+        .bind(move_name_key)
+        .fetch_all(pool)
+        .await?;
+
+        for (pokemon, method) in rows {
+            if !decided.contains(&pokemon) {
+                learners.entry(pokemon).or_default().push(method);
+            }
+        }
+        decided.extend(listed);
+    }
+
+    let rows: Vec<(String, String)> = sqlx::query_as(
+        "SELECT DISTINCT p.name_key, pm.learn_method
+         FROM pokemon_moves pm
+         JOIN pokemon p ON p.id = pm.pokemon_id
+         JOIN moves m ON m.id = pm.move_id
+         WHERE m.name_key = ?1"
+    )
+    .bind(move_name_key)
+    .fetch_all(pool)
+    .await?;
+
+    for (pokemon, method) in rows {
+        if !decided.contains(&pokemon) {
+            learners.entry(pokemon).or_default().push(method);
+        }
+    }
+
+    Ok(learners)
+}
+
+/// Apply merged move overrides to learnset rows.
+pub fn apply_move_overrides(
+    rows: &mut [PokemonMoveEntry],
+    overrides: &HashMap<String, GameMoveOverride>,
+) {
+    for row in rows {
+        let ov = match row.name_key.as_ref().and_then(|k| overrides.get(k)) {
+            Some(ov) => ov,
+            None => continue,
+        };
+        row.type_key = ov.type_key.clone().or(row.type_key.take());
+        row.damage_class = ov.damage_class.clone().or(row.damage_class.take());
+        row.power = ov.power.or(row.power);
+        row.accuracy = ov.accuracy.or(row.accuracy);
+        row.pp = ov.pp.or(row.pp);
+    }
+}
+
+/// Resolve a pokemon's abilities through a chain: the nearest layer listing the
+/// pokemon wins, then the global table.
+pub async fn resolve_pokemon_abilities(
+    pool: &SqlitePool,
+    chain: &[String],
+    pokemon_name_key: &str,
+) -> Result<Vec<PokemonAbility>, sqlx::Error> {
+    let mut conn = pool.acquire().await?;
+    resolve_pokemon_abilities_on(&mut conn, chain, pokemon_name_key).await
+}
+
+/// `resolve_pokemon_abilities` through `conn` (e.g. inside an edit's transaction).
+pub async fn resolve_pokemon_abilities_on(
+    conn: &mut SqliteConnection,
+    chain: &[String],
+    pokemon_name_key: &str,
+) -> Result<Vec<PokemonAbility>, sqlx::Error> {
+    for layer in chain {
+        let rows: Vec<PokemonAbility> = sqlx::query_as(
+            "SELECT
+               0 AS pokemon_id,
+               a.id AS ability_id,
+               gpa.ability_key,
+               a.name_en AS ability_en,
+               a.name_fr AS ability_fr,
+               a.short_effect_en,
+               a.short_effect_fr,
+               gpa.is_hidden,
+               gpa.slot,
+               gpa.game_id AS source
+             FROM game_pokemon_abilities gpa
+             LEFT JOIN abilities a ON a.name_key = gpa.ability_key
+             WHERE gpa.game_id = ?1 AND gpa.pokemon_name_key = ?2
+             ORDER BY gpa.slot"
+        )
+        .bind(layer)
+        .bind(pokemon_name_key)
+        .fetch_all(&mut *conn)
+        .await?;
+
+        if !rows.is_empty() {
+            return Ok(rows);
+        }
+    }
+
+    sqlx::query_as(
+        "SELECT pa.pokemon_id, a.id AS ability_id, pa.ability_key,
+                COALESCE(a.name_en, pa.ability_en) AS ability_en,
+                COALESCE(a.name_fr, pa.ability_fr) AS ability_fr,
+                a.short_effect_en, a.short_effect_fr,
+                pa.is_hidden, pa.slot,
+                ?2 AS source
+         FROM pokemon_abilities pa
+         JOIN pokemon p ON p.id = pa.pokemon_id
+         LEFT JOIN abilities a ON a.name_key = pa.ability_key
+         WHERE p.name_key = ?1
+         ORDER BY pa.slot"
+    )
+    .bind(pokemon_name_key)
+    .bind(GLOBAL_SOURCE)
+    .fetch_all(&mut *conn)
+    .await
+}
+
+/// Load the move overrides of every layer in a chain, merged per move (nearest layer wins per field).
+/// An official version group also gets the values its moves had back then, below its own overrides;
+/// games extending it were authored against current values and don't inherit those.
+pub async fn load_move_overrides(
+    pool: &SqlitePool,
+    chain: &[String],
+) -> Result<HashMap<String, GameMoveOverride>, sqlx::Error> {
+    let mut merged: HashMap<String, GameMoveOverride> = HashMap::new();
+
+    for layer in chain {
+        let rows: Vec<GameMoveOverride> = sqlx::query_as(
+            "SELECT game_id, move_name_key, power, accuracy, type_key, pp, damage_class, effect_en,
+                    game_id AS source
+             FROM game_move_overrides
+             WHERE game_id = ?1"
+        )
+        .bind(layer)
+        .fetch_all(pool)
+        .await?;
+
+        for row in rows {
+            match merged.get_mut(&row.move_name_key) {
+                Some(ov) => {
+                    ov.power = ov.power.or(row.power);
+                    ov.accuracy = ov.accuracy.or(row.accuracy);
+                    ov.type_key = ov.type_key.take().or(row.type_key);
+                    ov.pp = ov.pp.or(row.pp);
+                    ov.damage_class = ov.damage_class.take().or(row.damage_class);
+                    ov.effect_en = ov.effect_en.take().or(row.effect_en);
+                }
+                None => {
+                    merged.insert(row.move_name_key.clone(), row);
+                }
+            }
+        }
+    }
+
+    if let Some(game) = chain.first().filter(|g| is_official_version(g)) {
+        for (move_name_key, past) in super::moves::past_move_overrides(pool, game).await? {
+            match merged.get_mut(&move_name_key) {
+                Some(ov) => {
+                    ov.power = ov.power.or(past.power);
+                    ov.accuracy = ov.accuracy.or(past.accuracy);
+                    ov.type_key = ov.type_key.take().or(past.type_key);
+                    ov.pp = ov.pp.or(past.pp);
+                    ov.effect_en = ov.effect_en.take().or(past.effect_en);
+                }
+                None => {
+                    merged.insert(move_name_key, past);
+                }
+            }
+        }
+    }
+
+    Ok(merged)
+}
@@ -27,5 +27,7 @@ pub async fn upsert_item(pool: &SqlitePool, item: &ParsedItem) -> Result<(), sql
     .execute(pool)
     .await?;
 
+    super::localization::replace_localized_texts(pool, "item", item.id, &item.texts).await?;
+
     Ok(())
 }
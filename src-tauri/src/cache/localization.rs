@@ -0,0 +1,103 @@
+use std::collections::HashMap;
+
+use sqlx::SqlitePool;
+
+use crate::api::localization::LocalizedText;
+
+/// Replace the localized texts of an entity (e.g. "move", 33).
+pub async fn replace_localized_texts(
+    pool: &SqlitePool,
+    entity: &str,
+    entity_id: i64,
+    texts: &[LocalizedText],
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM localized_text WHERE entity = ?1 AND entity_id = ?2")
+        .bind(entity)
+        .bind(entity_id)
+        .execute(&mut *tx)
+        .await?;
+
+    for text in texts {
+        sqlx::query(
+            "INSERT OR REPLACE INTO localized_text (entity, entity_id, field, lang, text)
+             VALUES (?1, ?2, ?3, ?4, ?5)"
+        )
+        .bind(entity)
+        .bind(entity_id)
+        .bind(text.field)
+        .bind(&text.lang)
+        .bind(&text.text)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Whether localized texts have been synced for an entity.
+pub async fn has_localized_texts(pool: &SqlitePool, entity: &str) -> Result<bool, sqlx::Error> {
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM localized_text WHERE entity = ?1")
+        .bind(entity)
+        .fetch_one(pool)
+        .await?;
+    Ok(count > 0)
+}
+
+/// Languages with at least one cached text.
+pub async fn available_languages(pool: &SqlitePool) -> Result<Vec<String>, sqlx::Error> {
+    sqlx::query_scalar("SELECT DISTINCT lang FROM localized_text ORDER BY lang")
+        .fetch_all(pool)
+        .await
+}
+
+/// Fill one localized field of query rows: `slot` gives a row's entity id and the field
+/// to set. Does nothing without a language.
+pub async fn fill_localized<T>(
+    pool: &SqlitePool,
+    entity: &str,
+    field: &str,
+    lang: Option<&str>,
+    rows: &mut [T],
+    slot: fn(&mut T) -> (i64, &mut Option<String>),
+) -> Result<(), sqlx::Error> {
+    let lang = match lang {
+        Some(lang) if !rows.is_empty() => lang,
+        _ => return Ok(()),
+    };
+
+    let ids: Vec<i64> = rows.iter_mut().map(|row| slot(row).0).collect();
+    let ids_json = serde_json::to_string(&ids).unwrap_or_default();
+    let texts: HashMap<i64, String> = sqlx::query_as::<_, (i64, String)>(
+        "SELECT entity_id, text FROM localized_text
+         WHERE entity = ?1 AND field = ?2 AND lang = ?3
+           AND entity_id IN (SELECT value FROM json_each(?4))"
+    )
+    .bind(entity)
+    .bind(field)
+    .bind(lang)
+    .bind(&ids_json)
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+
+    for row in rows.iter_mut() {
+        let (id, target) = slot(row);
+        *target = texts.get(&id).cloned();
+    }
+
+    Ok(())
+}
+
+/// Whether a string looks like a language code ("de", "zh-Hans", "pt-BR", "roomaji").
+pub fn is_language_code(code: &str) -> bool {
+    let mut subtags = code.split('-');
+    let primary = subtags.next().unwrap_or_default();
+    let primary_ok = (2..=8).contains(&primary.len())
+        && primary.chars().all(|c| c.is_ascii_lowercase());
+    primary_ok
+        && subtags.all(|s| (2..=8).contains(&s.len()) && s.chars().all(|c| c.is_ascii_alphanumeric()))
+}
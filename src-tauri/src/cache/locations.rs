@@ -0,0 +1,165 @@
+use sqlx::SqliteConnection;
+
+/// A raw location string reduced to a stable lookup key and a display name.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct NormalizedLocation {
+    pub key: String,
+    pub name: String,
+}
+
+/// Abbreviations found in hackrom documents, mapped to (key token, display token).
+const LOCATION_ALIASES: &[(&str, &str, &str)] = &[
+    ("rt", "route", "Route"),
+    ("rte", "route", "Route"),
+    ("route", "route", "Route"),
+    ("mt", "mt", "Mt."),
+    ("mount", "mt", "Mt."),
+    ("pkmn", "pokemon", "Pokémon"),
+    ("pokemon", "pokemon", "Pokémon"),
+    ("pokémon", "pokemon", "Pokémon"),
+];
+
+/// Normalize a location string from a game data file.
+///
+/// Game files mix plain names ("ROUTE 1 [Grass & Caves]") with item descriptions
+/// ("Route 104: North-west corner of the beach (hidden)." or
+/// "TM001 - Close Combat: Cerulean Cave, 2F, ..."). Only the place name is kept;
+/// case, whitespace and common abbreviations are folded so that "Rt. 101",
+/// "ROUTE 101" and "Route 101" all map to the key `route-101`.
+pub fn normalize_location(raw: &str) -> Option<NormalizedLocation> {
+    let place = extract_place(raw);
+
+    let shouting = !place.chars().any(|c| c.is_lowercase());
+    let mut key_tokens = Vec::new();
+    let mut name_tokens = Vec::new();
+
+    for token in place.split_whitespace() {
+        let lower = token.to_lowercase();
+        let bare = lower.trim_end_matches('.');
+
+        if let Some((_, key, display)) = LOCATION_ALIASES.iter().find(|(alias, _, _)| *alias == bare) {
+            key_tokens.push(key.to_string());
+            name_tokens.push(display.to_string());
+            continue;
+        }
+
+        let key: String = lower
+            .chars()
+            .filter(|c| c.is_alphanumeric() || *c == '-')
+            .collect();
+        if key.is_empty() {
+            continue;
+        }
+        key_tokens.push(key);
+
+        if shouting && token.chars().all(|c| c.is_alphabetic()) {
+            let mut chars = lower.chars();
+            let titled = match chars.next() {
+                Some(first) => first.to_uppercase().chain(chars).collect(),
+                None => String::new(),
+            };
+            name_tokens.push(titled);
+        } else {
+            name_tokens.push(token.to_string());
+        }
+    }
+
+    if key_tokens.is_empty() {
+        return None;
+    }
+
+    Some(NormalizedLocation {
+        key: key_tokens.join("-"),
+        name: name_tokens.join(" "),
+    })
+}
+
+/// Strip annotations and descriptions, keeping only the place name.
+fn extract_place(raw: &str) -> String {
+    // Drop "[Encounter Tables]"-style annotations
+    let mut stripped = String::with_capacity(raw.len());
+    let mut depth = 0usize;
+    for c in raw.chars() {
+        match c {
+            '[' => depth += 1,
+            ']' => depth = depth.saturating_sub(1),
+            _ if depth == 0 => stripped.push(c),
+            _ => {}
+        }
+    }
+
+    // "Place: description" — except "TM001 - Move: Place, description"
+    let place = match stripped.split_once(':') {
+        Some((head, tail)) if is_machine_label(head) => tail,
+        Some((head, _)) => head,
+        None => stripped.as_str(),
+    };
+
+    // "Place, description"
+    let place = place.split(',').next().unwrap_or_default();
+
+    place.trim().trim_end_matches('.').trim().to_string()
+}
+
+/// Machine item of a "TM001 - Close Combat: place" location ("tm001").
+pub fn machine_label(location: &str) -> Option<String> {
+    let head = location.split([' ', '-', ':']).next().unwrap_or_default();
+    if is_machine_label(head) && head.chars().skip(2).all(|c| c.is_ascii_digit()) {
+        Some(head.to_lowercase())
+    } else {
+        None
+    }
+}
+
+/// Whether a string starts with a TM/HM/TR number ("TM001 - Close Combat").
+fn is_machine_label(s: &str) -> bool {
+    let s = s.trim().to_uppercase();
+    ["TM", "HM", "TR"].iter().any(|prefix| {
+        s.strip_prefix(prefix)
+            .and_then(|rest| rest.chars().next())
+            .is_some_and(|c| c.is_ascii_digit())
+    })
+}
+
+/// Register a normalized location for a game (first spelling seen wins the display name).
+pub async fn upsert_game_location(
+    conn: &mut SqliteConnection,
+    game_id: &str,
+    location: &NormalizedLocation,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT OR IGNORE INTO game_locations (game_id, location_key, name)
+         VALUES (?1, ?2, ?3)"
+    )
+    .bind(game_id)
+    .bind(&location.key)
+    .bind(&location.name)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
+
+/// Recompute the pokemon/item counts of every location of a game.
+pub async fn refresh_game_location_counts(
+    conn: &mut SqliteConnection,
+    game_id: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "UPDATE game_locations SET
+           pokemon_count = (
+             SELECT COUNT(DISTINCT gpl.pokemon_name_key) FROM game_pokemon_locations gpl
+             WHERE gpl.game_id = game_locations.game_id AND gpl.location_key = game_locations.location_key
+           ),
+           item_count = (
+             SELECT COUNT(DISTINCT gil.item_name_key) FROM game_item_locations gil
+             WHERE gil.game_id = game_locations.game_id AND gil.location_key = game_locations.location_key
+           )
+         WHERE game_id = ?1"
+    )
+    .bind(game_id)
+    .execute(&mut *conn)
+    .await?;
+
+    Ok(())
+}
@@ -0,0 +1,186 @@
+use std::collections::HashMap;
+
+use sqlx::{SqliteConnection, SqlitePool};
+
+use super::inheritance::version_group_for;
+use crate::api::machines::ParsedMachine;
+use crate::models::games::GameMachine;
+use crate::models::PokemonMoveEntry;
+
+/// Store a PokéAPI machine.
+pub async fn upsert_machine(pool: &SqlitePool, machine: &ParsedMachine) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT OR REPLACE INTO machines (id, version_group, item, move) VALUES (?1, ?2, ?3, ?4)"
+    )
+    .bind(machine.id)
+    .bind(&machine.version_group)
+    .bind(&machine.item)
+    .bind(&machine.move_name_key)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// Set a machine of a game's own TM list.
+pub async fn upsert_game_machine(
+    conn: &mut SqliteConnection,
+    game_id: &str,
+    item: &str,
+    move_name_key: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT OR REPLACE INTO game_machines (game_id, item, move_name_key) VALUES (?1, ?2, ?3)"
+    )
+    .bind(game_id)
+    .bind(item.to_lowercase())
+    .bind(move_name_key)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+/// Drop a game machine whose item locations were all removed.
+pub async fn prune_game_machine(
+    conn: &mut SqliteConnection,
+    game_id: &str,
+    move_name_key: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "DELETE FROM game_machines WHERE game_id = ?1 AND move_name_key = ?2
+           AND NOT EXISTS (SELECT 1 FROM game_item_locations
+                           WHERE game_id = ?1 AND item_name_key = ?2)"
+    )
+    .bind(game_id)
+    .bind(move_name_key)
+    .execute(&mut *conn)
+    .await?;
+    Ok(())
+}
+
+#[derive(sqlx::FromRow)]
+struct MachineRow {
+    item: String,
+    move_name_key: String,
+    move_id: Option<i64>,
+    name_en: Option<String>,
+    name_fr: Option<String>,
+    type_key: Option<String>,
+    damage_class: Option<String>,
+    /// Whether the row is part of a whole TM list (not just the machines a hack changes).
+    complete: bool,
+}
+
+/// Load the machine list of a layer: the game's own list, else its version group's.
+/// A game's own list is whole only when the game lists all of its data.
+async fn load_layer_machines(pool: &SqlitePool, layer: &str) -> Result<Vec<MachineRow>, sqlx::Error> {
+    let rows: Vec<MachineRow> = sqlx::query_as(
+        "SELECT gm.item, gm.move_name_key, m.id AS move_id, m.name_en, m.name_fr,
+                m.type_key, m.damage_class, g.coverage = 'full' AS complete
+         FROM game_machines gm
+         JOIN games g ON g.id = gm.game_id
+         LEFT JOIN moves m ON m.name_key = gm.move_name_key
+         WHERE gm.game_id = ?1"
+    )
+    .bind(layer)
+    .fetch_all(pool)
+    .await?;
+    if !rows.is_empty() {
+        return Ok(rows);
+    }
+
+    sqlx::query_as(
+        "SELECT mc.item, mc.move AS move_name_key, m.id AS move_id, m.name_en, m.name_fr,
+                m.type_key, m.damage_class, 1 AS complete
+         FROM machines mc
+         LEFT JOIN moves m ON m.name_key = mc.move
+         WHERE mc.version_group = ?1
+         ORDER BY mc.id"
+    )
+    .bind(version_group_for(layer))
+    .fetch_all(pool)
+    .await
+}
+
+/// Resolve the machines of a game through its inheritance chain, per machine: the nearest
+/// layer setting a machine (or teaching its move through another one) wins, down to the
+/// first layer with a whole TM list. A hack that moves one TM keeps its base game's others.
+/// Locations come from the nearest layer listing the machine item or its move.
+pub async fn resolve_machines(
+    pool: &SqlitePool,
+    chain: &[String],
+) -> Result<Vec<GameMachine>, sqlx::Error> {
+    let mut machines: Vec<GameMachine> = Vec::new();
+    for layer in chain {
+        let rows = load_layer_machines(pool, layer).await?;
+        let complete = rows.iter().any(|row| row.complete);
+        for row in rows {
+            let taken = machines
+                .iter()
+                .any(|m| m.item == row.item || m.move_name_key == row.move_name_key);
+            if taken {
+                continue;
+            }
+            machines.push(GameMachine {
+                item: row.item,
+                move_name_key: row.move_name_key,
+                move_id: row.move_id,
+                name_en: row.name_en,
+                name_fr: row.name_fr,
+                type_key: row.type_key,
+                damage_class: row.damage_class,
+                source: layer.clone(),
+                locations: Vec::new(),
+            });
+        }
+        if complete {
+            break;
+        }
+    }
+    machines.sort_by_key(|m| machine_order(&m.item));
+
+    for layer in chain {
+        let rows: Vec<(String, String)> = sqlx::query_as(
+            "SELECT item_name_key, location FROM game_item_locations WHERE game_id = ?1 ORDER BY rowid"
+        )
+        .bind(layer)
+        .fetch_all(pool)
+        .await?;
+        let mut by_key: HashMap<String, Vec<String>> = HashMap::new();
+        for (key, location) in rows {
+            by_key.entry(key).or_default().push(location);
+        }
+
+        for machine in machines.iter_mut().filter(|m| m.locations.is_empty()) {
+            let found = by_key
+                .get(&machine.item)
+                .or_else(|| by_key.get(&machine.move_name_key));
+            if let Some(locations) = found {
+                machine.locations = locations.clone();
+            }
+        }
+    }
+
+    Ok(machines)
+}
+
+/// Set the machine number of machine-learned moves.
+pub fn apply_machine_numbers(rows: &mut [PokemonMoveEntry], machines: &[GameMachine]) {
+    for row in rows.iter_mut().filter(|r| r.learn_method == "machine") {
+        row.machine = machines
+            .iter()
+            .find(|m| row.name_key.as_deref() == Some(m.move_name_key.as_str()))
+            .map(|m| m.item.clone());
+    }
+}
+
+/// Sort key of a machine item: TMs, then HMs, then TRs, by number.
+pub fn machine_order(item: &str) -> (u8, i64) {
+    let kind = match item.get(..2) {
+        Some("tm") => 0,
+        Some("hm") => 1,
+        Some("tr") => 2,
+        _ => 3,
+    };
+    let number = item.get(2..).and_then(|n| n.parse().ok()).unwrap_or(i64::MAX);
+    (kind, number)
+}
@@ -0,0 +1,277 @@
+use std::collections::HashMap;
+
+use sqlx::SqlitePool;
+
+use super::games::GameMechanicsRow;
+use super::inheritance::version_group_for;
+use crate::models::games::{GameMechanics, GameMoveOverride, MechanicsProfile};
+use crate::models::{PokemonMoveEntry, TypeEfficacy};
+
+/// Generation assumed when nothing in a game's chain tells (the global tables hold latest data).
+pub const LATEST_GENERATION: i64 = 9;
+
+/// Damage class follows the move's type (generations 1-3).
+pub const SPLIT_BY_TYPE: &str = "type";
+/// Each move has its own damage class (generation 4 onwards).
+pub const SPLIT_BY_MOVE: &str = "move";
+
+/// Official version groups and their generation, in release order.
+const VERSION_GROUP_GENERATIONS: &[(&str, i64)] = &[
+    ("red-blue", 1),
+    ("yellow", 1),
+    ("gold-silver", 2),
+    ("crystal", 2),
+    ("ruby-sapphire", 3),
+    ("emerald", 3),
+    ("firered-leafgreen", 3),
+    ("colosseum", 3),
+    ("xd", 3),
+    ("diamond-pearl", 4),
+    ("platinum", 4),
+    ("heartgold-soulsilver", 4),
+    ("black-white", 5),
+    ("black-2-white-2", 5),
+    ("x-y", 6),
+    ("omega-ruby-alpha-sapphire", 6),
+    ("sun-moon", 7),
+    ("ultra-sun-ultra-moon", 7),
+    ("lets-go-pikachu-lets-go-eevee", 7),
+    ("sword-shield", 8),
+    ("the-isle-of-armor", 8),
+    ("the-crown-tundra", 8),
+    ("brilliant-diamond-and-shining-pearl", 8),
+    ("legends-arceus", 8),
+    ("scarlet-violet", 9),
+    ("the-teal-mask", 9),
+    ("the-indigo-disk", 9),
+];
+
+/// Types that were physical under the type-based split; every other type was special.
+const PHYSICAL_TYPES: &[&str] = &[
+    "normal", "fighting", "flying", "poison", "ground", "rock", "bug", "ghost", "steel",
+];
+
+/// Generation of an official version or version group, if known.
+pub fn generation_for(name: &str) -> Option<i64> {
+    let group = version_group_for(name);
+    VERSION_GROUP_GENERATIONS
+        .iter()
+        .find(|(g, _)| *g == group)
+        .map(|(_, generation)| *generation)
+}
+
+/// Release order of an official version or version group, if known.
+pub fn version_group_order(name: &str) -> Option<usize> {
+    let group = version_group_for(name);
+    VERSION_GROUP_GENERATIONS.iter().position(|(g, _)| *g == group)
+}
+
+/// Official version groups released after a version or version group, in release order.
+pub fn version_groups_after(name: &str) -> Vec<&'static str> {
+    match version_group_order(name) {
+        Some(order) => VERSION_GROUP_GENERATIONS[order + 1..].iter().map(|(g, _)| *g).collect(),
+        None => Vec::new(),
+    }
+}
+
+/// Default mechanics of a generation.
+pub fn default_mechanics(generation: i64) -> MechanicsProfile {
+    MechanicsProfile {
+        generation,
+        damage_split: if generation <= 3 { SPLIT_BY_TYPE } else { SPLIT_BY_MOVE }.to_string(),
+        has_fairy: generation >= 6,
+        crit_multiplier: if generation <= 5 { 2.0 } else { 1.5 },
+        type_chart_overrides: Vec::new(),
+    }
+}
+
+/// Mechanics columns of a chain layer, with its base ROM.
+#[derive(sqlx::FromRow)]
+struct LayerMechanicsRow {
+    base_rom: Option<String>,
+    #[sqlx(flatten)]
+    mechanics: GameMechanicsRow,
+}
+
+/// Resolve the mechanics of a game through its inheritance chain: each field comes from
+/// the nearest layer that sets it, the generation defaults to the first official version
+/// group in the chain, then to the nearest base ROM (a full-coverage hack doesn't inherit
+/// from it but plays like it), and the remaining fields default from the generation.
+/// Type chart overrides of all layers are combined (nearest layer wins per cell).
+pub async fn resolve_game_mechanics(
+    pool: &SqlitePool,
+    chain: &[String],
+) -> Result<MechanicsProfile, sqlx::Error> {
+    let mut merged = GameMechanics::default();
+    let mut official_generation: Option<i64> = None;
+    let mut base_rom_generation: Option<i64> = None;
+
+    for layer in chain {
+        let row: Option<LayerMechanicsRow> = sqlx::query_as(
+            "SELECT base_rom, generation, damage_split, has_fairy, crit_multiplier, type_chart_overrides
+             FROM games WHERE id = ?1"
+        )
+        .bind(layer)
+        .fetch_optional(pool)
+        .await?;
+
+        if base_rom_generation.is_none() {
+            base_rom_generation = row.as_ref().and_then(|r| r.base_rom.as_deref()).and_then(generation_for);
+        }
+
+        if let Some(mechanics) = row.and_then(|r| r.mechanics.into_mechanics()) {
+            merged.generation = merged.generation.or(mechanics.generation);
+            merged.damage_split = merged.damage_split.or(mechanics.damage_split);
+            merged.has_fairy = merged.has_fairy.or(mechanics.has_fairy);
+            merged.crit_multiplier = merged.crit_multiplier.or(mechanics.crit_multiplier);
+            for cell in mechanics.type_chart_overrides {
+                let taken = merged.type_chart_overrides.iter().any(|c| {
+                    c.attacking_type == cell.attacking_type && c.defending_type == cell.defending_type
+                });
+                if !taken {
+                    merged.type_chart_overrides.push(cell);
+                }
+            }
+        }
+
+        if official_generation.is_none() {
+            official_generation = generation_for(layer);
+        }
+    }
+
+    let generation = merged
+        .generation
+        .or(official_generation)
+        .or(base_rom_generation)
+        .unwrap_or(LATEST_GENERATION);
+    let defaults = default_mechanics(generation);
+
+    Ok(MechanicsProfile {
+        generation,
+        damage_split: merged.damage_split.unwrap_or(defaults.damage_split),
+        has_fairy: merged.has_fairy.unwrap_or(defaults.has_fairy),
+        crit_multiplier: merged.crit_multiplier.unwrap_or(defaults.crit_multiplier),
+        type_chart_overrides: merged.type_chart_overrides,
+    })
+}
+
+/// Damage class of a damaging move under the type-based split.
+pub fn type_split_damage_class(type_key: &str) -> &'static str {
+    if PHYSICAL_TYPES.contains(&type_key) {
+        "physical"
+    } else {
+        "special"
+    }
+}
+
+/// Re-derive the damage class of learnset rows from their type when the game uses the
+/// type-based split. Status moves stay status, and a damage class set explicitly by a
+/// move override is kept.
+pub fn apply_damage_split(
+    rows: &mut [PokemonMoveEntry],
+    mechanics: &MechanicsProfile,
+    overrides: &HashMap<String, GameMoveOverride>,
+) {
+    if mechanics.damage_split != SPLIT_BY_TYPE {
+        return;
+    }
+
+    for row in rows {
+        let overridden = row
+            .name_key
+            .as_ref()
+            .and_then(|k| overrides.get(k))
+            .is_some_and(|ov| ov.damage_class.is_some());
+        if overridden || row.damage_class.as_deref() == Some("status") {
+            continue;
+        }
+        if let Some(type_key) = row.type_key.as_deref() {
+            row.damage_class = Some(type_split_damage_class(type_key).to_string());
+        }
+    }
+}
+
+/// The type chart of a generation (empty until types have been synced with their history).
+pub async fn type_efficacy_for_generation(
+    pool: &SqlitePool,
+    generation: i64,
+) -> Result<Vec<TypeEfficacy>, sqlx::Error> {
+    sqlx::query_as(
+        "SELECT attacking_type_id, defending_type_id, damage_factor
+         FROM type_efficacy_generations
+         WHERE generation = ?1
+         ORDER BY attacking_type_id, defending_type_id"
+    )
+    .bind(generation)
+    .fetch_all(pool)
+    .await
+}
+
+/// The type chart under a game's mechanics: the chart of its generation (the Gen 6 one
+/// when an older game adds Fairy, the global chart if no history was synced), without
+/// the Fairy type when the game lacks it, with the game's overrides applied.
+pub async fn game_type_efficacy(
+    pool: &SqlitePool,
+    mechanics: &MechanicsProfile,
+) -> Result<Vec<TypeEfficacy>, sqlx::Error> {
+    let chart_generation = if mechanics.has_fairy {
+        mechanics.generation.max(6)
+    } else {
+        mechanics.generation
+    };
+    let mut rows = type_efficacy_for_generation(pool, chart_generation).await?;
+    if rows.is_empty() {
+        rows = sqlx::query_as(
+            "SELECT attacking_type_id, defending_type_id, damage_factor
+             FROM type_efficacy
+             ORDER BY attacking_type_id, defending_type_id"
+        )
+        .fetch_all(pool)
+        .await?;
+    }
+
+    let type_ids: HashMap<String, i64> = sqlx::query_as::<_, (String, i64)>(
+        "SELECT name_key, id FROM types"
+    )
+    .fetch_all(pool)
+    .await?
+    .into_iter()
+    .collect();
+
+    if !mechanics.has_fairy {
+        if let Some(fairy) = type_ids.get("fairy") {
+            rows.retain(|r| r.attacking_type_id != *fairy && r.defending_type_id != *fairy);
+        }
+    }
+
+    for cell in &mechanics.type_chart_overrides {
+        let (attacking, defending) = match (
+            type_ids.get(&cell.attacking_type),
+            type_ids.get(&cell.defending_type),
+        ) {
+            (Some(a), Some(d)) => (*a, *d),
+            _ => {
+                log::warn!(
+                    "Unknown type in type chart override: {} -> {}",
+                    cell.attacking_type, cell.defending_type
+                );
+                continue;
+            }
+        };
+
+        match rows
+            .iter_mut()
+            .find(|r| r.attacking_type_id == attacking && r.defending_type_id == defending)
+        {
+            Some(row) => row.damage_factor = cell.damage_factor,
+            None => rows.push(TypeEfficacy {
+                attacking_type_id: attacking,
+                defending_type_id: defending,
+                damage_factor: cell.damage_factor,
+            }),
+        }
+    }
+
+    rows.sort_by_key(|r| (r.attacking_type_id, r.defending_type_id));
+    Ok(rows)
+}
@@ -6,3 +6,16 @@ pub mod evolution;
 pub mod natures;
 pub mod abilities;
 pub mod games;
+pub mod locations;
+pub mod trainers;
+pub mod inheritance;
+pub mod mechanics;
+pub mod edits;
+pub mod species;
+pub mod breeding;
+pub mod experience;
+pub mod catching;
+pub mod forms;
+pub mod localization;
+pub mod sprites;
+pub mod machines;
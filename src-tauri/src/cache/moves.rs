@@ -1,13 +1,20 @@
+use std::collections::HashMap;
+
 use sqlx::SqlitePool;
 
-use crate::api::moves::ParsedMove;
+use super::mechanics::{version_group_order, version_groups_after};
+use crate::api::moves::{ParsedMove, ParsedMovePastValue};
 use crate::api::pokemon::ParsedPokemonMove;
+use crate::models::games::GameMoveOverride;
 
-/// Upsert a move record.
+/// Upsert a move record, with its stat changes.
 pub async fn upsert_move(pool: &SqlitePool, m: &ParsedMove) -> Result<(), sqlx::Error> {
     sqlx::query(
-        "INSERT INTO moves (id, name_key, name_en, name_fr, type_key, damage_class, power, accuracy, pp, priority, effect_en, effect_fr)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12)
+        "INSERT INTO moves (id, name_key, name_en, name_fr, type_key, damage_class, power, accuracy, pp, priority, effect_en, effect_fr,
+                            effect_chance, target, crit_rate, drain, healing, min_hits, max_hits,
+                            ailment, ailment_chance, flinch_chance, stat_chance, generation)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12,
+                 ?13, ?14, ?15, ?16, ?17, ?18, ?19, ?20, ?21, ?22, ?23, ?24)
          ON CONFLICT(id) DO UPDATE SET
            name_key = excluded.name_key,
            name_en = excluded.name_en,
@@ -19,7 +26,19 @@ pub async fn upsert_move(pool: &SqlitePool, m: &ParsedMove) -> Result<(), sqlx::
            pp = excluded.pp,
            priority = excluded.priority,
            effect_en = excluded.effect_en,
-           effect_fr = excluded.effect_fr"
+           effect_fr = excluded.effect_fr,
+           effect_chance = excluded.effect_chance,
+           target = excluded.target,
+           crit_rate = excluded.crit_rate,
+           drain = excluded.drain,
+           healing = excluded.healing,
+           min_hits = excluded.min_hits,
+           max_hits = excluded.max_hits,
+           ailment = excluded.ailment,
+           ailment_chance = excluded.ailment_chance,
+           flinch_chance = excluded.flinch_chance,
+           stat_chance = excluded.stat_chance,
+           generation = excluded.generation"
     )
     .bind(m.id)
     .bind(&m.name_key)
@@ -33,12 +52,174 @@ pub async fn upsert_move(pool: &SqlitePool, m: &ParsedMove) -> Result<(), sqlx::
     .bind(m.priority)
     .bind(&m.effect_en)
     .bind(&m.effect_fr)
+    .bind(m.effect_chance)
+    .bind(&m.target)
+    .bind(m.crit_rate)
+    .bind(m.drain)
+    .bind(m.healing)
+    .bind(m.min_hits)
+    .bind(m.max_hits)
+    .bind(&m.ailment)
+    .bind(m.ailment_chance)
+    .bind(m.flinch_chance)
+    .bind(m.stat_chance)
+    .bind(m.generation)
     .execute(pool)
     .await?;
 
+    sqlx::query("DELETE FROM move_stat_changes WHERE move_id = ?1")
+        .bind(m.id)
+        .execute(pool)
+        .await?;
+    for (stat, change) in &m.stat_changes {
+        sqlx::query(
+            "INSERT OR REPLACE INTO move_stat_changes (move_id, stat, change) VALUES (?1, ?2, ?3)"
+        )
+        .bind(m.id)
+        .bind(stat)
+        .bind(change)
+        .execute(pool)
+        .await?;
+    }
+
+    super::localization::replace_localized_texts(pool, "move", m.id, &m.texts).await?;
+
+    Ok(())
+}
+
+/// Replace the flags of all moves (flags of moves not cached yet are skipped).
+pub async fn replace_move_flags(pool: &SqlitePool, flags: &[(i64, String)]) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM move_flags").execute(&mut *tx).await?;
+
+    for (move_id, flag) in flags {
+        sqlx::query(
+            "INSERT OR IGNORE INTO move_flags (move_id, flag)
+             SELECT id, ?2 FROM moves WHERE id = ?1"
+        )
+        .bind(move_id)
+        .bind(flag)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Replace the past values of a move.
+pub async fn replace_move_past_values(
+    pool: &SqlitePool,
+    move_id: i64,
+    past_values: &[ParsedMovePastValue],
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM move_past_values WHERE move_id = ?1")
+        .bind(move_id)
+        .execute(&mut *tx)
+        .await?;
+
+    for pv in past_values {
+        sqlx::query(
+            "INSERT OR REPLACE INTO move_past_values
+               (move_id, version_group, power, accuracy, pp, type_key, effect_en)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)"
+        )
+        .bind(move_id)
+        .bind(&pv.version_group)
+        .bind(pv.power)
+        .bind(pv.accuracy)
+        .bind(pv.pp)
+        .bind(&pv.type_key)
+        .bind(&pv.effect_en)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
     Ok(())
 }
 
+/// Whether cached moves carry everything the current sync fetches (past values, metadata,
+/// flags).
+pub async fn is_move_data_current(pool: &SqlitePool) -> Result<bool, sqlx::Error> {
+    let (past_values, with_metadata, has_flags): (i64, i64, bool) = sqlx::query_as(
+        "SELECT (SELECT COUNT(*) FROM move_past_values),
+                (SELECT COUNT(*) FROM moves WHERE target IS NOT NULL),
+                EXISTS (SELECT 1 FROM move_flags)"
+    )
+    .fetch_one(pool)
+    .await?;
+    Ok(past_values > 0 && with_metadata > 0 && has_flags)
+}
+
+#[derive(sqlx::FromRow)]
+struct MovePastValueRow {
+    move_name_key: String,
+    version_group: String,
+    power: Option<i64>,
+    accuracy: Option<i64>,
+    pp: Option<i64>,
+    type_key: Option<String>,
+    effect_en: Option<String>,
+}
+
+/// Move values of an official version group, as overrides of the current values.
+/// Each past entry holds the values from before its version group changed them, so a
+/// field comes from the earliest entry released after `version_group` that sets it.
+pub async fn past_move_overrides(
+    pool: &SqlitePool,
+    version_group: &str,
+) -> Result<HashMap<String, GameMoveOverride>, sqlx::Error> {
+    let later_groups = version_groups_after(version_group);
+    if later_groups.is_empty() {
+        return Ok(HashMap::new());
+    }
+
+    // Only the entries of version groups released later apply
+    let groups_json = serde_json::to_string(&later_groups).unwrap_or_default();
+    let rows: Vec<MovePastValueRow> = sqlx::query_as(
+        "SELECT m.name_key AS move_name_key, mpv.version_group,
+                mpv.power, mpv.accuracy, mpv.pp, mpv.type_key, mpv.effect_en
+         FROM move_past_values mpv
+         JOIN moves m ON m.id = mpv.move_id
+         WHERE mpv.version_group IN (SELECT value FROM json_each(?1))"
+    )
+    .bind(&groups_json)
+    .fetch_all(pool)
+    .await?;
+
+    let mut later: Vec<(usize, MovePastValueRow)> = rows
+        .into_iter()
+        .filter_map(|row| Some((version_group_order(&row.version_group)?, row)))
+        .collect();
+    later.sort_by_key(|(changed_in, _)| *changed_in);
+
+    let mut overrides: HashMap<String, GameMoveOverride> = HashMap::new();
+    for (_, row) in later {
+        let ov = overrides
+            .entry(row.move_name_key.clone())
+            .or_insert_with(|| GameMoveOverride {
+                game_id: version_group.to_string(),
+                move_name_key: row.move_name_key.clone(),
+                power: None,
+                accuracy: None,
+                type_key: None,
+                pp: None,
+                damage_class: None,
+                effect_en: None,
+                source: Some(version_group.to_string()),
+            });
+        ov.power = ov.power.or(row.power);
+        ov.accuracy = ov.accuracy.or(row.accuracy);
+        ov.pp = ov.pp.or(row.pp);
+        ov.type_key = ov.type_key.take().or(row.type_key);
+        ov.effect_en = ov.effect_en.take().or(row.effect_en);
+    }
+
+    Ok(overrides)
+}
+
 /// Upsert a pokemon-move junction record.
 pub async fn upsert_pokemon_move(
     pool: &SqlitePool,
@@ -26,5 +26,7 @@ pub async fn upsert_nature(pool: &SqlitePool, nature: &ParsedNature) -> Result<(
     .execute(pool)
     .await?;
 
+    super::localization::replace_localized_texts(pool, "nature", nature.id, &nature.texts).await?;
+
     Ok(())
 }
@@ -9,8 +9,10 @@ pub async fn upsert_pokemon(
     p: &ParsedPokemon,
 ) -> Result<(), sqlx::Error> {
     sqlx::query(
-        "INSERT INTO pokemon (id, name_key, type1_key, type2_key, hp, atk, def, spa, spd, spe, base_stat_total, sprite_url, height, weight)
-         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14)
+        "INSERT INTO pokemon (id, name_key, type1_key, type2_key, hp, atk, def, spa, spd, spe, base_stat_total, sprite_url, height, weight,
+                              base_experience, ev_hp, ev_atk, ev_def, ev_spa, ev_spd, ev_spe)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14,
+                 ?15, ?16, ?17, ?18, ?19, ?20, ?21)
          ON CONFLICT(id) DO UPDATE SET
            name_key = excluded.name_key,
            type1_key = excluded.type1_key,
@@ -24,7 +26,14 @@ pub async fn upsert_pokemon(
            base_stat_total = excluded.base_stat_total,
            sprite_url = excluded.sprite_url,
            height = excluded.height,
-           weight = excluded.weight"
+           weight = excluded.weight,
+           base_experience = excluded.base_experience,
+           ev_hp = excluded.ev_hp,
+           ev_atk = excluded.ev_atk,
+           ev_def = excluded.ev_def,
+           ev_spa = excluded.ev_spa,
+           ev_spd = excluded.ev_spd,
+           ev_spe = excluded.ev_spe"
     )
     .bind(p.id)
     .bind(&p.name_key)
@@ -40,6 +49,13 @@ pub async fn upsert_pokemon(
     .bind(&p.sprite_url)
     .bind(p.height)
     .bind(p.weight)
+    .bind(p.base_experience)
+    .bind(p.ev_yield[0])
+    .bind(p.ev_yield[1])
+    .bind(p.ev_yield[2])
+    .bind(p.ev_yield[3])
+    .bind(p.ev_yield[4])
+    .bind(p.ev_yield[5])
     .execute(pool)
     .await?;
 
@@ -0,0 +1,68 @@
+use sqlx::SqlitePool;
+
+use crate::api::species::ParsedSpecies;
+
+/// Upsert a species record with its egg groups.
+pub async fn upsert_species(pool: &SqlitePool, species: &ParsedSpecies) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        "INSERT INTO species (species_id, name_key, name_en, name_fr, genus_en, genus_fr,
+                              capture_rate, base_happiness, gender_rate, growth_rate, hatch_counter,
+                              is_legendary, is_mythical, is_baby, generation,
+                              evolves_from_species_id, evolution_chain_id)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17)
+         ON CONFLICT(species_id) DO UPDATE SET
+           name_key = excluded.name_key,
+           name_en = excluded.name_en,
+           name_fr = excluded.name_fr,
+           genus_en = excluded.genus_en,
+           genus_fr = excluded.genus_fr,
+           capture_rate = excluded.capture_rate,
+           base_happiness = excluded.base_happiness,
+           gender_rate = excluded.gender_rate,
+           growth_rate = excluded.growth_rate,
+           hatch_counter = excluded.hatch_counter,
+           is_legendary = excluded.is_legendary,
+           is_mythical = excluded.is_mythical,
+           is_baby = excluded.is_baby,
+           generation = excluded.generation,
+           evolves_from_species_id = excluded.evolves_from_species_id,
+           evolution_chain_id = excluded.evolution_chain_id"
+    )
+    .bind(species.id)
+    .bind(&species.name_key)
+    .bind(&species.name_en)
+    .bind(&species.name_fr)
+    .bind(&species.genus_en)
+    .bind(&species.genus_fr)
+    .bind(species.capture_rate)
+    .bind(species.base_happiness)
+    .bind(species.gender_rate)
+    .bind(&species.growth_rate)
+    .bind(species.hatch_counter)
+    .bind(species.is_legendary)
+    .bind(species.is_mythical)
+    .bind(species.is_baby)
+    .bind(species.generation)
+    .bind(species.evolves_from_species_id)
+    .bind(species.evolution_chain_id)
+    .execute(&mut *tx)
+    .await?;
+
+    sqlx::query("DELETE FROM species_egg_groups WHERE species_id = ?1")
+        .bind(species.id)
+        .execute(&mut *tx)
+        .await?;
+    for egg_group in &species.egg_groups {
+        sqlx::query("INSERT OR IGNORE INTO species_egg_groups (species_id, egg_group) VALUES (?1, ?2)")
+            .bind(species.id)
+            .bind(egg_group)
+            .execute(&mut *tx)
+            .await?;
+    }
+
+    tx.commit().await?;
+    super::localization::replace_localized_texts(pool, "species", species.id, &species.texts).await?;
+    Ok(())
+}
@@ -0,0 +1,378 @@
+use sha2::{Digest, Sha256};
+use sqlx::SqlitePool;
+
+use super::inheritance::{resolve_game_chain, version_group_for};
+use super::mechanics::resolve_game_mechanics;
+use crate::api::pokemon::ParsedSpriteVariant;
+use crate::models::{PokemonSpriteVariant, PokemonSprites};
+
+/// Where each kind of sprite comes from: official artwork (or front sprite when there is
+/// none), the front sprite of the pokemon's default form, and item sprites.
+const SPRITE_SOURCES: &str =
+    "SELECT 'pokemon' AS kind, id AS entity_id, sprite_url AS url
+     FROM pokemon WHERE sprite_url IS NOT NULL
+     UNION ALL
+     SELECT 'pokemon-sprite', pokemon_id, sprite_url
+     FROM pokemon_forms WHERE is_default = 1 AND sprite_url IS NOT NULL
+     UNION ALL
+     SELECT 'item', id, sprite_url
+     FROM items WHERE sprite_url IS NOT NULL";
+
+/// Kind prefix of a sprite from a game's set: `pokemon-set/emerald/front_default`, keyed
+/// by pokemon id. Pokemon missing from the set get the default sprite.
+pub const POKEMON_SET_KIND: &str = "pokemon-set/";
+
+/// Variants of the game sets downloaded for offline use.
+const GAME_SET_VARIANTS: &str = "('front_default', 'front_shiny')";
+
+/// A sprite to serve or download.
+#[derive(Debug, Clone, sqlx::FromRow)]
+pub struct SpriteAsset {
+    pub kind: String,
+    pub entity_id: i64,
+    pub url: String,
+    pub hash: Option<String>,
+}
+
+/// Register the sprites of every cached pokemon, form and item. A sprite whose URL changed
+/// is downloaded again.
+pub async fn refresh_sprite_sources(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    let sql = format!(
+        "INSERT INTO sprite_assets (kind, entity_id, url)
+         SELECT kind, entity_id, url FROM ({}) WHERE true
+         ON CONFLICT(kind, entity_id) DO UPDATE SET
+           url = excluded.url,
+           hash = CASE WHEN sprite_assets.url = excluded.url THEN sprite_assets.hash END",
+        SPRITE_SOURCES
+    );
+    sqlx::query(&sql).execute(pool).await?;
+    Ok(())
+}
+
+/// Register the front sprites of a game set for every pokemon (the default sprite where the
+/// set has none), so they get downloaded like the other sprites.
+pub async fn register_sprite_set(pool: &SqlitePool, source: &str) -> Result<(), sqlx::Error> {
+    let kind_prefix = format!("{}{}/", POKEMON_SET_KIND, source);
+    let set_rows = format!(
+        "INSERT INTO sprite_assets (kind, entity_id, url)
+         SELECT ?1 || variant, pokemon_id, url FROM pokemon_sprites
+         WHERE source = ?2 AND variant IN {}
+         ON CONFLICT(kind, entity_id) DO UPDATE SET
+           url = excluded.url,
+           hash = CASE WHEN sprite_assets.url = excluded.url THEN sprite_assets.hash END",
+        GAME_SET_VARIANTS
+    );
+    sqlx::query(&set_rows)
+        .bind(&kind_prefix)
+        .bind(source)
+        .execute(pool)
+        .await?;
+
+    let default_rows = format!(
+        "INSERT OR IGNORE INTO sprite_assets (kind, entity_id, url)
+         SELECT ?1 || variant, pokemon_id, url FROM pokemon_sprites
+         WHERE source = 'default' AND variant IN {}",
+        GAME_SET_VARIANTS
+    );
+    sqlx::query(&default_rows)
+        .bind(&kind_prefix)
+        .execute(pool)
+        .await?;
+
+    Ok(())
+}
+
+/// Register the sprite sets of every installed game.
+pub async fn refresh_game_sprite_sets(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    let game_ids: Vec<String> = sqlx::query_scalar("SELECT id FROM games WHERE hidden = 0").fetch_all(pool).await?;
+
+    let mut sets: Vec<String> = Vec::new();
+    for game_id in &game_ids {
+        let set = game_sprite_set(pool, game_id).await?;
+        if set != "default" && !sets.contains(&set) {
+            sets.push(set);
+        }
+    }
+    for set in &sets {
+        register_sprite_set(pool, set).await?;
+    }
+
+    Ok(())
+}
+
+/// Whether some registered sprite isn't downloaded yet.
+pub async fn has_missing_sprites(pool: &SqlitePool) -> Result<bool, sqlx::Error> {
+    sqlx::query_scalar("SELECT EXISTS (SELECT 1 FROM sprite_assets WHERE hash IS NULL)")
+        .fetch_one(pool)
+        .await
+}
+
+/// Every registered sprite, downloaded or not.
+pub async fn get_sprite_assets(pool: &SqlitePool) -> Result<Vec<SpriteAsset>, sqlx::Error> {
+    sqlx::query_as(
+        "SELECT kind, entity_id, url, hash FROM sprite_assets ORDER BY kind, entity_id"
+    )
+    .fetch_all(pool)
+    .await
+}
+
+/// A sprite by kind and id; falls back to the source tables when the sprite phase never ran.
+pub async fn get_sprite_asset(
+    pool: &SqlitePool,
+    kind: &str,
+    entity_id: i64,
+) -> Result<Option<SpriteAsset>, sqlx::Error> {
+    let registered: Option<SpriteAsset> = sqlx::query_as(
+        "SELECT kind, entity_id, url, hash FROM sprite_assets WHERE kind = ?1 AND entity_id = ?2"
+    )
+    .bind(kind)
+    .bind(entity_id)
+    .fetch_optional(pool)
+    .await?;
+    if registered.is_some() {
+        return Ok(registered);
+    }
+
+    if let Some((source, variant)) = kind.strip_prefix(POKEMON_SET_KIND).and_then(|s| s.split_once('/')) {
+        return sqlx::query_as(
+            "SELECT ?1 AS kind, pokemon_id AS entity_id, url, NULL AS hash FROM pokemon_sprites
+             WHERE pokemon_id = ?2 AND source IN (?3, 'default') AND variant = ?4
+             ORDER BY source = 'default' LIMIT 1"
+        )
+        .bind(kind)
+        .bind(entity_id)
+        .bind(source)
+        .bind(variant)
+        .fetch_optional(pool)
+        .await;
+    }
+
+    let sql = format!(
+        "SELECT kind, entity_id, url, NULL AS hash FROM ({})
+         WHERE kind = ?1 AND entity_id = ?2 LIMIT 1",
+        SPRITE_SOURCES
+    );
+    sqlx::query_as(&sql)
+        .bind(kind)
+        .bind(entity_id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Hash of an already downloaded copy of `url`, if any (several forms share a sprite).
+pub async fn hash_for_url(pool: &SqlitePool, url: &str) -> Result<Option<String>, sqlx::Error> {
+    sqlx::query_scalar(
+        "SELECT hash FROM sprite_assets WHERE url = ?1 AND hash IS NOT NULL LIMIT 1"
+    )
+    .bind(url)
+    .fetch_optional(pool)
+    .await
+}
+
+/// Record the cached file of a sprite.
+pub async fn set_sprite_hash(
+    pool: &SqlitePool,
+    kind: &str,
+    entity_id: i64,
+    url: &str,
+    hash: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query(
+        "INSERT INTO sprite_assets (kind, entity_id, url, hash) VALUES (?1, ?2, ?3, ?4)
+         ON CONFLICT(kind, entity_id) DO UPDATE SET url = excluded.url, hash = excluded.hash"
+    )
+    .bind(kind)
+    .bind(entity_id)
+    .bind(url)
+    .bind(hash)
+    .execute(pool)
+    .await?;
+    Ok(())
+}
+
+/// SHA-256 of a sprite file, hex-encoded.
+pub fn sprite_hash(bytes: &[u8]) -> String {
+    format!("{:x}", Sha256::digest(bytes))
+}
+
+/// MIME type of an image from its signature, `None` if it isn't a supported image
+/// (e.g. an HTML error page or a truncated download).
+pub fn image_mime(bytes: &[u8]) -> Option<&'static str> {
+    if bytes.starts_with(b"\x89PNG\r\n\x1a\n") {
+        Some("image/png")
+    } else if bytes.starts_with(b"GIF87a") || bytes.starts_with(b"GIF89a") {
+        Some("image/gif")
+    } else if bytes.starts_with(b"\xff\xd8\xff") {
+        Some("image/jpeg")
+    } else if bytes.len() >= 12 && &bytes[0..4] == b"RIFF" && &bytes[8..12] == b"WEBP" {
+        Some("image/webp")
+    } else if bytes.starts_with(b"<svg") || bytes.starts_with(b"<?xml") {
+        Some("image/svg+xml")
+    } else {
+        None
+    }
+}
+
+/// Replace the sprite variants of a pokemon.
+pub async fn replace_pokemon_sprites(
+    pool: &SqlitePool,
+    pokemon_id: i64,
+    sprites: &[ParsedSpriteVariant],
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+
+    sqlx::query("DELETE FROM pokemon_sprites WHERE pokemon_id = ?1")
+        .bind(pokemon_id)
+        .execute(&mut *tx)
+        .await?;
+
+    for sprite in sprites {
+        sqlx::query(
+            "INSERT OR REPLACE INTO pokemon_sprites (pokemon_id, source, generation, animated, variant, url)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)"
+        )
+        .bind(pokemon_id)
+        .bind(&sprite.source)
+        .bind(sprite.generation)
+        .bind(sprite.animated)
+        .bind(&sprite.variant)
+        .bind(&sprite.url)
+        .execute(&mut *tx)
+        .await?;
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Whether sprite variants have been synced.
+pub async fn has_pokemon_sprites(pool: &SqlitePool) -> Result<bool, sqlx::Error> {
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM pokemon_sprites")
+        .fetch_one(pool)
+        .await?;
+    Ok(count > 0)
+}
+
+/// Every sprite variant of a pokemon.
+pub async fn get_pokemon_sprite_variants(
+    pool: &SqlitePool,
+    pokemon_id: i64,
+) -> Result<Vec<PokemonSpriteVariant>, sqlx::Error> {
+    sqlx::query_as(
+        "SELECT source, generation, animated, variant, url FROM pokemon_sprites
+         WHERE pokemon_id = ?1 ORDER BY generation, source, variant"
+    )
+    .bind(pokemon_id)
+    .fetch_all(pool)
+    .await
+}
+
+/// Layers to match sprite sets against for a game, and its generation. A hack that lists
+/// all its data doesn't inherit from its base ROM but still looks like it.
+pub async fn game_sprite_context(
+    pool: &SqlitePool,
+    game_id: &str,
+) -> Result<(Vec<String>, i64), sqlx::Error> {
+    let mut chain = resolve_game_chain(pool, game_id).await?;
+    let profile = resolve_game_mechanics(pool, &chain).await?;
+
+    let base_rom: Option<Option<String>> = sqlx::query_scalar("SELECT base_rom FROM games WHERE id = ?1")
+        .bind(game_id)
+        .fetch_optional(pool)
+        .await?;
+    if let Some(base_rom) = base_rom.flatten() {
+        if !chain.contains(&base_rom) {
+            chain.push(base_rom);
+        }
+    }
+
+    Ok((chain, profile.generation))
+}
+
+/// The sprite set of a game as a whole ("default" if none), for lists of pokemon.
+/// Same choice as `pick_sprite_set`, over the sets of every pokemon.
+pub async fn game_sprite_set(pool: &SqlitePool, game_id: &str) -> Result<String, sqlx::Error> {
+    let (chain, generation) = game_sprite_context(pool, game_id).await?;
+    let variants: Vec<PokemonSpriteVariant> = sqlx::query_as(
+        "SELECT DISTINCT source, generation, animated, variant, '' AS url FROM pokemon_sprites"
+    )
+    .fetch_all(pool)
+    .await?;
+
+    Ok(pick_sprite_set(&variants, &chain, Some(generation)).0)
+}
+
+/// Pick the sprite set to show for a game: the set of a game in its inheritance chain
+/// (e.g. "emerald" for an Emerald hack), else the fullest still set of its generation,
+/// else the default sprites. Sets without a front sprite (pokemon not in that game) are skipped.
+pub fn pick_sprite_set(
+    variants: &[PokemonSpriteVariant],
+    chain: &[String],
+    generation: Option<i64>,
+) -> (String, Option<i64>) {
+    let mut sets: Vec<(&str, Option<i64>, usize)> = Vec::new();
+    for v in variants.iter().filter(|v| v.generation.is_some() && !v.animated) {
+        match sets.iter_mut().find(|(source, _, _)| *source == v.source) {
+            Some(set) => set.2 += 1,
+            None => sets.push((&v.source, v.generation, 1)),
+        }
+    }
+    sets.retain(|(source, _, _)| {
+        !source.ends_with("-icons")
+            && variants.iter().any(|v| v.source == *source && v.variant == "front_default")
+    });
+
+    // PokéAPI drops some dashes in set names ("omegaruby-alphasapphire")
+    let group_key = |name: &str| version_group_for(name).replace('-', "");
+    for layer in chain {
+        let exact = sets.iter().find(|(source, _, _)| source == layer);
+        let grouped = sets.iter().find(|(source, _, _)| group_key(source) == group_key(layer));
+        if let Some((source, generation, _)) = exact.or(grouped) {
+            return (source.to_string(), *generation);
+        }
+    }
+
+    let fullest = sets
+        .iter()
+        .filter(|(_, set_generation, _)| generation.is_some() && *set_generation == generation)
+        .max_by(|a, b| a.2.cmp(&b.2).then_with(|| b.0.cmp(a.0)));
+    match fullest {
+        Some((source, generation, _)) => (source.to_string(), *generation),
+        None => ("default".to_string(), None),
+    }
+}
+
+/// Sprites of a pokemon from the picked set, with artwork and animated sprites alongside.
+pub fn build_pokemon_sprites(
+    pokemon_id: i64,
+    variants: Vec<PokemonSpriteVariant>,
+    source: String,
+    generation: Option<i64>,
+) -> PokemonSprites {
+    let url = |source: &str, variant: &str| {
+        variants
+            .iter()
+            .find(|v| v.source == source && v.variant == variant)
+            .map(|v| v.url.clone())
+    };
+    // Gen 1 sets have no shiny sprites: use the default ones. Female sprites only exist
+    // where the game shows gender differences, so they never fall back.
+    let picked = |variant: &str| url(&source, variant).or_else(|| url("default", variant));
+
+    PokemonSprites {
+        pokemon_id,
+        front_default: picked("front_default"),
+        front_shiny: picked("front_shiny"),
+        front_female: url(&source, "front_female"),
+        front_shiny_female: url(&source, "front_shiny_female"),
+        back_default: picked("back_default"),
+        back_shiny: picked("back_shiny"),
+        artwork: url("official-artwork", "front_default"),
+        artwork_shiny: url("official-artwork", "front_shiny"),
+        animated: url("showdown", "front_default"),
+        animated_shiny: url("showdown", "front_shiny"),
+        source,
+        generation,
+        variants,
+    }
+}
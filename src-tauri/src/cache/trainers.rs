@@ -0,0 +1,183 @@
+use sqlx::{SqliteConnection, SqlitePool};
+
+use super::locations;
+use crate::models::games::{TrainerEntry, TrainerPokemonEntry};
+
+#[derive(sqlx::FromRow)]
+struct TrainerRow {
+    trainer_id: i64,
+    name: String,
+    trainer_class: Option<String>,
+    location: Option<String>,
+    battle_type: String,
+    level_cap: Option<i64>,
+}
+
+/// Raw team row; IVs/EVs are stored as JSON.
+#[derive(sqlx::FromRow)]
+struct TrainerPokemonRow {
+    trainer_id: i64,
+    slot: i64,
+    pokemon_name_key: String,
+    level: i64,
+    ability_key: Option<String>,
+    item_key: Option<String>,
+    nature_key: Option<String>,
+    ivs: Option<String>,
+    evs: Option<String>,
+}
+
+/// Import a trainer and its team. `trainer_id` is the trainer's position in progression order.
+pub async fn import_trainer(
+    conn: &mut SqliteConnection,
+    game_id: &str,
+    trainer_id: i64,
+    trainer: &TrainerEntry,
+) -> Result<(), sqlx::Error> {
+    let location_key = trainer
+        .location
+        .as_deref()
+        .and_then(locations::normalize_location)
+        .map(|l| l.key);
+
+    sqlx::query(
+        "INSERT OR REPLACE INTO game_trainers (game_id, trainer_id, name, trainer_class, location, location_key, battle_type, level_cap)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)"
+    )
+    .bind(game_id)
+    .bind(trainer_id)
+    .bind(&trainer.name)
+    .bind(&trainer.trainer_class)
+    .bind(&trainer.location)
+    .bind(&location_key)
+    .bind(&trainer.battle_type)
+    .bind(trainer.level_cap)
+    .execute(&mut *conn)
+    .await?;
+
+    for (slot, pokemon) in trainer.team.iter().enumerate() {
+        import_trainer_pokemon(&mut *conn, game_id, trainer_id, slot as i64 + 1, pokemon).await?;
+    }
+
+    Ok(())
+}
+
+async fn import_trainer_pokemon(
+    conn: &mut SqliteConnection,
+    game_id: &str,
+    trainer_id: i64,
+    slot: i64,
+    pokemon: &TrainerPokemonEntry,
+) -> Result<(), sqlx::Error> {
+    let ivs = pokemon.ivs.as_ref().and_then(|s| serde_json::to_string(s).ok());
+    let evs = pokemon.evs.as_ref().and_then(|s| serde_json::to_string(s).ok());
+
+    sqlx::query(
+        "INSERT OR REPLACE INTO game_trainer_pokemon (game_id, trainer_id, slot, pokemon_name_key, level, ability_key, item_key, nature_key, ivs, evs)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10)"
+    )
+    .bind(game_id)
+    .bind(trainer_id)
+    .bind(slot)
+    .bind(&pokemon.name_key)
+    .bind(pokemon.level)
+    .bind(&pokemon.ability_key)
+    .bind(&pokemon.item_key)
+    .bind(&pokemon.nature_key)
+    .bind(&ivs)
+    .bind(&evs)
+    .execute(&mut *conn)
+    .await?;
+
+    for (move_slot, move_name_key) in pokemon.moves.iter().enumerate() {
+        sqlx::query(
+            "INSERT OR REPLACE INTO game_trainer_pokemon_moves (game_id, trainer_id, slot, move_slot, move_name_key)
+             VALUES (?1, ?2, ?3, ?4, ?5)"
+        )
+        .bind(game_id)
+        .bind(trainer_id)
+        .bind(slot)
+        .bind(move_slot as i64 + 1)
+        .bind(move_name_key)
+        .execute(&mut *conn)
+        .await?;
+    }
+
+    Ok(())
+}
+
+/// Rebuild the trainer list of a game, in progression order.
+pub async fn export_game_trainers(
+    pool: &SqlitePool,
+    game_id: &str,
+) -> Result<Vec<TrainerEntry>, sqlx::Error> {
+    let trainers: Vec<TrainerRow> = sqlx::query_as(
+        "SELECT trainer_id, name, trainer_class, location, battle_type, level_cap
+         FROM game_trainers WHERE game_id = ?1 ORDER BY trainer_id"
+    )
+    .bind(game_id)
+    .fetch_all(pool)
+    .await?;
+
+    let team: Vec<TrainerPokemonRow> = sqlx::query_as(
+        "SELECT trainer_id, slot, pokemon_name_key, level, ability_key, item_key, nature_key, ivs, evs
+         FROM game_trainer_pokemon WHERE game_id = ?1 ORDER BY trainer_id, slot"
+    )
+    .bind(game_id)
+    .fetch_all(pool)
+    .await?;
+
+    let moves: Vec<(i64, i64, String)> = sqlx::query_as(
+        "SELECT trainer_id, slot, move_name_key
+         FROM game_trainer_pokemon_moves WHERE game_id = ?1 ORDER BY trainer_id, slot, move_slot"
+    )
+    .bind(game_id)
+    .fetch_all(pool)
+    .await?;
+
+    let entries = trainers
+        .into_iter()
+        .map(|t| TrainerEntry {
+            team: team
+                .iter()
+                .filter(|p| p.trainer_id == t.trainer_id)
+                .map(|p| TrainerPokemonEntry {
+                    name_key: p.pokemon_name_key.clone(),
+                    level: p.level,
+                    ability_key: p.ability_key.clone(),
+                    item_key: p.item_key.clone(),
+                    nature_key: p.nature_key.clone(),
+                    moves: moves
+                        .iter()
+                        .filter(|(trainer_id, slot, _)| *trainer_id == t.trainer_id && *slot == p.slot)
+                        .map(|(_, _, move_name_key)| move_name_key.clone())
+                        .collect(),
+                    ivs: p.ivs.as_deref().and_then(|s| serde_json::from_str(s).ok()),
+                    evs: p.evs.as_deref().and_then(|s| serde_json::from_str(s).ok()),
+                })
+                .collect(),
+            name: t.name,
+            trainer_class: t.trainer_class,
+            location: t.location,
+            battle_type: t.battle_type,
+            level_cap: t.level_cap,
+        })
+        .collect();
+
+    Ok(entries)
+}
+
+/// Delete all trainers of a game.
+pub async fn delete_game_trainers(
+    conn: &mut SqliteConnection,
+    game_id: &str,
+) -> Result<(), sqlx::Error> {
+    sqlx::query("DELETE FROM game_trainer_pokemon_moves WHERE game_id = ?1")
+        .bind(game_id).execute(&mut *conn).await?;
+    sqlx::query("DELETE FROM game_trainer_pokemon WHERE game_id = ?1")
+        .bind(game_id).execute(&mut *conn).await?;
+    sqlx::query("DELETE FROM game_trainers WHERE game_id = ?1")
+        .bind(game_id).execute(&mut *conn).await?;
+
+    Ok(())
+}
@@ -1,24 +1,31 @@
+use std::collections::HashMap;
+
 use sqlx::SqlitePool;
 
-use crate::api::types::{ParsedType, ParsedTypeEfficacy};
+use super::mechanics::LATEST_GENERATION;
+use crate::api::types::{ParsedDamageRelations, ParsedType, ParsedTypeEfficacy, ParsedTypeRelations};
 
 /// Upsert a type record.
 pub async fn upsert_type(pool: &SqlitePool, t: &ParsedType) -> Result<(), sqlx::Error> {
     sqlx::query(
-        "INSERT INTO types (id, name_key, name_en, name_fr)
-         VALUES (?1, ?2, ?3, ?4)
+        "INSERT INTO types (id, name_key, name_en, name_fr, generation)
+         VALUES (?1, ?2, ?3, ?4, ?5)
          ON CONFLICT(id) DO UPDATE SET
            name_key = excluded.name_key,
            name_en = excluded.name_en,
-           name_fr = excluded.name_fr"
+           name_fr = excluded.name_fr,
+           generation = excluded.generation"
     )
     .bind(t.id)
     .bind(&t.name_key)
     .bind(&t.name_en)
     .bind(&t.name_fr)
+    .bind(t.generation)
     .execute(pool)
     .await?;
 
+    super::localization::replace_localized_texts(pool, "type", t.id, &t.texts).await?;
+
     Ok(())
 }
 
@@ -41,3 +48,83 @@ pub async fn upsert_type_efficacy(
 
     Ok(())
 }
+
+/// Relations of a type in a generation: the oldest past entry still covering it, else the
+/// current ones. The flag tells whether they come from a past entry.
+fn relations_at(relations: &ParsedTypeRelations, generation: i64) -> (&ParsedDamageRelations, bool) {
+    relations
+        .past
+        .iter()
+        .find(|(until, _)| *until >= generation)
+        .map(|(_, r)| (r, true))
+        .unwrap_or((&relations.current, false))
+}
+
+fn factor_of(factors: &[(i64, i64)], type_id: i64) -> Option<i64> {
+    factors.iter().find(|(id, _)| *id == type_id).map(|(_, f)| *f)
+}
+
+/// Rebuild the per-generation type charts from the current and past relations of all types.
+/// A cell is read from whichever side has past relations for the generation (the attacker's
+/// when both or neither do), since only that side records the change.
+pub async fn replace_type_efficacy_generations(
+    pool: &SqlitePool,
+    types: &[ParsedTypeRelations],
+) -> Result<(), sqlx::Error> {
+    let mut tx = pool.begin().await?;
+    sqlx::query("DELETE FROM type_efficacy_generations")
+        .execute(&mut *tx)
+        .await?;
+
+    for generation in 1..=LATEST_GENERATION {
+        let present: Vec<&ParsedTypeRelations> = types
+            .iter()
+            .filter(|t| t.generation.unwrap_or(1) <= generation)
+            .collect();
+        let at: HashMap<i64, (&ParsedDamageRelations, bool)> = present
+            .iter()
+            .map(|t| (t.type_id, relations_at(t, generation)))
+            .collect();
+
+        for attacking in &present {
+            let (attacker, attacker_past) = at[&attacking.type_id];
+            for defending in &present {
+                let (defender, defender_past) = at[&defending.type_id];
+                let to = factor_of(&attacker.to, defending.type_id);
+                let from = factor_of(&defender.from, attacking.type_id);
+                let factor = match (attacker_past, defender_past) {
+                    (true, false) => to,
+                    (false, true) => from,
+                    _ => to.or(from),
+                };
+
+                let factor = match factor {
+                    Some(f) if f != 100 => f,
+                    _ => continue,
+                };
+                sqlx::query(
+                    "INSERT INTO type_efficacy_generations
+                       (generation, attacking_type_id, defending_type_id, damage_factor)
+                     VALUES (?1, ?2, ?3, ?4)"
+                )
+                .bind(generation)
+                .bind(attacking.type_id)
+                .bind(defending.type_id)
+                .bind(factor)
+                .execute(&mut *tx)
+                .await?;
+            }
+        }
+    }
+
+    tx.commit().await?;
+    Ok(())
+}
+
+/// Whether the per-generation type charts have been built.
+pub async fn has_type_efficacy_generations(pool: &SqlitePool) -> Result<bool, sqlx::Error> {
+    let count: i64 = sqlx::query_scalar("SELECT COUNT(*) FROM type_efficacy_generations")
+        .fetch_one(pool)
+        .await?;
+    Ok(count > 0)
+}
@@ -1,3 +1,4 @@
+use crate::cache::localization::fill_localized;
 use crate::models::{AbilitySummary, AbilityDetail, AbilityPokemonEntry};
 use crate::AppState;
 use tauri::State;
@@ -5,8 +6,9 @@ use tauri::State;
 #[tauri::command]
 pub async fn get_all_abilities(
     state: State<'_, AppState>,
+    lang: Option<String>,
 ) -> Result<Vec<AbilitySummary>, String> {
-    let rows: Vec<AbilitySummary> = sqlx::query_as(
+    let mut rows: Vec<AbilitySummary> = sqlx::query_as(
         "SELECT id, name_key, name_en, name_fr, short_effect_en, short_effect_fr, generation
          FROM abilities ORDER BY id",
     )
@@ -14,6 +16,13 @@ pub async fn get_all_abilities(
     .await
     .map_err(|e| e.to_string())?;
 
+    fill_localized(&state.pool, "ability", "name", lang.as_deref(), &mut rows, |x| (x.id, &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+    fill_localized(&state.pool, "ability", "short_effect", lang.as_deref(), &mut rows, |x| (x.id, &mut x.short_effect_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
     Ok(rows)
 }
 
@@ -21,8 +30,9 @@ pub async fn get_all_abilities(
 pub async fn get_ability_by_id(
     state: State<'_, AppState>,
     id: i64,
+    lang: Option<String>,
 ) -> Result<Option<AbilityDetail>, String> {
-    let row: Option<AbilityDetail> = sqlx::query_as(
+    let mut row: Option<AbilityDetail> = sqlx::query_as(
         "SELECT id, name_key, name_en, name_fr, effect_en, effect_fr, short_effect_en, short_effect_fr, generation
          FROM abilities WHERE id = ?1",
     )
@@ -31,6 +41,17 @@ pub async fn get_ability_by_id(
     .await
     .map_err(|e| e.to_string())?;
 
+    let details = row.as_mut_slice();
+    fill_localized(&state.pool, "ability", "name", lang.as_deref(), &mut *details, |x| (x.id, &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+    fill_localized(&state.pool, "ability", "effect", lang.as_deref(), &mut *details, |x| (x.id, &mut x.effect_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+    fill_localized(&state.pool, "ability", "short_effect", lang.as_deref(), details, |x| (x.id, &mut x.short_effect_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
     Ok(row)
 }
 
@@ -38,15 +59,18 @@ pub async fn get_ability_by_id(
 pub async fn search_abilities(
     state: State<'_, AppState>,
     query: String,
+    lang: Option<String>,
 ) -> Result<Vec<AbilitySummary>, String> {
     let pattern = format!("%{}%", query.to_lowercase());
 
-    let rows: Vec<AbilitySummary> = sqlx::query_as(
+    let mut rows: Vec<AbilitySummary> = sqlx::query_as(
         "SELECT id, name_key, name_en, name_fr, short_effect_en, short_effect_fr, generation
          FROM abilities
          WHERE LOWER(name_key) LIKE ?1
             OR LOWER(name_en) LIKE ?1
             OR LOWER(name_fr) LIKE ?1
+            OR id IN (SELECT entity_id FROM localized_text
+                      WHERE entity = 'ability' AND field = 'name' AND LOWER(text) LIKE ?1)
          ORDER BY id
          LIMIT 50",
     )
@@ -55,6 +79,13 @@ pub async fn search_abilities(
     .await
     .map_err(|e| e.to_string())?;
 
+    fill_localized(&state.pool, "ability", "name", lang.as_deref(), &mut rows, |x| (x.id, &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+    fill_localized(&state.pool, "ability", "short_effect", lang.as_deref(), &mut rows, |x| (x.id, &mut x.short_effect_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
     Ok(rows)
 }
 
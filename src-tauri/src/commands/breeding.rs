@@ -0,0 +1,72 @@
+use crate::cache::breeding::{self, EGG_METHOD};
+use crate::cache::inheritance::{resolve_game_chain, resolve_move_learners, resolve_pokemon_moves};
+use crate::models::{BreedingChain, BreedingCompatibility, PokemonMoveEntry};
+use crate::AppState;
+use tauri::State;
+
+/// Check whether two pokemon can breed (egg groups, genders, Ditto).
+#[tauri::command]
+pub async fn check_breeding_compatibility(
+    state: State<'_, AppState>,
+    pokemon_a: String,
+    pokemon_b: String,
+) -> Result<BreedingCompatibility, String> {
+    let profiles = breeding::load_breeding_profiles(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let a = profiles
+        .get(&pokemon_a)
+        .ok_or_else(|| format!("No species data for: {}", pokemon_a))?;
+    let b = profiles
+        .get(&pokemon_b)
+        .ok_or_else(|| format!("No species data for: {}", pokemon_b))?;
+
+    Ok(breeding::compatibility(a, b))
+}
+
+/// Get the egg moves of a pokemon in a game.
+#[tauri::command]
+pub async fn get_game_egg_moves(
+    state: State<'_, AppState>,
+    game_id: String,
+    pokemon_name_key: String,
+) -> Result<Vec<PokemonMoveEntry>, String> {
+    let chain = resolve_game_chain(&state.pool, &game_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let rows = resolve_pokemon_moves(&state.pool, &chain, &pokemon_name_key)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(rows.into_iter().filter(|r| r.learn_method == EGG_METHOD).collect())
+}
+
+/// Find the shortest breeding chain bringing an egg move onto a pokemon in a game.
+/// Returns None when no chain exists (not an egg move, male-only or genderless target, ...).
+#[tauri::command]
+pub async fn find_breeding_chain(
+    state: State<'_, AppState>,
+    game_id: String,
+    pokemon_name_key: String,
+    move_name_key: String,
+) -> Result<Option<BreedingChain>, String> {
+    let chain = resolve_game_chain(&state.pool, &game_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let learners = resolve_move_learners(&state.pool, &chain, &move_name_key)
+        .await
+        .map_err(|e| e.to_string())?;
+    let profiles = breeding::load_breeding_profiles(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let steps = breeding::shortest_breeding_chain(&pokemon_name_key, &learners, &profiles);
+    Ok(steps.map(|steps| BreedingChain {
+        pokemon_name_key,
+        move_name_key,
+        steps,
+    }))
+}
@@ -0,0 +1,101 @@
+use crate::cache::catching::{self, CatchTarget};
+use crate::models::{CatchChance, CatchChanceParams};
+use crate::AppState;
+use tauri::State;
+
+/// Item categories holding Poké Balls.
+const BALL_CATEGORIES: &[&str] = &["standard-balls", "special-balls", "apricorn-balls"];
+
+/// Bounds of the first throw's turn and of the number of throws.
+const MAX_TURNS: i64 = 100;
+
+/// Calculate the chance to catch a pokemon, per throw and cumulated over several throws
+/// (one per turn). Critical captures are not accounted for.
+#[tauri::command]
+pub async fn calculate_catch_chance(
+    state: State<'_, AppState>,
+    params: CatchChanceParams,
+) -> Result<CatchChance, String> {
+    if params.generation < 3 {
+        return Err("Catch formulas are only available from Gen 3".to_string());
+    }
+    if !(1..=MAX_TURNS).contains(&params.turn) {
+        return Err(format!("Turn must be between 1 and {}", MAX_TURNS));
+    }
+    if !(1..=MAX_TURNS).contains(&params.throws) {
+        return Err(format!("Throws must be between 1 and {}", MAX_TURNS));
+    }
+
+    let ball_category: Option<String> = sqlx::query_scalar(
+        "SELECT category FROM items WHERE name_key = ?1"
+    )
+    .bind(&params.ball)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    match ball_category {
+        Some(category) if BALL_CATEGORIES.contains(&category.as_str()) => {}
+        _ => return Err(format!("Unknown ball: {}", params.ball)),
+    }
+
+    let row: Option<(Option<i64>, Option<String>, Option<String>)> = sqlx::query_as(
+        "SELECT s.capture_rate, p.type1_key, p.type2_key
+         FROM pokemon p
+         LEFT JOIN species s ON s.species_id = COALESCE(p.species_id, p.id)
+         WHERE p.name_key = ?1"
+    )
+    .bind(&params.pokemon_name_key)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    let (capture_rate, type1, type2) =
+        row.ok_or_else(|| format!("Unknown pokemon: {}", params.pokemon_name_key))?;
+    let capture_rate =
+        capture_rate.ok_or_else(|| format!("No capture rate for: {}", params.pokemon_name_key))?;
+
+    let types: Vec<String> = type1.into_iter().chain(type2).collect();
+    let target = CatchTarget {
+        level: params.level,
+        types: &types,
+        status: params.status.as_deref(),
+    };
+    let status_modifier = catching::status_modifier(params.status.as_deref(), params.generation);
+    let guaranteed = catching::is_guaranteed_ball(&params.ball);
+
+    let mut ball_modifiers = Vec::new();
+    let mut per_throw = Vec::new();
+    let mut cumulative = Vec::new();
+    let mut missed_all = 1.0;
+    for throw in 0..params.throws {
+        let turn = params.turn + throw;
+        let ball = catching::ball_modifier(&params.ball, params.generation, turn, &target, &params.conditions);
+        let chance = if guaranteed {
+            1.0
+        } else if params.generation <= 4 {
+            catching::catch_chance_gen3(capture_rate, params.hp_percent, ball, status_modifier)
+        } else {
+            catching::catch_chance_gen5(
+                capture_rate,
+                params.hp_percent,
+                params.level,
+                ball,
+                status_modifier,
+                params.generation,
+            )
+        };
+
+        missed_all *= 1.0 - chance;
+        ball_modifiers.push(ball);
+        per_throw.push(chance);
+        cumulative.push(1.0 - missed_all);
+    }
+
+    Ok(CatchChance {
+        capture_rate,
+        formula: if params.generation <= 4 { "gen3" } else { "gen5" }.to_string(),
+        status_modifier,
+        ball_modifiers,
+        per_throw,
+        cumulative,
+    })
+}
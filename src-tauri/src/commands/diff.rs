@@ -0,0 +1,629 @@
+use std::collections::{BTreeMap, BTreeSet, HashMap};
+use std::fmt::Write;
+
+use crate::cache::inheritance::{
+    load_move_overrides, resolve_game_chain, resolve_learnset, resolve_pokemon_abilities,
+    GLOBAL_SOURCE,
+};
+use crate::models::games::GameMoveOverride;
+use crate::models::{
+    AbilityChange, GameDiff, LearnsetDiffEntry, LevelChange, MoveDiff, PokemonAbility,
+    PokemonDiff, PokemonMoveEntry, StatChange,
+};
+use crate::AppState;
+use sqlx::SqlitePool;
+use tauri::State;
+
+/// Global move stats, the base that game overrides apply to.
+#[derive(sqlx::FromRow)]
+struct BaseMove {
+    name_key: String,
+    name_en: Option<String>,
+    name_fr: Option<String>,
+    type_key: Option<String>,
+    damage_class: Option<String>,
+    power: Option<i64>,
+    accuracy: Option<i64>,
+    pp: Option<i64>,
+    effect_en: Option<String>,
+}
+
+/// Learnset row tagged with its pokemon, for whole-layer loads.
+#[derive(sqlx::FromRow)]
+struct KeyedMove {
+    pokemon_name_key: String,
+    #[sqlx(flatten)]
+    entry: PokemonMoveEntry,
+}
+
+/// Ability row tagged with its pokemon, for whole-layer loads.
+#[derive(sqlx::FromRow)]
+struct KeyedAbility {
+    pokemon_name_key: String,
+    #[sqlx(flatten)]
+    ability: PokemonAbility,
+}
+
+/// Resolved learnsets and abilities of one side of a diff, by pokemon name key,
+/// with the layer each list was resolved from.
+#[derive(Default)]
+struct ResolvedPokemon {
+    learnsets: HashMap<String, Vec<PokemonMoveEntry>>,
+    abilities: HashMap<String, Vec<PokemonAbility>>,
+    learnset_sources: HashMap<String, String>,
+    ability_sources: HashMap<String, String>,
+}
+
+impl ResolvedPokemon {
+    /// Side holding a single pokemon, resolved by `resolve_learnset`/`resolve_pokemon_abilities`.
+    fn single(pokemon_name_key: &str, moves: Vec<PokemonMoveEntry>, abilities: Vec<PokemonAbility>) -> Self {
+        let mut side = Self::default();
+        if let Some(source) = moves.first().and_then(|m| m.source.clone()) {
+            side.learnset_sources.insert(pokemon_name_key.to_string(), source);
+        }
+        if let Some(source) = abilities.first().and_then(|a| a.source.clone()) {
+            side.ability_sources.insert(pokemon_name_key.to_string(), source);
+        }
+        side.learnsets.insert(pokemon_name_key.to_string(), moves);
+        side.abilities.insert(pokemon_name_key.to_string(), abilities);
+        side
+    }
+
+    fn learnset(&self, pokemon_name_key: &str) -> &[PokemonMoveEntry] {
+        self.learnsets.get(pokemon_name_key).map(Vec::as_slice).unwrap_or(&[])
+    }
+
+    fn abilities(&self, pokemon_name_key: &str) -> &[PokemonAbility] {
+        self.abilities.get(pokemon_name_key).map(Vec::as_slice).unwrap_or(&[])
+    }
+}
+
+type PokemonNames = HashMap<String, (i64, Option<String>, Option<String>)>;
+
+/// Compare the learnset and abilities of one pokemon between two games.
+/// Both sides are resolved through their inheritance chains.
+#[tauri::command]
+pub async fn diff_game_pokemon(
+    state: State<'_, AppState>,
+    game_a: String,
+    game_b: String,
+    pokemon_name_key: String,
+) -> Result<PokemonDiff, String> {
+    let chain_a = resolve_game_chain(&state.pool, &game_a)
+        .await
+        .map_err(|e| e.to_string())?;
+    let chain_b = resolve_game_chain(&state.pool, &game_b)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let pool = &state.pool;
+    let moves_a = resolve_learnset(pool, &chain_a, &pokemon_name_key)
+        .await
+        .map_err(|e| e.to_string())?;
+    let moves_b = resolve_learnset(pool, &chain_b, &pokemon_name_key)
+        .await
+        .map_err(|e| e.to_string())?;
+    let abilities_a = resolve_pokemon_abilities(pool, &chain_a, &pokemon_name_key)
+        .await
+        .map_err(|e| e.to_string())?;
+    let abilities_b = resolve_pokemon_abilities(pool, &chain_b, &pokemon_name_key)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let keys = BTreeSet::from([pokemon_name_key.clone()]);
+    let names = load_pokemon_names(pool, &keys).await?;
+
+    let side_a = ResolvedPokemon::single(&pokemon_name_key, moves_a, abilities_a);
+    let side_b = ResolvedPokemon::single(&pokemon_name_key, moves_b, abilities_b);
+
+    Ok(diff_pokemon(&pokemon_name_key, names.get(&pokemon_name_key), &side_a, &side_b))
+}
+
+/// Compare two games: every pokemon listed in either inheritance chain, and every
+/// move overridden in either chain. Only entries with changes are returned.
+#[tauri::command]
+pub async fn diff_games(
+    state: State<'_, AppState>,
+    game_a: String,
+    game_b: String,
+) -> Result<GameDiff, String> {
+    build_game_diff(&state.pool, &game_a, &game_b).await
+}
+
+/// Export the differences between two games as a Markdown changelog.
+#[tauri::command]
+pub async fn export_game_changelog(
+    state: State<'_, AppState>,
+    game_a: String,
+    game_b: String,
+) -> Result<String, String> {
+    let diff = build_game_diff(&state.pool, &game_a, &game_b).await?;
+
+    let name_a = game_name(&state.pool, &game_a).await?;
+    let name_b = game_name(&state.pool, &game_b).await?;
+
+    Ok(render_changelog(&diff, &name_a, &name_b))
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────
+
+async fn build_game_diff(pool: &SqlitePool, game_a: &str, game_b: &str) -> Result<GameDiff, String> {
+    let chain_a = resolve_game_chain(pool, game_a)
+        .await
+        .map_err(|e| e.to_string())?;
+    let chain_b = resolve_game_chain(pool, game_b)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    // Both sides are loaded in bulk (one query per layer) and diffed in memory
+    let mut side_a = load_layers(pool, &chain_a).await?;
+    let mut side_b = load_layers(pool, &chain_b).await?;
+
+    // Every pokemon listed by any layer of either chain
+    let pokemon_keys: BTreeSet<String> = [&side_a, &side_b]
+        .iter()
+        .flat_map(|side| side.learnsets.keys().chain(side.abilities.keys()))
+        .cloned()
+        .collect();
+    fill_global(pool, &pokemon_keys, &mut side_a, &mut side_b).await?;
+    let names = load_pokemon_names(pool, &pokemon_keys).await?;
+
+    let mut pokemon = Vec::new();
+    for key in &pokemon_keys {
+        let diff = diff_pokemon(key, names.get(key), &side_a, &side_b);
+        if !diff.added_moves.is_empty()
+            || !diff.removed_moves.is_empty()
+            || !diff.level_changes.is_empty()
+            || !diff.ability_changes.is_empty()
+        {
+            pokemon.push(diff);
+        }
+    }
+    pokemon.sort_by_key(|p| (p.pokemon_id.unwrap_or(i64::MAX), p.pokemon_name_key.clone()));
+
+    let moves = diff_moves(pool, &chain_a, &chain_b).await?;
+
+    Ok(GameDiff {
+        game_a: game_a.to_string(),
+        game_b: game_b.to_string(),
+        pokemon,
+        moves,
+    })
+}
+
+/// Load the learnsets and abilities listed by each layer of a chain, one query per layer.
+/// Same layering as `resolve_learnset`/`resolve_pokemon_abilities`: the nearest layer
+/// listing a pokemon wins. The global fallback is added by `fill_global`.
+async fn load_layers(pool: &SqlitePool, chain: &[String]) -> Result<ResolvedPokemon, String> {
+    let mut side = ResolvedPokemon::default();
+
+    for layer in chain {
+        let moves: Vec<KeyedMove> = sqlx::query_as(
+            "SELECT
+               gpm.pokemon_name_key,
+               0 AS pokemon_id,
+               COALESCE(m.id, 0) AS move_id,
+               gpm.learn_method,
+               gpm.level_learned_at,
+               gpm.move_name_key AS name_key,
+               m.name_en,
+               m.name_fr,
+               m.type_key,
+               m.damage_class,
+               m.power,
+               m.accuracy,
+               m.pp,
+               gpm.game_id AS source
+             FROM game_pokemon_moves gpm
+             LEFT JOIN moves m ON m.name_key = gpm.move_name_key
+             WHERE gpm.game_id = ?1
+             ORDER BY gpm.pokemon_name_key, gpm.learn_method, gpm.level_learned_at, gpm.move_name_key"
+        )
+        .bind(layer)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let mut learnsets: HashMap<String, Vec<PokemonMoveEntry>> = HashMap::new();
+        for row in moves {
+            learnsets.entry(row.pokemon_name_key).or_default().push(row.entry);
+        }
+        for (key, rows) in learnsets {
+            if !side.learnsets.contains_key(&key) {
+                side.learnset_sources.insert(key.clone(), layer.clone());
+                side.learnsets.insert(key, rows);
+            }
+        }
+
+        let abilities: Vec<KeyedAbility> = sqlx::query_as(
+            "SELECT
+               gpa.pokemon_name_key,
+               0 AS pokemon_id,
+               a.id AS ability_id,
+               gpa.ability_key,
+               a.name_en AS ability_en,
+               a.name_fr AS ability_fr,
+               a.short_effect_en,
+               a.short_effect_fr,
+               gpa.is_hidden,
+               gpa.slot,
+               gpa.game_id AS source
+             FROM game_pokemon_abilities gpa
+             LEFT JOIN abilities a ON a.name_key = gpa.ability_key
+             WHERE gpa.game_id = ?1
+             ORDER BY gpa.pokemon_name_key, gpa.slot"
+        )
+        .bind(layer)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        let mut by_pokemon: HashMap<String, Vec<PokemonAbility>> = HashMap::new();
+        for row in abilities {
+            by_pokemon.entry(row.pokemon_name_key).or_default().push(row.ability);
+        }
+        for (key, rows) in by_pokemon {
+            if !side.abilities.contains_key(&key) {
+                side.ability_sources.insert(key.clone(), layer.clone());
+                side.abilities.insert(key, rows);
+            }
+        }
+    }
+
+    Ok(side)
+}
+
+/// Fill pokemon no layer lists on a side with the global learnsets and abilities,
+/// loaded once for both sides.
+async fn fill_global(
+    pool: &SqlitePool,
+    keys: &BTreeSet<String>,
+    side_a: &mut ResolvedPokemon,
+    side_b: &mut ResolvedPokemon,
+) -> Result<(), String> {
+    let missing = |pick: &dyn Fn(&ResolvedPokemon, &String) -> bool| -> Vec<&String> {
+        keys.iter()
+            .filter(|k| !pick(side_a, k) || !pick(side_b, k))
+            .collect()
+    };
+    let missing_moves = missing(&|side, k| side.learnsets.contains_key(k));
+    let missing_abilities = missing(&|side, k| side.abilities.contains_key(k));
+
+    let mut learnsets: HashMap<String, Vec<PokemonMoveEntry>> = HashMap::new();
+    if !missing_moves.is_empty() {
+        let keys_json = serde_json::to_string(&missing_moves).unwrap_or_default();
+        let rows: Vec<KeyedMove> = sqlx::query_as(
+            "SELECT p.name_key AS pokemon_name_key,
+                    pm.pokemon_id, pm.move_id, pm.learn_method, pm.level_learned_at,
+                    m.name_key, m.name_en, m.name_fr, m.type_key, m.damage_class,
+                    m.power, m.accuracy, m.pp,
+                    ?2 AS source
+             FROM pokemon_moves pm
+             JOIN pokemon p ON p.id = pm.pokemon_id
+             JOIN moves m ON pm.move_id = m.id
+             WHERE p.name_key IN (SELECT value FROM json_each(?1))
+             ORDER BY p.name_key, pm.learn_method, pm.level_learned_at, m.name_key"
+        )
+        .bind(&keys_json)
+        .bind(GLOBAL_SOURCE)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        for row in rows {
+            learnsets.entry(row.pokemon_name_key).or_default().push(row.entry);
+        }
+    }
+
+    let mut abilities: HashMap<String, Vec<PokemonAbility>> = HashMap::new();
+    if !missing_abilities.is_empty() {
+        let keys_json = serde_json::to_string(&missing_abilities).unwrap_or_default();
+        let rows: Vec<KeyedAbility> = sqlx::query_as(
+            "SELECT p.name_key AS pokemon_name_key,
+                    pa.pokemon_id, a.id AS ability_id, pa.ability_key,
+                    COALESCE(a.name_en, pa.ability_en) AS ability_en,
+                    COALESCE(a.name_fr, pa.ability_fr) AS ability_fr,
+                    a.short_effect_en, a.short_effect_fr,
+                    pa.is_hidden, pa.slot,
+                    ?2 AS source
+             FROM pokemon_abilities pa
+             JOIN pokemon p ON p.id = pa.pokemon_id
+             LEFT JOIN abilities a ON a.name_key = pa.ability_key
+             WHERE p.name_key IN (SELECT value FROM json_each(?1))
+             ORDER BY p.name_key, pa.slot"
+        )
+        .bind(&keys_json)
+        .bind(GLOBAL_SOURCE)
+        .fetch_all(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+        for row in rows {
+            abilities.entry(row.pokemon_name_key).or_default().push(row.ability);
+        }
+    }
+
+    for side in [side_a, side_b] {
+        for (key, rows) in &learnsets {
+            if !side.learnsets.contains_key(key) {
+                side.learnset_sources.insert(key.clone(), GLOBAL_SOURCE.to_string());
+                side.learnsets.insert(key.clone(), rows.clone());
+            }
+        }
+        for (key, rows) in &abilities {
+            if !side.abilities.contains_key(key) {
+                side.ability_sources.insert(key.clone(), GLOBAL_SOURCE.to_string());
+                side.abilities.insert(key.clone(), rows.clone());
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Load (id, name_en, name_fr) of the given pokemon, by name key.
+async fn load_pokemon_names(pool: &SqlitePool, keys: &BTreeSet<String>) -> Result<PokemonNames, String> {
+    let keys_json = serde_json::to_string(keys).unwrap_or_default();
+    let rows: Vec<(String, i64, Option<String>, Option<String>)> = sqlx::query_as(
+        "SELECT name_key, id, name_en, name_fr FROM pokemon
+         WHERE name_key IN (SELECT value FROM json_each(?1))"
+    )
+    .bind(&keys_json)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(rows
+        .into_iter()
+        .map(|(key, id, name_en, name_fr)| (key, (id, name_en, name_fr)))
+        .collect())
+}
+
+/// Compare one pokemon's resolved learnsets and abilities (side a, side b).
+fn diff_pokemon(
+    pokemon_name_key: &str,
+    names: Option<&(i64, Option<String>, Option<String>)>,
+    side_a: &ResolvedPokemon,
+    side_b: &ResolvedPokemon,
+) -> PokemonDiff {
+    let (moves_a, moves_b) = (side_a.learnset(pokemon_name_key), side_b.learnset(pokemon_name_key));
+    let (abilities_a, abilities_b) = (side_a.abilities(pokemon_name_key), side_b.abilities(pokemon_name_key));
+    let learnset_a = learnset_by_move(moves_a);
+    let learnset_b = learnset_by_move(moves_b);
+
+    let entry = |(move_key, method): &(String, String), r: &PokemonMoveEntry| {
+        LearnsetDiffEntry {
+            move_name_key: move_key.clone(),
+            move_name_en: r.name_en.clone(),
+            move_name_fr: r.name_fr.clone(),
+            learn_method: method.clone(),
+            level: r.level_learned_at,
+        }
+    };
+
+    let mut added_moves = Vec::new();
+    let mut removed_moves = Vec::new();
+    let mut level_changes = Vec::new();
+    let keys: BTreeSet<&(String, String)> = learnset_a.keys().chain(learnset_b.keys()).collect();
+    for key in keys {
+        let mut only_a: Vec<&PokemonMoveEntry> = learnset_a.get(key).cloned().unwrap_or_default();
+        let mut only_b: Vec<&PokemonMoveEntry> = learnset_b.get(key).cloned().unwrap_or_default();
+        // Levels present on both sides are unchanged
+        only_a.retain(|a| !learnset_b.get(key).is_some_and(|b| b.iter().any(|b| b.level_learned_at == a.level_learned_at)));
+        only_b.retain(|b| !learnset_a.get(key).is_some_and(|a| a.iter().any(|a| a.level_learned_at == b.level_learned_at)));
+
+        // Remaining levels are paired up as level changes; extras are additions/removals
+        let paired = only_a.len().min(only_b.len());
+        for (a, b) in only_a.iter().zip(only_b.iter()) {
+            level_changes.push(LevelChange {
+                move_name_key: key.0.clone(),
+                move_name_en: b.name_en.clone(),
+                move_name_fr: b.name_fr.clone(),
+                learn_method: key.1.clone(),
+                level_a: a.level_learned_at,
+                level_b: b.level_learned_at,
+            });
+        }
+        removed_moves.extend(only_a[paired..].iter().map(|a| entry(key, a)));
+        added_moves.extend(only_b[paired..].iter().map(|b| entry(key, b)));
+    }
+
+    // Abilities compared slot by slot
+    let slots: BTreeSet<i64> = abilities_a
+        .iter()
+        .chain(abilities_b.iter())
+        .map(|a| a.slot)
+        .collect();
+    let mut ability_changes = Vec::new();
+    for slot in slots {
+        let a = abilities_a.iter().find(|a| a.slot == slot);
+        let b = abilities_b.iter().find(|a| a.slot == slot);
+        if a.map(|a| &a.ability_key) == b.map(|b| &b.ability_key) {
+            continue;
+        }
+        ability_changes.push(AbilityChange {
+            slot,
+            is_hidden: b.or(a).map(|x| x.is_hidden).unwrap_or(0),
+            ability_a: a.map(|a| a.ability_key.clone()),
+            ability_a_en: a.and_then(|a| a.ability_en.clone()),
+            ability_b: b.map(|b| b.ability_key.clone()),
+            ability_b_en: b.and_then(|b| b.ability_en.clone()),
+        });
+    }
+
+    PokemonDiff {
+        pokemon_name_key: pokemon_name_key.to_string(),
+        pokemon_id: names.map(|n| n.0),
+        name_en: names.and_then(|n| n.1.clone()),
+        name_fr: names.and_then(|n| n.2.clone()),
+        learnset_source_a: side_a.learnset_sources.get(pokemon_name_key).cloned(),
+        learnset_source_b: side_b.learnset_sources.get(pokemon_name_key).cloned(),
+        ability_source_a: side_a.ability_sources.get(pokemon_name_key).cloned(),
+        ability_source_b: side_b.ability_sources.get(pokemon_name_key).cloned(),
+        added_moves,
+        removed_moves,
+        level_changes,
+        ability_changes,
+    }
+}
+
+/// Learnset grouped by (move, learn method); a move can be learned at several levels.
+fn learnset_by_move(rows: &[PokemonMoveEntry]) -> BTreeMap<(String, String), Vec<&PokemonMoveEntry>> {
+    let mut map: BTreeMap<(String, String), Vec<&PokemonMoveEntry>> = BTreeMap::new();
+    for row in rows {
+        if let Some(ref key) = row.name_key {
+            map.entry((key.clone(), row.learn_method.clone())).or_default().push(row);
+        }
+    }
+    map
+}
+
+/// Compare effective move stats (merged overrides over global stats) for every overridden move.
+async fn diff_moves(
+    pool: &SqlitePool,
+    chain_a: &[String],
+    chain_b: &[String],
+) -> Result<Vec<MoveDiff>, String> {
+    let overrides_a = load_move_overrides(pool, chain_a)
+        .await
+        .map_err(|e| e.to_string())?;
+    let overrides_b = load_move_overrides(pool, chain_b)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let keys: BTreeSet<&String> = overrides_a.keys().chain(overrides_b.keys()).collect();
+
+    let keys_json = serde_json::to_string(&keys).unwrap_or_default();
+    let base_moves: Vec<BaseMove> = sqlx::query_as(
+        "SELECT name_key, name_en, name_fr, type_key, damage_class, power, accuracy, pp, effect_en
+         FROM moves WHERE name_key IN (SELECT value FROM json_each(?1))"
+    )
+    .bind(&keys_json)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    let base_moves: HashMap<&str, &BaseMove> =
+        base_moves.iter().map(|m| (m.name_key.as_str(), m)).collect();
+
+    let mut diffs = Vec::new();
+    for key in keys {
+        let base = base_moves.get(key.as_str()).copied();
+
+        let a = effective_move_stats(base, overrides_a.get(key));
+        let b = effective_move_stats(base, overrides_b.get(key));
+
+        let changes: Vec<StatChange> = a
+            .into_iter()
+            .zip(b)
+            .filter(|((_, va), (_, vb))| va != vb)
+            .map(|((field, value_a), (_, value_b))| StatChange {
+                field: field.to_string(),
+                value_a,
+                value_b,
+            })
+            .collect();
+
+        if !changes.is_empty() {
+            diffs.push(MoveDiff {
+                move_name_key: key.clone(),
+                name_en: base.and_then(|m| m.name_en.clone()),
+                name_fr: base.and_then(|m| m.name_fr.clone()),
+                changes,
+            });
+        }
+    }
+
+    Ok(diffs)
+}
+
+fn effective_move_stats(
+    base: Option<&BaseMove>,
+    ov: Option<&GameMoveOverride>,
+) -> Vec<(&'static str, Option<String>)> {
+    let pick_str = |o: Option<&Option<String>>, b: Option<&Option<String>>| {
+        o.and_then(|v| v.clone()).or_else(|| b.and_then(|v| v.clone()))
+    };
+    let pick_num = |o: Option<i64>, b: Option<i64>| o.or(b).map(|v| v.to_string());
+
+    vec![
+        ("type", pick_str(ov.map(|o| &o.type_key), base.map(|b| &b.type_key))),
+        ("damage_class", pick_str(ov.map(|o| &o.damage_class), base.map(|b| &b.damage_class))),
+        ("power", pick_num(ov.and_then(|o| o.power), base.and_then(|b| b.power))),
+        ("accuracy", pick_num(ov.and_then(|o| o.accuracy), base.and_then(|b| b.accuracy))),
+        ("pp", pick_num(ov.and_then(|o| o.pp), base.and_then(|b| b.pp))),
+        ("effect", pick_str(ov.map(|o| &o.effect_en), base.map(|b| &b.effect_en))),
+    ]
+}
+
+async fn game_name(pool: &SqlitePool, game_id: &str) -> Result<String, String> {
+    let name: Option<String> = sqlx::query_scalar("SELECT name_en FROM games WHERE id = ?1")
+        .bind(game_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(name.unwrap_or_else(|| game_id.to_string()))
+}
+
+fn render_changelog(diff: &GameDiff, name_a: &str, name_b: &str) -> String {
+    let mut out = String::new();
+    let display = |en: &Option<String>, key: &str| en.clone().unwrap_or_else(|| key.to_string());
+    let value = |v: &Option<String>| v.clone().unwrap_or_else(|| "—".to_string());
+
+    let _ = writeln!(out, "# {} → {}", name_a, name_b);
+
+    if diff.pokemon.is_empty() && diff.moves.is_empty() {
+        let _ = writeln!(out, "\nNo differences.");
+        return out;
+    }
+
+    if !diff.pokemon.is_empty() {
+        let _ = writeln!(out, "\n## Pokémon");
+    }
+    for p in &diff.pokemon {
+        let _ = writeln!(out, "\n### {}\n", display(&p.name_en, &p.pokemon_name_key));
+        for a in &p.ability_changes {
+            let _ = writeln!(
+                out,
+                "- Ability {}{}: {} → {}",
+                a.slot,
+                if a.is_hidden == 1 { " (hidden)" } else { "" },
+                a.ability_a_en.clone().or(a.ability_a.clone()).unwrap_or_else(|| "—".to_string()),
+                a.ability_b_en.clone().or(a.ability_b.clone()).unwrap_or_else(|| "—".to_string()),
+            );
+        }
+        for m in &p.added_moves {
+            let _ = writeln!(out, "- Added {} ({})", display(&m.move_name_en, &m.move_name_key), learn_label(&m.learn_method, m.level));
+        }
+        for m in &p.removed_moves {
+            let _ = writeln!(out, "- Removed {} ({})", display(&m.move_name_en, &m.move_name_key), learn_label(&m.learn_method, m.level));
+        }
+        for m in &p.level_changes {
+            let _ = writeln!(
+                out,
+                "- {}: Lv. {} → Lv. {}",
+                display(&m.move_name_en, &m.move_name_key),
+                m.level_a,
+                m.level_b
+            );
+        }
+    }
+
+    if !diff.moves.is_empty() {
+        let _ = writeln!(out, "\n## Moves");
+    }
+    for m in &diff.moves {
+        let _ = writeln!(out, "\n### {}\n", display(&m.name_en, &m.move_name_key));
+        for c in &m.changes {
+            let _ = writeln!(out, "- {}: {} → {}", c.field, value(&c.value_a), value(&c.value_b));
+        }
+    }
+
+    out
+}
+
+fn learn_label(method: &str, level: i64) -> String {
+    if method == "level-up" {
+        format!("Lv. {}", level)
+    } else {
+        method.to_string()
+    }
+}
@@ -0,0 +1,332 @@
+use crate::cache::inheritance::{resolve_game_chain, resolve_learnset, resolve_pokemon_abilities};
+use crate::cache::{edits, locations};
+use crate::models::games::{AbilityOverrideEntry, LearnsetEntry, MoveOverrideEntry};
+use crate::models::{GameEdit, GameEditChange};
+use crate::AppState;
+use sqlx::SqlitePool;
+use tauri::State;
+
+/// Author recorded when the caller doesn't name one.
+const DEFAULT_AUTHOR: &str = "local";
+
+/// Get the edit log of a game, oldest first.
+#[tauri::command]
+pub async fn get_game_edits(
+    state: State<'_, AppState>,
+    game_id: String,
+) -> Result<Vec<GameEdit>, String> {
+    edits::get_game_edits(&state.pool, &game_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+// ── Learnsets ────────────────────────────────────────────────────────
+
+/// Add a learnset entry to a pokemon.
+#[tauri::command]
+pub async fn add_game_learnset_entry(
+    state: State<'_, AppState>,
+    game_id: String,
+    pokemon_name_key: String,
+    entry: LearnsetEntry,
+    author: Option<String>,
+) -> Result<(), String> {
+    if learnset_entry_exists(&state.pool, &game_id, &pokemon_name_key, &entry).await? {
+        return Err(format!("{} already learns {}", pokemon_name_key, entry.move_name_key));
+    }
+
+    let change = GameEditChange::Learnset { pokemon_name_key, before: None, after: Some(entry) };
+    commit_edit(&state.pool, &game_id, change, author).await
+}
+
+/// Replace a learnset entry (e.g. change the level a move is learned at).
+#[tauri::command]
+pub async fn update_game_learnset_entry(
+    state: State<'_, AppState>,
+    game_id: String,
+    pokemon_name_key: String,
+    before: LearnsetEntry,
+    after: LearnsetEntry,
+    author: Option<String>,
+) -> Result<(), String> {
+    if !learnset_entry_exists(&state.pool, &game_id, &pokemon_name_key, &before).await? {
+        return Err(format!("{} doesn't learn {} that way", pokemon_name_key, before.move_name_key));
+    }
+
+    let change = GameEditChange::Learnset { pokemon_name_key, before: Some(before), after: Some(after) };
+    commit_edit(&state.pool, &game_id, change, author).await
+}
+
+/// Remove a learnset entry from a pokemon.
+#[tauri::command]
+pub async fn remove_game_learnset_entry(
+    state: State<'_, AppState>,
+    game_id: String,
+    pokemon_name_key: String,
+    entry: LearnsetEntry,
+    author: Option<String>,
+) -> Result<(), String> {
+    if !learnset_entry_exists(&state.pool, &game_id, &pokemon_name_key, &entry).await? {
+        return Err(format!("{} doesn't learn {} that way", pokemon_name_key, entry.move_name_key));
+    }
+
+    let change = GameEditChange::Learnset { pokemon_name_key, before: Some(entry), after: None };
+    commit_edit(&state.pool, &game_id, change, author).await
+}
+
+// ── Abilities ────────────────────────────────────────────────────────
+
+/// Set the ability in a pokemon's ability slot (adds or replaces).
+#[tauri::command]
+pub async fn set_game_pokemon_ability(
+    state: State<'_, AppState>,
+    game_id: String,
+    pokemon_name_key: String,
+    ability: AbilityOverrideEntry,
+    author: Option<String>,
+) -> Result<(), String> {
+    let before = current_ability(&state.pool, &game_id, &pokemon_name_key, ability.slot).await?;
+
+    let change = GameEditChange::Ability { pokemon_name_key, before, after: Some(ability) };
+    commit_edit(&state.pool, &game_id, change, author).await
+}
+
+/// Clear a pokemon's ability slot.
+#[tauri::command]
+pub async fn remove_game_pokemon_ability(
+    state: State<'_, AppState>,
+    game_id: String,
+    pokemon_name_key: String,
+    slot: i64,
+    author: Option<String>,
+) -> Result<(), String> {
+    let before = current_ability(&state.pool, &game_id, &pokemon_name_key, slot)
+        .await?
+        .ok_or_else(|| format!("{} has no ability in slot {}", pokemon_name_key, slot))?;
+
+    let change = GameEditChange::Ability { pokemon_name_key, before: Some(before), after: None };
+    commit_edit(&state.pool, &game_id, change, author).await
+}
+
+// ── Locations ────────────────────────────────────────────────────────
+
+/// Add a location where a pokemon can be found.
+#[tauri::command]
+pub async fn add_game_pokemon_location(
+    state: State<'_, AppState>,
+    game_id: String,
+    pokemon_name_key: String,
+    location: String,
+    author: Option<String>,
+) -> Result<(), String> {
+    let change = GameEditChange::PokemonLocation { pokemon_name_key, before: None, after: Some(location) };
+    commit_edit(&state.pool, &game_id, change, author).await
+}
+
+/// Remove a location of a pokemon (raw location string, as returned by get_game_pokemon_locations).
+#[tauri::command]
+pub async fn remove_game_pokemon_location(
+    state: State<'_, AppState>,
+    game_id: String,
+    pokemon_name_key: String,
+    location: String,
+    author: Option<String>,
+) -> Result<(), String> {
+    let exists: Option<String> = sqlx::query_scalar(
+        "SELECT location FROM game_pokemon_locations
+         WHERE game_id = ?1 AND pokemon_name_key = ?2 AND location = ?3"
+    )
+    .bind(&game_id)
+    .bind(&pokemon_name_key)
+    .bind(&location)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    if exists.is_none() {
+        return Err(format!("{} is not found at {}", pokemon_name_key, location));
+    }
+
+    let change = GameEditChange::PokemonLocation { pokemon_name_key, before: Some(location), after: None };
+    commit_edit(&state.pool, &game_id, change, author).await
+}
+
+/// Add a location where an item can be found.
+#[tauri::command]
+pub async fn add_game_item_location(
+    state: State<'_, AppState>,
+    game_id: String,
+    item_name_key: String,
+    location: String,
+    author: Option<String>,
+) -> Result<(), String> {
+    let change = GameEditChange::ItemLocation { item_name_key, before: None, after: Some(location) };
+    commit_edit(&state.pool, &game_id, change, author).await
+}
+
+/// Remove a location of an item (raw location string, as returned by get_game_item_locations).
+#[tauri::command]
+pub async fn remove_game_item_location(
+    state: State<'_, AppState>,
+    game_id: String,
+    item_name_key: String,
+    location: String,
+    author: Option<String>,
+) -> Result<(), String> {
+    let exists: Option<String> = sqlx::query_scalar(
+        "SELECT location FROM game_item_locations
+         WHERE game_id = ?1 AND item_name_key = ?2 AND location = ?3"
+    )
+    .bind(&game_id)
+    .bind(&item_name_key)
+    .bind(&location)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    if exists.is_none() {
+        return Err(format!("{} is not found at {}", item_name_key, location));
+    }
+
+    let change = GameEditChange::ItemLocation { item_name_key, before: Some(location), after: None };
+    commit_edit(&state.pool, &game_id, change, author).await
+}
+
+// ── Move overrides ───────────────────────────────────────────────────
+
+/// Set a game's override of a move (adds or replaces).
+#[tauri::command]
+pub async fn set_game_move_override(
+    state: State<'_, AppState>,
+    game_id: String,
+    move_override: MoveOverrideEntry,
+    author: Option<String>,
+) -> Result<(), String> {
+    let before = current_move_override(&state.pool, &game_id, &move_override.name_key).await?;
+
+    let change = GameEditChange::MoveOverride {
+        move_name_key: move_override.name_key.clone(),
+        before,
+        after: Some(move_override),
+    };
+    commit_edit(&state.pool, &game_id, change, author).await
+}
+
+/// Remove a game's override of a move (the move falls back to inherited stats).
+#[tauri::command]
+pub async fn remove_game_move_override(
+    state: State<'_, AppState>,
+    game_id: String,
+    move_name_key: String,
+    author: Option<String>,
+) -> Result<(), String> {
+    let before = current_move_override(&state.pool, &game_id, &move_name_key)
+        .await?
+        .ok_or_else(|| format!("{} is not overridden", move_name_key))?;
+
+    let change = GameEditChange::MoveOverride { move_name_key, before: Some(before), after: None };
+    commit_edit(&state.pool, &game_id, change, author).await
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────
+
+/// Apply a change and log it (in one transaction) so it's replayed after reimports.
+async fn commit_edit(
+    pool: &SqlitePool,
+    game_id: &str,
+    change: GameEditChange,
+    author: Option<String>,
+) -> Result<(), String> {
+    let exists: Option<String> = sqlx::query_scalar("SELECT id FROM games WHERE id = ?1")
+        .bind(game_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    if exists.is_none() {
+        return Err(format!("Unknown game: {}", game_id));
+    }
+
+    let author = author
+        .filter(|a| !a.trim().is_empty())
+        .unwrap_or_else(|| DEFAULT_AUTHOR.to_string());
+
+    // The data (inherited rows copied included) and the edit log change together, or not at all
+    let mut tx = pool.begin().await.map_err(|e| e.to_string())?;
+    edits::materialize_inherited(&mut tx, game_id, &change)
+        .await
+        .map_err(|e| e.to_string())?;
+    edits::apply_edit(&mut tx, game_id, &change)
+        .await
+        .map_err(|e| e.to_string())?;
+    edits::record_edit(&mut tx, game_id, &change, &author)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    if matches!(change, GameEditChange::PokemonLocation { .. } | GameEditChange::ItemLocation { .. }) {
+        locations::refresh_game_location_counts(&mut tx, game_id)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
+    tx.commit().await.map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Whether the pokemon's effective learnset in the game (own or inherited) has the entry.
+async fn learnset_entry_exists(
+    pool: &SqlitePool,
+    game_id: &str,
+    pokemon_name_key: &str,
+    entry: &LearnsetEntry,
+) -> Result<bool, String> {
+    let chain = resolve_game_chain(pool, game_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let learnset = resolve_learnset(pool, &chain, pokemon_name_key)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(learnset.iter().any(|row| {
+        row.name_key.as_deref() == Some(entry.move_name_key.as_str())
+            && row.learn_method == entry.learn_method
+            && row.level_learned_at == entry.level
+    }))
+}
+
+/// The ability in a slot of the pokemon's effective abilities in the game (own or inherited).
+async fn current_ability(
+    pool: &SqlitePool,
+    game_id: &str,
+    pokemon_name_key: &str,
+    slot: i64,
+) -> Result<Option<AbilityOverrideEntry>, String> {
+    let chain = resolve_game_chain(pool, game_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let abilities = resolve_pokemon_abilities(pool, &chain, pokemon_name_key)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(abilities
+        .into_iter()
+        .find(|a| a.slot == slot)
+        .map(|a| AbilityOverrideEntry {
+            ability_key: a.ability_key,
+            slot: a.slot,
+            is_hidden: a.is_hidden != 0,
+        }))
+}
+
+async fn current_move_override(
+    pool: &SqlitePool,
+    game_id: &str,
+    move_name_key: &str,
+) -> Result<Option<MoveOverrideEntry>, String> {
+    sqlx::query_as(
+        "SELECT move_name_key AS name_key, power, accuracy, type_key, pp, damage_class, effect_en
+         FROM game_move_overrides WHERE game_id = ?1 AND move_name_key = ?2"
+    )
+    .bind(game_id)
+    .bind(move_name_key)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| e.to_string())
+}
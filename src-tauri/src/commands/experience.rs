@@ -0,0 +1,186 @@
+use crate::cache::experience::{self, ExpGainFactors};
+use crate::models::{EvTrainingTarget, ExpGain, ExpGainParams, ExperienceRange};
+use crate::AppState;
+use tauri::State;
+
+/// Stats as named by PokéAPI, with their EV yield column.
+const EV_COLUMNS: &[(&str, &str)] = &[
+    ("hp", "ev_hp"),
+    ("attack", "ev_atk"),
+    ("defense", "ev_def"),
+    ("special-attack", "ev_spa"),
+    ("special-defense", "ev_spd"),
+    ("speed", "ev_spe"),
+];
+
+#[derive(sqlx::FromRow)]
+struct EvTargetRow {
+    pokemon_name_key: String,
+    pokemon_id: Option<i64>,
+    name_en: Option<String>,
+    name_fr: Option<String>,
+    sprite_url: Option<String>,
+    ev_yield: i64,
+    total_ev_yield: i64,
+    location: String,
+}
+
+/// Get the experience a pokemon needs between two levels, from its growth rate.
+#[tauri::command]
+pub async fn get_experience_between_levels(
+    state: State<'_, AppState>,
+    pokemon_name_key: String,
+    from_level: i64,
+    to_level: i64,
+) -> Result<ExperienceRange, String> {
+    if !(1..=100).contains(&from_level) || !(1..=100).contains(&to_level) {
+        return Err("Levels must be between 1 and 100".to_string());
+    }
+
+    let growth_rate: Option<String> = sqlx::query_scalar(
+        "SELECT s.growth_rate FROM pokemon p
+         JOIN species s ON s.species_id = COALESCE(p.species_id, p.id)
+         WHERE p.name_key = ?1"
+    )
+    .bind(&pokemon_name_key)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?
+    .flatten();
+    let growth_rate = growth_rate.ok_or_else(|| format!("No growth rate for: {}", pokemon_name_key))?;
+
+    let experience_at = |level: i64| {
+        let pool = &state.pool;
+        let growth_rate = &growth_rate;
+        async move {
+            sqlx::query_scalar::<_, i64>(
+                "SELECT experience FROM growth_rate_levels WHERE growth_rate = ?1 AND level = ?2"
+            )
+            .bind(growth_rate)
+            .bind(level)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| format!("No experience table for growth rate: {}", growth_rate))
+        }
+    };
+    let from_experience = experience_at(from_level).await?;
+    let to_experience = experience_at(to_level).await?;
+
+    Ok(ExperienceRange {
+        growth_rate: growth_rate.clone(),
+        from_level,
+        to_level,
+        from_experience,
+        to_experience,
+        needed: (to_experience - from_experience).max(0),
+    })
+}
+
+/// Calculate the experience gained from defeating a pokemon (flat formula in Gen 1-4
+/// and Gen 6, scaled formula in Gen 5 and from Gen 7).
+#[tauri::command]
+pub async fn calculate_exp_gain(
+    state: State<'_, AppState>,
+    params: ExpGainParams,
+) -> Result<ExpGain, String> {
+    if !(1..=100).contains(&params.defeated_level) || !(1..=100).contains(&params.winner_level) {
+        return Err("Levels must be between 1 and 100".to_string());
+    }
+    if params.participants.is_some_and(|p| p < 1) {
+        return Err("At least one pokemon must take part in the battle".to_string());
+    }
+
+    let base_experience: Option<i64> = sqlx::query_scalar(
+        "SELECT base_experience FROM pokemon WHERE name_key = ?1"
+    )
+    .bind(&params.defeated_pokemon)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?
+    .flatten();
+    let base_experience =
+        base_experience.ok_or_else(|| format!("No base experience for: {}", params.defeated_pokemon))?;
+
+    let factors = ExpGainFactors {
+        trainer_battle: params.trainer_battle,
+        participants: params.participants.unwrap_or(1),
+        lucky_egg: params.lucky_egg,
+        traded: params.traded,
+    };
+
+    let (formula, exp) = if params.generation <= 4 || params.generation == 6 {
+        (
+            "flat",
+            experience::exp_gain_flat(base_experience, params.defeated_level, params.generation, factors),
+        )
+    } else {
+        (
+            "scaled",
+            experience::exp_gain_scaled(
+                base_experience,
+                params.defeated_level,
+                params.winner_level,
+                params.generation,
+                factors,
+            ),
+        )
+    };
+
+    Ok(ExpGain {
+        base_experience,
+        formula: formula.to_string(),
+        experience: exp,
+    })
+}
+
+/// Get the pokemon of a game's encounter locations yielding EVs in a stat, best first
+/// (highest yield, then fewest EVs in other stats).
+#[tauri::command]
+pub async fn get_ev_training_targets(
+    state: State<'_, AppState>,
+    game_id: String,
+    stat: String,
+) -> Result<Vec<EvTrainingTarget>, String> {
+    let column = EV_COLUMNS
+        .iter()
+        .find(|(name, _)| *name == stat)
+        .map(|(_, column)| *column)
+        .ok_or_else(|| format!("Unknown stat: {}", stat))?;
+
+    let sql = format!(
+        "SELECT gpl.pokemon_name_key, p.id AS pokemon_id, p.name_en, p.name_fr, p.sprite_url,
+                p.{column} AS ev_yield,
+                p.ev_hp + p.ev_atk + p.ev_def + p.ev_spa + p.ev_spd + p.ev_spe AS total_ev_yield,
+                gpl.location
+         FROM game_pokemon_locations gpl
+         JOIN pokemon p ON p.name_key = gpl.pokemon_name_key
+         WHERE gpl.game_id = ?1 AND p.{column} > 0
+         ORDER BY ev_yield DESC, total_ev_yield ASC, gpl.pokemon_name_key, gpl.location",
+        column = column
+    );
+    let rows: Vec<EvTargetRow> = sqlx::query_as(&sql)
+        .bind(&game_id)
+        .fetch_all(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut targets: Vec<EvTrainingTarget> = Vec::new();
+    for row in rows {
+        match targets.iter_mut().find(|t| t.pokemon_name_key == row.pokemon_name_key) {
+            Some(target) => target.locations.push(row.location),
+            None => targets.push(EvTrainingTarget {
+                pokemon_name_key: row.pokemon_name_key,
+                pokemon_id: row.pokemon_id,
+                name_en: row.name_en,
+                name_fr: row.name_fr,
+                sprite_url: row.sprite_url,
+                ev_yield: row.ev_yield,
+                total_ev_yield: row.total_ev_yield,
+                locations: vec![row.location],
+            }),
+        }
+    }
+
+    Ok(targets)
+}
@@ -1,16 +1,26 @@
-use crate::models::games::{GameDataFile, GameMoveOverride, GameSummary};
+use crate::cache::games::{ORIGIN_BUNDLED, ORIGIN_IMPORTED, ORIGIN_USER};
+use crate::cache::inheritance::{
+    load_move_overrides, resolve_game_chain, resolve_pokemon_abilities, resolve_pokemon_moves,
+};
+use crate::models::games::{
+    GameDataFile, GameLocation, GameMachine, GameMoveOverride, GameSources, GameSummary, LocationContents,
+    LocationItemEntry, LocationPokemonEntry, MechanicsProfile,
+};
 use crate::models::{PokemonAbility, PokemonMoveEntry};
+use crate::game_files;
 use crate::AppState;
+use sqlx::SqlitePool;
 use tauri::State;
 
-/// Get all registered games (hackroms + officials), sorted by sort_order.
+/// Get all registered games (hackroms + officials), sorted by sort_order. Unsupported
+/// version groups and user games whose file left the games folder are left out.
 #[tauri::command]
 pub async fn get_all_games(
     state: State<'_, AppState>,
 ) -> Result<Vec<GameSummary>, String> {
     let rows: Vec<GameSummary> = sqlx::query_as(
-        "SELECT id, name_en, name_fr, base_rom, version, author, is_hackrom, sort_order, coverage
-         FROM games ORDER BY sort_order, name_en"
+        "SELECT id, name_en, name_fr, base_rom, version, author, is_hackrom, sort_order, coverage, extends
+         FROM games WHERE hidden = 0 AND orphaned = 0 ORDER BY sort_order, name_en"
     )
     .fetch_all(&state.pool)
     .await
@@ -37,73 +47,57 @@ pub async fn get_game_coverage(
 }
 
 /// Get pokemon moves for a specific game, joined with move details.
-/// Returns the same PokemonMoveEntry format as get_pokemon_moves, with
-/// COALESCE for move overrides.
+/// Returns the same PokemonMoveEntry format as get_pokemon_moves.
+/// The learnset comes from the nearest layer of the game's inheritance chain that
+/// lists this pokemon (falling back to the global table), and move overrides are
+/// merged across the whole chain. Damage classes follow the game's physical/special
+/// split (Shadow Ball is physical in a vanilla Gen 3 game).
 #[tauri::command]
 pub async fn get_game_pokemon_moves(
     state: State<'_, AppState>,
     game_id: String,
     pokemon_name_key: String,
 ) -> Result<Vec<PokemonMoveEntry>, String> {
-    let rows: Vec<PokemonMoveEntry> = sqlx::query_as(
-        "SELECT
-           0 AS pokemon_id,
-           COALESCE(m.id, 0) AS move_id,
-           gpm.learn_method,
-           gpm.level_learned_at,
-           gpm.move_name_key AS name_key,
-           m.name_en,
-           m.name_fr,
-           COALESCE(gmo.type_key, m.type_key) AS type_key,
-           COALESCE(gmo.damage_class, m.damage_class) AS damage_class,
-           COALESCE(gmo.power, m.power) AS power,
-           COALESCE(gmo.accuracy, m.accuracy) AS accuracy,
-           COALESCE(gmo.pp, m.pp) AS pp
-         FROM game_pokemon_moves gpm
-         LEFT JOIN moves m ON m.name_key = gpm.move_name_key
-         LEFT JOIN game_move_overrides gmo ON gmo.game_id = gpm.game_id AND gmo.move_name_key = gpm.move_name_key
-         WHERE gpm.game_id = ?1 AND gpm.pokemon_name_key = ?2
-         ORDER BY gpm.learn_method, gpm.level_learned_at, gpm.move_name_key"
-    )
-    .bind(&game_id)
-    .bind(&pokemon_name_key)
-    .fetch_all(&state.pool)
-    .await
-    .map_err(|e| e.to_string())?;
+    let chain = resolve_game_chain(&state.pool, &game_id)
+        .await
+        .map_err(|e| e.to_string())?;
 
-    Ok(rows)
+    resolve_pokemon_moves(&state.pool, &chain, &pokemon_name_key)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get the effective battle mechanics of a game (inherited, then generation defaults).
+#[tauri::command]
+pub async fn get_game_mechanics(
+    state: State<'_, AppState>,
+    game_id: String,
+) -> Result<MechanicsProfile, String> {
+    let chain = resolve_game_chain(&state.pool, &game_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    crate::cache::mechanics::resolve_game_mechanics(&state.pool, &chain)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 /// Get pokemon abilities for a specific game, joined with ability details.
+/// Abilities come from the nearest layer of the inheritance chain that lists this
+/// pokemon, falling back to the global table.
 #[tauri::command]
 pub async fn get_game_pokemon_abilities(
     state: State<'_, AppState>,
     game_id: String,
     pokemon_name_key: String,
 ) -> Result<Vec<PokemonAbility>, String> {
-    let rows: Vec<PokemonAbility> = sqlx::query_as(
-        "SELECT
-           0 AS pokemon_id,
-           a.id AS ability_id,
-           gpa.ability_key,
-           a.name_en AS ability_en,
-           a.name_fr AS ability_fr,
-           a.short_effect_en,
-           a.short_effect_fr,
-           gpa.is_hidden,
-           gpa.slot
-         FROM game_pokemon_abilities gpa
-         LEFT JOIN abilities a ON a.name_key = gpa.ability_key
-         WHERE gpa.game_id = ?1 AND gpa.pokemon_name_key = ?2
-         ORDER BY gpa.slot"
-    )
-    .bind(&game_id)
-    .bind(&pokemon_name_key)
-    .fetch_all(&state.pool)
-    .await
-    .map_err(|e| e.to_string())?;
+    let chain = resolve_game_chain(&state.pool, &game_id)
+        .await
+        .map_err(|e| e.to_string())?;
 
-    Ok(rows)
+    resolve_pokemon_abilities(&state.pool, &chain, &pokemon_name_key)
+        .await
+        .map_err(|e| e.to_string())
 }
 
 /// Get pokemon locations for a specific game.
@@ -127,25 +121,27 @@ pub async fn get_game_pokemon_locations(
     Ok(rows)
 }
 
-/// Get move overrides for a specific game and move.
+/// Get move overrides for a specific game and move, merged across the game's
+/// inheritance chain (nearest layer wins per field).
+/// Returns None when no layer changes the move (the global move applies).
 #[tauri::command]
 pub async fn get_game_move_override(
     state: State<'_, AppState>,
     game_id: String,
     move_name_key: String,
 ) -> Result<Option<GameMoveOverride>, String> {
-    let row: Option<GameMoveOverride> = sqlx::query_as(
-        "SELECT game_id, move_name_key, power, accuracy, type_key, pp, damage_class, effect_en
-         FROM game_move_overrides
-         WHERE game_id = ?1 AND move_name_key = ?2"
-    )
-    .bind(&game_id)
-    .bind(&move_name_key)
-    .fetch_optional(&state.pool)
-    .await
-    .map_err(|e| e.to_string())?;
+    let chain = resolve_game_chain(&state.pool, &game_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut overrides = load_move_overrides(&state.pool, &chain)
+        .await
+        .map_err(|e| e.to_string())?;
 
-    Ok(row)
+    Ok(overrides.remove(&move_name_key).map(|mut ov| {
+        ov.game_id = game_id;
+        ov
+    }))
 }
 
 /// Get item locations for a specific game and item.
@@ -169,6 +165,120 @@ pub async fn get_game_item_locations(
     Ok(rows)
 }
 
+/// Get the TM/HM/TR list of a game with the move each machine teaches. The list comes
+/// from the nearest layer of the inheritance chain that has one (a hack's own list, or
+/// its version group's); locations come from the game's item locations.
+#[tauri::command]
+pub async fn get_game_machines(
+    state: State<'_, AppState>,
+    game_id: String,
+) -> Result<Vec<GameMachine>, String> {
+    let chain = resolve_game_chain(&state.pool, &game_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    crate::cache::machines::resolve_machines(&state.pool, &chain)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get all normalized locations of a game, with pokemon/item counts.
+#[tauri::command]
+pub async fn get_game_locations(
+    state: State<'_, AppState>,
+    game_id: String,
+) -> Result<Vec<GameLocation>, String> {
+    let rows: Vec<GameLocation> = sqlx::query_as(
+        "SELECT game_id, location_key, name, pokemon_count, item_count
+         FROM game_locations
+         WHERE game_id = ?1
+         ORDER BY name"
+    )
+    .bind(&game_id)
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(rows)
+}
+
+/// Get every pokemon and item found at a location.
+/// `location` may be a location key or any spelling of the location name.
+#[tauri::command]
+pub async fn get_location_contents(
+    state: State<'_, AppState>,
+    game_id: String,
+    location: String,
+) -> Result<Option<LocationContents>, String> {
+    let location_key = match crate::cache::locations::normalize_location(&location) {
+        Some(loc) => loc.key,
+        None => return Ok(None),
+    };
+
+    let game_location: Option<GameLocation> = sqlx::query_as(
+        "SELECT game_id, location_key, name, pokemon_count, item_count
+         FROM game_locations
+         WHERE game_id = ?1 AND location_key = ?2"
+    )
+    .bind(&game_id)
+    .bind(&location_key)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let game_location = match game_location {
+        Some(l) => l,
+        None => return Ok(None),
+    };
+
+    let pokemon: Vec<LocationPokemonEntry> = sqlx::query_as(
+        "SELECT
+           gpl.pokemon_name_key,
+           p.id AS pokemon_id,
+           p.name_en,
+           p.name_fr,
+           p.type1_key,
+           p.type2_key,
+           p.sprite_url,
+           gpl.location AS raw_location
+         FROM game_pokemon_locations gpl
+         LEFT JOIN pokemon p ON p.name_key = gpl.pokemon_name_key
+         WHERE gpl.game_id = ?1 AND gpl.location_key = ?2
+         ORDER BY COALESCE(p.id, 99999), gpl.pokemon_name_key, gpl.location"
+    )
+    .bind(&game_id)
+    .bind(&location_key)
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let items: Vec<LocationItemEntry> = sqlx::query_as(
+        "SELECT
+           gil.item_name_key,
+           i.id AS item_id,
+           i.name_en,
+           i.name_fr,
+           i.category,
+           i.sprite_url,
+           gil.location AS raw_location
+         FROM game_item_locations gil
+         LEFT JOIN items i ON i.name_key = gil.item_name_key
+         WHERE gil.game_id = ?1 AND gil.location_key = ?2
+         ORDER BY gil.item_name_key, gil.location"
+    )
+    .bind(&game_id)
+    .bind(&location_key)
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(Some(LocationContents {
+        location: game_location,
+        pokemon,
+        items,
+    }))
+}
+
 /// Import game data from a JSON string.
 #[tauri::command]
 pub async fn import_game_data(
@@ -177,11 +287,150 @@ pub async fn import_game_data(
 ) -> Result<String, String> {
     let data: GameDataFile = serde_json::from_str(&json_data)
         .map_err(|e| format!("Failed to parse game JSON: {}", e))?;
+    crate::cache::games::validate_game_data(&data)?;
 
     let game_id = crate::cache::games::import_game_data(&state.pool, &data)
         .await
         .map_err(|e| format!("Failed to import game data: {}", e))?;
 
+    let hash = crate::cache::games::content_hash(&json_data);
+    crate::cache::games::record_game_import(&state.pool, &game_id, &hash, ORIGIN_IMPORTED, None)
+        .await
+        .map_err(|e| e.to_string())?;
+
     log::info!("Imported game data for: {}", game_id);
     Ok(game_id)
 }
+
+/// Reimport a bundled or user-folder game from its data file, even if it didn't change.
+#[tauri::command]
+pub async fn force_reimport_game(
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    game_id: String,
+) -> Result<String, String> {
+    let bundled = crate::BUNDLED_GAMES.iter().find_map(|json| {
+        let data: GameDataFile = serde_json::from_str(json).ok()?;
+        (data.game.id == game_id).then(|| (data, crate::cache::games::content_hash(json)))
+    });
+
+    let (data, hash, origin, file_name) = match bundled {
+        Some((data, hash)) => (data, hash, ORIGIN_BUNDLED, None),
+        None => {
+            let dir = game_files::user_games_dir(&app_handle)?;
+            game_files::read_user_game_files(&dir)?
+                .into_iter()
+                .find_map(|file| match file.data {
+                    Ok(data) if data.game.id == game_id => {
+                        Some((data, file.hash, ORIGIN_USER, Some(file.file_name)))
+                    }
+                    _ => None,
+                })
+                .ok_or_else(|| format!("No data file for game: {}", game_id))?
+        }
+    };
+
+    crate::cache::games::import_game_data(&state.pool, &data)
+        .await
+        .map_err(|e| format!("Failed to import game data: {}", e))?;
+
+    crate::cache::games::record_game_import(&state.pool, &game_id, &hash, origin, file_name.as_deref())
+        .await
+        .map_err(|e| e.to_string())?;
+
+    log::info!("Force-reimported game data for: {}", game_id);
+    Ok(game_id)
+}
+
+/// List where game data comes from (bundled, user games folder, manual imports).
+#[tauri::command]
+pub async fn list_game_sources(
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+) -> Result<GameSources, String> {
+    let dir = game_files::user_games_dir(&app_handle)?;
+    let sources = game_files::list_game_sources(&state.pool, &dir).await?;
+
+    Ok(GameSources {
+        user_games_dir: dir.to_string_lossy().to_string(),
+        sources,
+    })
+}
+
+/// Delete a game and all of its data (runs and edits included). A game from the user
+/// games folder also loses its file; a bundled game comes back on next start.
+/// Official games can't be deleted.
+#[tauri::command]
+pub async fn delete_game(
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    game_id: String,
+) -> Result<(), String> {
+    delete_game_and_file(&app_handle, &state.pool, &game_id, true).await
+}
+
+/// Remove a user-supplied game: its data, and its file in the user games folder.
+/// Bundled and official games can't be removed.
+#[tauri::command]
+pub async fn remove_game(
+    app_handle: tauri::AppHandle,
+    state: State<'_, AppState>,
+    game_id: String,
+) -> Result<(), String> {
+    delete_game_and_file(&app_handle, &state.pool, &game_id, false).await
+}
+
+async fn delete_game_and_file(
+    app_handle: &tauri::AppHandle,
+    pool: &SqlitePool,
+    game_id: &str,
+    allow_bundled: bool,
+) -> Result<(), String> {
+    let row: Option<(Option<String>, Option<String>, i64)> = sqlx::query_as(
+        "SELECT origin, origin_file, is_hackrom FROM games WHERE id = ?1"
+    )
+    .bind(game_id)
+    .fetch_optional(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let (origin, origin_file, is_hackrom) = row.ok_or_else(|| format!("Unknown game: {}", game_id))?;
+    match origin.as_deref() {
+        Some(ORIGIN_USER) => {
+            if let Some(file_name) = origin_file {
+                let path = game_files::user_games_dir(app_handle)?.join(file_name);
+                if path.exists() {
+                    std::fs::remove_file(&path)
+                        .map_err(|e| format!("Failed to delete {}: {}", path.display(), e))?;
+                }
+            }
+        }
+        Some(ORIGIN_IMPORTED) => {}
+        // Hackroms imported before origins were recorded
+        None if is_hackrom != 0 => {}
+        Some(ORIGIN_BUNDLED) if allow_bundled => {}
+        Some(ORIGIN_BUNDLED) => return Err(format!("Bundled games can't be removed: {}", game_id)),
+        _ => return Err(format!("Official games can't be deleted: {}", game_id)),
+    }
+
+    crate::cache::games::delete_game(pool, game_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    log::info!("Deleted game: {}", game_id);
+    Ok(())
+}
+
+/// Export a game's data as a JSON string in the game data file format.
+#[tauri::command]
+pub async fn export_game_data(
+    state: State<'_, AppState>,
+    game_id: String,
+) -> Result<String, String> {
+    let data = crate::cache::games::export_game_data(&state.pool, &game_id)
+        .await
+        .map_err(|e| format!("Failed to export game data: {}", e))?
+        .ok_or_else(|| format!("Unknown game: {}", game_id))?;
+
+    serde_json::to_string_pretty(&data).map_err(|e| e.to_string())
+}
@@ -1,13 +1,15 @@
+use crate::cache::localization::fill_localized;
 use crate::models::{ItemDetail, ItemSummary};
 use crate::AppState;
 use tauri::State;
 
-/// Get all items (ordered by ID).
+/// Get all items (ordered by ID), with texts in `lang` if given.
 #[tauri::command]
 pub async fn get_all_items(
     state: State<'_, AppState>,
+    lang: Option<String>,
 ) -> Result<Vec<ItemSummary>, String> {
-    let rows: Vec<ItemSummary> = sqlx::query_as(
+    let mut rows: Vec<ItemSummary> = sqlx::query_as(
         "SELECT id, name_key, name_en, name_fr, category, effect_en, effect_fr, sprite_url
          FROM items ORDER BY id",
     )
@@ -15,16 +17,24 @@ pub async fn get_all_items(
     .await
     .map_err(|e| e.to_string())?;
 
+    fill_localized(&state.pool, "item", "name", lang.as_deref(), &mut rows, |x| (x.id, &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+    fill_localized(&state.pool, "item", "effect", lang.as_deref(), &mut rows, |x| (x.id, &mut x.effect_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
     Ok(rows)
 }
 
-/// Get a single item by ID (full data).
+/// Get a single item by ID (full data), with texts in `lang` if given.
 #[tauri::command]
 pub async fn get_item_by_id(
     state: State<'_, AppState>,
     id: i64,
+    lang: Option<String>,
 ) -> Result<Option<ItemDetail>, String> {
-    let row: Option<ItemDetail> = sqlx::query_as(
+    let mut row: Option<ItemDetail> = sqlx::query_as(
         "SELECT id, name_key, name_en, name_fr, category, effect_en, effect_fr, sprite_url
          FROM items WHERE id = ?1",
     )
@@ -33,23 +43,33 @@ pub async fn get_item_by_id(
     .await
     .map_err(|e| e.to_string())?;
 
+    fill_localized(&state.pool, "item", "name", lang.as_deref(), row.as_mut_slice(), |x| (x.id, &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+    fill_localized(&state.pool, "item", "effect", lang.as_deref(), row.as_mut_slice(), |x| (x.id, &mut x.effect_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
     Ok(row)
 }
 
-/// Search items by name (supports partial matching).
+/// Search items by name in any synced language (supports partial matching).
 #[tauri::command]
 pub async fn search_items(
     state: State<'_, AppState>,
     query: String,
+    lang: Option<String>,
 ) -> Result<Vec<ItemSummary>, String> {
     let pattern = format!("%{}%", query.to_lowercase());
 
-    let rows: Vec<ItemSummary> = sqlx::query_as(
+    let mut rows: Vec<ItemSummary> = sqlx::query_as(
         "SELECT id, name_key, name_en, name_fr, category, effect_en, effect_fr, sprite_url
          FROM items
          WHERE LOWER(name_key) LIKE ?1
             OR LOWER(name_en) LIKE ?1
             OR LOWER(name_fr) LIKE ?1
+            OR id IN (SELECT entity_id FROM localized_text
+                      WHERE entity = 'item' AND field = 'name' AND LOWER(text) LIKE ?1)
          ORDER BY id
          LIMIT 50",
     )
@@ -58,5 +78,12 @@ pub async fn search_items(
     .await
     .map_err(|e| e.to_string())?;
 
+    fill_localized(&state.pool, "item", "name", lang.as_deref(), &mut rows, |x| (x.id, &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+    fill_localized(&state.pool, "item", "effect", lang.as_deref(), &mut rows, |x| (x.id, &mut x.effect_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
     Ok(rows)
 }
@@ -8,3 +8,11 @@ pub mod favorites;
 pub mod natures;
 pub mod abilities;
 pub mod games;
+pub mod trainers;
+pub mod runs;
+pub mod diff;
+pub mod edits;
+pub mod species;
+pub mod breeding;
+pub mod experience;
+pub mod catching;
@@ -1,13 +1,17 @@
-use crate::models::{MoveDetail, MovePokemonEntry, MoveSummary, PokemonMoveEntry};
+use crate::cache::inheritance::{load_move_overrides, resolve_game_chain};
+use crate::cache::localization::fill_localized;
+use crate::cache::mechanics;
+use crate::models::{MoveDetail, MoveFilter, MovePokemonEntry, MoveStatChange, MoveSummary, PokemonMoveEntry};
 use crate::AppState;
 use tauri::State;
 
-/// Get all moves (summary list, ordered by ID).
+/// Get all moves (summary list, ordered by ID), with names in `lang` if given.
 #[tauri::command]
 pub async fn get_all_moves(
     state: State<'_, AppState>,
+    lang: Option<String>,
 ) -> Result<Vec<MoveSummary>, String> {
-    let rows: Vec<MoveSummary> = sqlx::query_as(
+    let mut rows: Vec<MoveSummary> = sqlx::query_as(
         "SELECT id, name_key, name_en, name_fr, type_key, damage_class, power, accuracy, pp
          FROM moves ORDER BY id"
     )
@@ -15,18 +19,28 @@ pub async fn get_all_moves(
     .await
     .map_err(|e| e.to_string())?;
 
+    fill_localized(&state.pool, "move", "name", lang.as_deref(), &mut rows, |m| (m.id, &mut m.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
     Ok(rows)
 }
 
 /// Get a single move by ID with full detail.
+/// With a game, the values are the ones that applied in that game (overrides, past values
+/// of official version groups, physical/special split). Texts are also given in `lang` if set.
 #[tauri::command]
 pub async fn get_move_by_id(
     state: State<'_, AppState>,
     id: i64,
+    game_id: Option<String>,
+    lang: Option<String>,
 ) -> Result<Option<MoveDetail>, String> {
     let row: Option<MoveDetail> = sqlx::query_as(
         "SELECT id, name_key, name_en, name_fr, type_key, damage_class,
-                power, accuracy, pp, priority, effect_en, effect_fr
+                power, accuracy, pp, priority, effect_en, effect_fr,
+                effect_chance, target, crit_rate, drain, healing, min_hits, max_hits,
+                ailment, ailment_chance, flinch_chance, stat_chance, generation
          FROM moves WHERE id = ?1"
     )
     .bind(id)
@@ -34,23 +48,148 @@ pub async fn get_move_by_id(
     .await
     .map_err(|e| e.to_string())?;
 
-    Ok(row)
+    let row = match row {
+        Some(mut detail) => {
+            detail.stat_changes = sqlx::query_as::<_, MoveStatChange>(
+                "SELECT stat, change FROM move_stat_changes WHERE move_id = ?1 ORDER BY stat"
+            )
+            .bind(id)
+            .fetch_all(&state.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+            detail.flags = sqlx::query_scalar(
+                "SELECT flag FROM move_flags WHERE move_id = ?1 ORDER BY flag"
+            )
+            .bind(id)
+            .fetch_all(&state.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+
+            let lang = lang.as_deref();
+            let details = std::slice::from_mut(&mut detail);
+            fill_localized(&state.pool, "move", "name", lang, details, |m| (m.id, &mut m.name_localized))
+                .await
+                .map_err(|e| e.to_string())?;
+            fill_localized(&state.pool, "move", "effect", lang, details, |m| (m.id, &mut m.effect_localized))
+                .await
+                .map_err(|e| e.to_string())?;
+            Some(detail)
+        }
+        None => None,
+    };
+
+    let (mut detail, game_id) = match (row, game_id) {
+        (Some(detail), Some(game_id)) => (detail, game_id),
+        (row, _) => return Ok(row),
+    };
+
+    let chain = resolve_game_chain(&state.pool, &game_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let overrides = load_move_overrides(&state.pool, &chain)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut class_overridden = false;
+    if let Some(ov) = overrides.get(&detail.name_key) {
+        detail.type_key = ov.type_key.clone().or(detail.type_key);
+        detail.power = ov.power.or(detail.power);
+        detail.accuracy = ov.accuracy.or(detail.accuracy);
+        detail.pp = ov.pp.or(detail.pp);
+        detail.effect_en = ov.effect_en.clone().or(detail.effect_en);
+        class_overridden = ov.damage_class.is_some();
+        detail.damage_class = ov.damage_class.clone().or(detail.damage_class);
+    }
+
+    let profile = mechanics::resolve_game_mechanics(&state.pool, &chain)
+        .await
+        .map_err(|e| e.to_string())?;
+    let is_status = detail.damage_class.as_deref() == Some("status");
+    if profile.damage_split == mechanics::SPLIT_BY_TYPE && !class_overridden && !is_status {
+        if let Some(type_key) = detail.type_key.as_deref() {
+            detail.damage_class = Some(mechanics::type_split_damage_class(type_key).to_string());
+        }
+    }
+
+    Ok(Some(detail))
 }
 
-/// Search moves by name (supports partial matching).
+/// Query moves by metadata, e.g. all contact moves with priority above 0.
+#[tauri::command]
+pub async fn filter_moves(
+    state: State<'_, AppState>,
+    filter: MoveFilter,
+    lang: Option<String>,
+) -> Result<Vec<MoveSummary>, String> {
+    let pattern = filter.query.map(|q| format!("%{}%", q.to_lowercase()));
+    let flags = serde_json::to_string(&filter.flags).map_err(|e| e.to_string())?;
+
+    let mut rows: Vec<MoveSummary> = sqlx::query_as(
+        "SELECT m.id, m.name_key, m.name_en, m.name_fr, m.type_key, m.damage_class,
+                m.power, m.accuracy, m.pp
+         FROM moves m
+         WHERE (?1 IS NULL OR LOWER(m.name_key) LIKE ?1 OR LOWER(m.name_en) LIKE ?1
+                OR LOWER(m.name_fr) LIKE ?1
+                OR m.id IN (SELECT entity_id FROM localized_text
+                            WHERE entity = 'move' AND field = 'name' AND LOWER(text) LIKE ?1))
+           AND (?2 IS NULL OR m.type_key = ?2)
+           AND (?3 IS NULL OR m.damage_class = ?3)
+           AND (?4 IS NULL OR m.target = ?4)
+           AND (?5 IS NULL OR m.ailment = ?5)
+           AND (SELECT COUNT(*) FROM move_flags mf
+                WHERE mf.move_id = m.id AND mf.flag IN (SELECT value FROM json_each(?6)))
+               = json_array_length(?6)
+           AND (?7 IS NULL OR EXISTS (SELECT 1 FROM move_stat_changes msc
+                                      WHERE msc.move_id = m.id AND msc.stat = ?7))
+           AND (?8 IS NULL OR m.priority >= ?8)
+           AND (?9 IS NULL OR m.priority <= ?9)
+           AND (?10 IS NULL OR m.power >= ?10)
+           AND (?11 IS NULL OR m.power <= ?11)
+           AND (?12 IS NULL OR m.generation = ?12)
+           AND (?13 IS NULL OR (COALESCE(m.max_hits, 1) > 1) = ?13)
+         ORDER BY m.id"
+    )
+    .bind(&pattern)
+    .bind(&filter.type_key)
+    .bind(&filter.damage_class)
+    .bind(&filter.target)
+    .bind(&filter.ailment)
+    .bind(&flags)
+    .bind(&filter.stat)
+    .bind(filter.min_priority)
+    .bind(filter.max_priority)
+    .bind(filter.min_power)
+    .bind(filter.max_power)
+    .bind(filter.generation)
+    .bind(filter.multi_hit)
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    fill_localized(&state.pool, "move", "name", lang.as_deref(), &mut rows, |x| (x.id, &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(rows)
+}
+
+/// Search moves by name in any synced language (supports partial matching).
 #[tauri::command]
 pub async fn search_moves(
     state: State<'_, AppState>,
     query: String,
+    lang: Option<String>,
 ) -> Result<Vec<MoveSummary>, String> {
     let pattern = format!("%{}%", query.to_lowercase());
 
-    let rows: Vec<MoveSummary> = sqlx::query_as(
+    let mut rows: Vec<MoveSummary> = sqlx::query_as(
         "SELECT id, name_key, name_en, name_fr, type_key, damage_class, power, accuracy, pp
          FROM moves
          WHERE LOWER(name_key) LIKE ?1
             OR LOWER(name_en) LIKE ?1
             OR LOWER(name_fr) LIKE ?1
+            OR id IN (SELECT entity_id FROM localized_text
+                      WHERE entity = 'move' AND field = 'name' AND LOWER(text) LIKE ?1)
          ORDER BY id
          LIMIT 50"
     )
@@ -59,6 +198,10 @@ pub async fn search_moves(
     .await
     .map_err(|e| e.to_string())?;
 
+    fill_localized(&state.pool, "move", "name", lang.as_deref(), &mut rows, |x| (x.id, &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
     Ok(rows)
 }
 
@@ -1,3 +1,4 @@
+use crate::cache::localization::fill_localized;
 use crate::models::NatureSummary;
 use crate::AppState;
 use tauri::State;
@@ -5,8 +6,9 @@ use tauri::State;
 #[tauri::command]
 pub async fn get_all_natures(
     state: State<'_, AppState>,
+    lang: Option<String>,
 ) -> Result<Vec<NatureSummary>, String> {
-    let rows: Vec<NatureSummary> = sqlx::query_as(
+    let mut rows: Vec<NatureSummary> = sqlx::query_as(
         "SELECT id, name_key, name_en, name_fr, increased_stat, decreased_stat, likes_flavor, hates_flavor
          FROM natures ORDER BY id",
     )
@@ -14,5 +16,9 @@ pub async fn get_all_natures(
     .await
     .map_err(|e| e.to_string())?;
 
+    fill_localized(&state.pool, "nature", "name", lang.as_deref(), &mut rows, |x| (x.id, &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
     Ok(rows)
 }
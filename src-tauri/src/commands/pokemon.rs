@@ -1,33 +1,58 @@
-use crate::models::{PokemonAbility, PokemonDetail, PokemonSummary};
+use crate::cache::localization::fill_localized;
+use crate::models::{
+    EvolutionEntry, EvolutionFilter, FormFilter, PokemonAbility, PokemonDetail, PokemonSprites,
+    PokemonSummary,
+};
 use crate::AppState;
 use tauri::State;
 
-/// Get all pokemon (summary list, ordered by ID).
+/// Get all pokemon (summary list, ordered by ID), optionally filtered by form
+/// (e.g. only regional variants, or no megas). Names are also given in `lang` if set.
 #[tauri::command]
 pub async fn get_all_pokemon(
     state: State<'_, AppState>,
+    forms: Option<FormFilter>,
+    lang: Option<String>,
 ) -> Result<Vec<PokemonSummary>, String> {
-    let rows: Vec<PokemonSummary> = sqlx::query_as(
+    let forms = forms.unwrap_or_default();
+    let categories = serde_json::to_string(&forms.categories).map_err(|e| e.to_string())?;
+
+    let mut rows: Vec<PokemonSummary> = sqlx::query_as(
         "SELECT id, name_key, name_en, name_fr, type1_key, type2_key, hp, atk, def, spa, spd, spe, base_stat_total, sprite_url, species_id
-         FROM pokemon ORDER BY id"
+         FROM pokemon p
+         WHERE (json_array_length(?1) = 0
+                OR EXISTS (SELECT 1 FROM pokemon_forms f
+                           WHERE f.pokemon_id = p.id AND f.is_default = 1
+                             AND f.category IN (SELECT value FROM json_each(?1))))
+           AND (?2 IS NULL OR EXISTS (SELECT 1 FROM pokemon_forms f
+                                      WHERE f.pokemon_id = p.id AND f.regional_tag = ?2))
+         ORDER BY id"
     )
+    .bind(&categories)
+    .bind(&forms.regional_tag)
     .fetch_all(&state.pool)
     .await
     .map_err(|e| e.to_string())?;
 
+    fill_localized(&state.pool, "species", "name", lang.as_deref(), &mut rows, |x| (x.species_id.unwrap_or(x.id), &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
     Ok(rows)
 }
 
-/// Get a single pokemon by ID with full detail.
+/// Get a single pokemon by ID with full detail, with texts in `lang` if given.
 #[tauri::command]
 pub async fn get_pokemon_by_id(
     state: State<'_, AppState>,
     id: i64,
+    lang: Option<String>,
 ) -> Result<Option<PokemonDetail>, String> {
-    let row: Option<PokemonDetail> = sqlx::query_as(
+    let mut row: Option<PokemonDetail> = sqlx::query_as(
         "SELECT id, name_key, name_en, name_fr, type1_key, type2_key,
                 hp, atk, def, spa, spd, spe, base_stat_total, sprite_url,
-                evolution_chain_id, description_en, description_fr, height, weight, species_id
+                evolution_chain_id, description_en, description_fr, height, weight, species_id,
+                base_experience, ev_hp, ev_atk, ev_def, ev_spa, ev_spd, ev_spe
          FROM pokemon WHERE id = ?1"
     )
     .bind(id)
@@ -35,31 +60,57 @@ pub async fn get_pokemon_by_id(
     .await
     .map_err(|e| e.to_string())?;
 
+    fill_localized(&state.pool, "species", "name", lang.as_deref(), row.as_mut_slice(), |x| (x.species_id.unwrap_or(x.id), &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+    fill_localized(&state.pool, "species", "description", lang.as_deref(), row.as_mut_slice(), |x| (x.species_id.unwrap_or(x.id), &mut x.description_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
     Ok(row)
 }
 
-/// Search pokemon by name (supports partial matching).
+/// Search pokemon by name in any synced language (supports partial matching).
 #[tauri::command]
 pub async fn search_pokemon(
     state: State<'_, AppState>,
     query: String,
+    forms: Option<FormFilter>,
+    lang: Option<String>,
 ) -> Result<Vec<PokemonSummary>, String> {
     let pattern = format!("%{}%", query.to_lowercase());
+    let forms = forms.unwrap_or_default();
+    let categories = serde_json::to_string(&forms.categories).map_err(|e| e.to_string())?;
 
-    let rows: Vec<PokemonSummary> = sqlx::query_as(
+    let mut rows: Vec<PokemonSummary> = sqlx::query_as(
         "SELECT id, name_key, name_en, name_fr, type1_key, type2_key, hp, atk, def, spa, spd, spe, base_stat_total, sprite_url, species_id
-         FROM pokemon
-         WHERE LOWER(name_key) LIKE ?1
-            OR LOWER(name_en) LIKE ?1
-            OR LOWER(name_fr) LIKE ?1
+         FROM pokemon p
+         WHERE (LOWER(name_key) LIKE ?1
+                OR LOWER(name_en) LIKE ?1
+                OR LOWER(name_fr) LIKE ?1
+                OR COALESCE(species_id, id) IN (SELECT entity_id FROM localized_text
+                                                WHERE entity = 'species' AND field = 'name'
+                                                  AND LOWER(text) LIKE ?1))
+           AND (json_array_length(?2) = 0
+                OR EXISTS (SELECT 1 FROM pokemon_forms f
+                           WHERE f.pokemon_id = p.id AND f.is_default = 1
+                             AND f.category IN (SELECT value FROM json_each(?2))))
+           AND (?3 IS NULL OR EXISTS (SELECT 1 FROM pokemon_forms f
+                                      WHERE f.pokemon_id = p.id AND f.regional_tag = ?3))
          ORDER BY id
          LIMIT 50"
     )
     .bind(&pattern)
+    .bind(&categories)
+    .bind(&forms.regional_tag)
     .fetch_all(&state.pool)
     .await
     .map_err(|e| e.to_string())?;
 
+    fill_localized(&state.pool, "species", "name", lang.as_deref(), &mut rows, |x| (x.species_id.unwrap_or(x.id), &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
     Ok(rows)
 }
 
@@ -107,21 +158,110 @@ pub async fn get_pokemon_evolution_chain(
         None => return Ok(None),
     };
 
-    let node = crate::cache::evolution::get_evolution_chain(&state.pool, chain_id)
+    let mut node = crate::cache::evolution::get_evolution_chain(&state.pool, chain_id)
         .await
         .map_err(|e| e.to_string())?;
 
+    if let Some(root) = node.as_mut() {
+        crate::cache::evolution::resolve_evolution_chain(&state.pool, root)
+            .await
+            .map_err(|e| e.to_string())?;
+    }
+
     Ok(node)
 }
 
-/// Get alternate forms (mega, regional, etc.) for all species in a given evolution chain.
-/// Finds all pokemon sharing the same evolution_chain_id but NOT present as nodes in the
-/// evolution chain JSON. This works regardless of whether species_id is populated.
+/// Find evolution steps matching a filter (e.g. everything evolving with a Moon Stone,
+/// or by trade).
+#[tauri::command]
+pub async fn find_evolutions(
+    state: State<'_, AppState>,
+    filter: EvolutionFilter,
+) -> Result<Vec<EvolutionEntry>, String> {
+    let mut rows: Vec<EvolutionEntry> = sqlx::query_as(
+        "SELECT e.chain_id, e.from_species_id,
+                f.name_key AS from_name_key, f.name_en AS from_name_en,
+                f.name_fr AS from_name_fr, f.sprite_url AS from_sprite_url,
+                e.to_species_id,
+                t.name_key AS to_name_key, t.name_en AS to_name_en,
+                t.name_fr AS to_name_fr, t.sprite_url AS to_sprite_url,
+                e.trigger, e.min_level, e.item, e.held_item, e.known_move, e.known_move_type,
+                e.location, e.min_happiness, e.min_affection, e.min_beauty, e.time_of_day,
+                e.gender, e.party_species, e.party_type, e.trade_species,
+                e.relative_physical_stats, e.needs_overworld_rain, e.turn_upside_down
+         FROM pokemon_evolutions e
+         LEFT JOIN pokemon f ON f.id = e.from_species_id
+         LEFT JOIN pokemon t ON t.id = e.to_species_id
+         WHERE (?1 IS NULL OR e.from_species_id = ?1 OR e.to_species_id = ?1)
+           AND (?2 IS NULL OR e.trigger = ?2)
+           AND (?3 IS NULL OR e.item = ?3)
+           AND (?4 IS NULL OR e.held_item = ?4)
+           AND (?5 IS NULL OR e.known_move = ?5)
+           AND (?6 IS NULL OR e.known_move_type = ?6)
+           AND (?7 IS NULL OR e.location = ?7)
+           AND (?8 IS NULL OR e.time_of_day = ?8)
+           AND (?9 IS NULL OR e.gender = ?9)
+           AND (?10 IS NULL OR e.party_species = ?10)
+           AND (?11 IS NULL OR e.trade_species = ?11)
+           AND (?12 IS NULL OR (e.min_happiness IS NOT NULL) = ?12)
+           AND (?13 IS NULL OR e.needs_overworld_rain = ?13)
+         ORDER BY e.to_species_id, e.detail_index"
+    )
+    .bind(filter.species_id)
+    .bind(&filter.trigger)
+    .bind(&filter.item)
+    .bind(&filter.held_item)
+    .bind(&filter.known_move)
+    .bind(&filter.known_move_type)
+    .bind(&filter.location)
+    .bind(&filter.time_of_day)
+    .bind(filter.gender)
+    .bind(&filter.party_species)
+    .bind(&filter.trade_species)
+    .bind(filter.needs_happiness)
+    .bind(filter.needs_overworld_rain)
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let conditions = rows.iter_mut().map(|row| &mut row.condition).collect();
+    crate::cache::evolution::localize_conditions(&state.pool, conditions)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(rows)
+}
+
+/// Get alternate forms (mega, regional, etc.) for all species in a given evolution chain:
+/// pokemon of the chain whose own default form isn't a species' default form.
+/// Until pokemon forms are synced, falls back to pokemon sharing the chain's
+/// evolution_chain_id but NOT present as nodes in the evolution chain JSON.
 #[tauri::command]
 pub async fn get_alternate_forms(
     state: State<'_, AppState>,
     chain_id: i64,
 ) -> Result<Vec<PokemonSummary>, String> {
+    let forms_synced = crate::cache::forms::has_pokemon_forms(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    if forms_synced {
+        let rows: Vec<PokemonSummary> = sqlx::query_as(
+            "SELECT id, name_key, name_en, name_fr, type1_key, type2_key, hp, atk, def, spa, spd, spe, base_stat_total, sprite_url, species_id
+             FROM pokemon p
+             WHERE p.evolution_chain_id = ?1
+               AND EXISTS (SELECT 1 FROM pokemon_forms f
+                           WHERE f.pokemon_id = p.id AND f.is_default = 1
+                             AND f.category != 'default')
+             ORDER BY id"
+        )
+        .bind(chain_id)
+        .fetch_all(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        return Ok(rows);
+    }
+
     // 1. Get the evolution chain JSON to find base-form pokemon IDs
     let chain_node = crate::cache::evolution::get_evolution_chain(&state.pool, chain_id)
         .await
@@ -157,6 +297,44 @@ pub async fn get_alternate_forms(
     Ok(forms)
 }
 
+/// Get every sprite of a pokemon (shiny, female, back, animated, per-game sets). With a
+/// game, the main sprites come from the set of its generation, so an Emerald hack gets
+/// Gen 3 sprites.
+#[tauri::command]
+pub async fn get_pokemon_sprites(
+    state: State<'_, AppState>,
+    pokemon_id: i64,
+    game_id: Option<String>,
+) -> Result<PokemonSprites, String> {
+    let variants = crate::cache::sprites::get_pokemon_sprite_variants(&state.pool, pokemon_id)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let (source, generation) = match game_id {
+        Some(game_id) => {
+            let (chain, generation) = crate::cache::sprites::game_sprite_context(&state.pool, &game_id)
+                .await
+                .map_err(|e| e.to_string())?;
+            crate::cache::sprites::pick_sprite_set(&variants, &chain, Some(generation))
+        }
+        None => ("default".to_string(), None),
+    };
+
+    Ok(crate::cache::sprites::build_pokemon_sprites(pokemon_id, variants, source, generation))
+}
+
+/// Get the sprite set of a game as a whole ("default" if none), to show its sprites in
+/// lists through `pokedia-asset://pokemon-set/<set>/<variant>/<id>`.
+#[tauri::command]
+pub async fn get_game_sprite_set(
+    state: State<'_, AppState>,
+    game_id: String,
+) -> Result<String, String> {
+    crate::cache::sprites::game_sprite_set(&state.pool, &game_id)
+        .await
+        .map_err(|e| e.to_string())
+}
+
 /// Recursively collect all pokemon_ids from an evolution chain tree.
 fn collect_chain_ids(node: &crate::models::EvolutionNode, ids: &mut Vec<i64>) {
     if let Some(pid) = node.pokemon_id {
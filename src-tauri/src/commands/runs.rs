@@ -0,0 +1,539 @@
+use std::collections::HashSet;
+
+use crate::cache::locations::normalize_location;
+use crate::models::games::GameLocation;
+use crate::models::{CatchCandidate, RunEncounter, RunGraveyard, RunSummary};
+use crate::AppState;
+use sqlx::SqlitePool;
+use tauri::State;
+
+/// Valid encounter statuses.
+const ENCOUNTER_STATUSES: &[&str] = &["caught", "failed", "skipped", "dead", "boxed"];
+
+/// Statuses that mean the player obtained the pokemon (they count for the dupes clause).
+const OWNED_STATUSES: &str = "('caught', 'dead', 'boxed')";
+
+const RUN_SUMMARY_SELECT: &str =
+    "SELECT
+       r.id, r.game_id, g.name_en AS game_name, r.name,
+       r.dupes_clause, r.shiny_clause, r.level_caps, r.last_trainer_id,
+       CASE WHEN r.level_caps = 1 THEN (
+         SELECT t.level_cap FROM game_trainers t
+         WHERE t.game_id = r.game_id AND t.trainer_id > r.last_trainer_id AND t.level_cap IS NOT NULL
+         ORDER BY t.trainer_id LIMIT 1
+       ) END AS level_cap,
+       (SELECT COUNT(DISTINCT e.location_key) FROM run_encounters e WHERE e.run_id = r.id) AS visited_count,
+       (SELECT COUNT(*) FROM run_encounters e WHERE e.run_id = r.id AND e.status IN ('caught', 'boxed')) AS caught_count,
+       (SELECT COUNT(*) FROM run_encounters e WHERE e.run_id = r.id AND e.status = 'dead') AS dead_count,
+       r.created_at, r.updated_at
+     FROM runs r
+     LEFT JOIN games g ON g.id = r.game_id";
+
+const RUN_ENCOUNTER_SELECT: &str =
+    "SELECT
+       e.id, e.run_id, e.location_key, gl.name AS location_name,
+       e.pokemon_name_key, p.id AS pokemon_id, p.name_en, p.name_fr, p.sprite_url,
+       e.nickname, e.status, e.is_shiny, e.notes, e.updated_at
+     FROM run_encounters e
+     JOIN runs r ON r.id = e.run_id
+     LEFT JOIN game_locations gl ON gl.game_id = r.game_id AND gl.location_key = e.location_key
+     LEFT JOIN pokemon p ON p.name_key = e.pokemon_name_key";
+
+/// Create a new run for a game.
+#[tauri::command]
+pub async fn create_run(
+    state: State<'_, AppState>,
+    game_id: String,
+    name: String,
+    dupes_clause: bool,
+    shiny_clause: bool,
+    level_caps: bool,
+) -> Result<RunSummary, String> {
+    let exists: Option<String> = sqlx::query_scalar("SELECT id FROM games WHERE id = ?1")
+        .bind(&game_id)
+        .fetch_optional(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    if exists.is_none() {
+        return Err(format!("Unknown game: {}", game_id));
+    }
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let run_id = sqlx::query(
+        "INSERT INTO runs (game_id, name, dupes_clause, shiny_clause, level_caps, created_at, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?6)"
+    )
+    .bind(&game_id)
+    .bind(&name)
+    .bind(dupes_clause as i64)
+    .bind(shiny_clause as i64)
+    .bind(level_caps as i64)
+    .bind(&now)
+    .execute(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?
+    .last_insert_rowid();
+
+    fetch_run(&state.pool, run_id)
+        .await?
+        .ok_or_else(|| "Failed to create run".to_string())
+}
+
+/// Get all runs, most recently updated first.
+#[tauri::command]
+pub async fn get_all_runs(
+    state: State<'_, AppState>,
+) -> Result<Vec<RunSummary>, String> {
+    let sql = format!("{} ORDER BY r.updated_at DESC", RUN_SUMMARY_SELECT);
+
+    let rows: Vec<RunSummary> = sqlx::query_as(&sql)
+        .fetch_all(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(rows)
+}
+
+/// Get a single run.
+#[tauri::command]
+pub async fn get_run(
+    state: State<'_, AppState>,
+    run_id: i64,
+) -> Result<Option<RunSummary>, String> {
+    fetch_run(&state.pool, run_id).await
+}
+
+/// Update a run's ruleset.
+#[tauri::command]
+pub async fn update_run_rules(
+    state: State<'_, AppState>,
+    run_id: i64,
+    dupes_clause: bool,
+    shiny_clause: bool,
+    level_caps: bool,
+) -> Result<(), String> {
+    let now = chrono::Utc::now().to_rfc3339();
+    sqlx::query(
+        "UPDATE runs SET dupes_clause = ?1, shiny_clause = ?2, level_caps = ?3, updated_at = ?4
+         WHERE id = ?5"
+    )
+    .bind(dupes_clause as i64)
+    .bind(shiny_clause as i64)
+    .bind(level_caps as i64)
+    .bind(&now)
+    .bind(run_id)
+    .execute(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Record the last trainer defeated (progression marker used for level caps).
+#[tauri::command]
+pub async fn set_run_progress(
+    state: State<'_, AppState>,
+    run_id: i64,
+    last_trainer_id: i64,
+) -> Result<(), String> {
+    let now = chrono::Utc::now().to_rfc3339();
+    sqlx::query("UPDATE runs SET last_trainer_id = ?1, updated_at = ?2 WHERE id = ?3")
+        .bind(last_trainer_id)
+        .bind(&now)
+        .bind(run_id)
+        .execute(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Delete a run and its encounters.
+#[tauri::command]
+pub async fn delete_run(
+    state: State<'_, AppState>,
+    run_id: i64,
+) -> Result<(), String> {
+    sqlx::query("DELETE FROM run_encounters WHERE run_id = ?1")
+        .bind(run_id)
+        .execute(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+    sqlx::query("DELETE FROM runs WHERE id = ?1")
+        .bind(run_id)
+        .execute(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Record the encounter at a location.
+/// Each location allows one encounter; with the shiny clause, shiny encounters are extra.
+/// The location must be one of the game's. With the dupes clause, caught pokemon must not
+/// share an evolution family with one already obtained (unless every pokemon at the
+/// location is a dupe).
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn record_encounter(
+    state: State<'_, AppState>,
+    run_id: i64,
+    location: String,
+    pokemon_name_key: Option<String>,
+    status: String,
+    nickname: Option<String>,
+    is_shiny: bool,
+    notes: Option<String>,
+) -> Result<RunEncounter, String> {
+    let pool = &state.pool;
+
+    validate_status(&status)?;
+    if pokemon_name_key.is_none() && matches!(status.as_str(), "caught" | "dead" | "boxed") {
+        return Err(format!("A pokemon is required for status '{}'", status));
+    }
+
+    let run = fetch_run(pool, run_id)
+        .await?
+        .ok_or_else(|| format!("Unknown run: {}", run_id))?;
+    let location_key = normalize_location(&location)
+        .map(|l| l.key)
+        .ok_or_else(|| format!("Invalid location: {}", location))?;
+
+    let in_game: bool = sqlx::query_scalar(
+        "SELECT EXISTS (SELECT 1 FROM game_locations WHERE game_id = ?1 AND location_key = ?2)
+             OR EXISTS (SELECT 1 FROM game_pokemon_locations WHERE game_id = ?1 AND location_key = ?2)"
+    )
+    .bind(&run.game_id)
+    .bind(&location_key)
+    .fetch_one(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    if !in_game {
+        return Err(format!("{} is not a location of {}", location_key, run.game_id));
+    }
+
+    let shiny_exempt = is_shiny && run.shiny_clause == 1;
+
+    if !shiny_exempt {
+        let used: Option<i64> = sqlx::query_scalar(
+            "SELECT id FROM run_encounters
+             WHERE run_id = ?1 AND location_key = ?2 AND NOT (is_shiny = 1 AND ?3 = 1)
+             LIMIT 1"
+        )
+        .bind(run_id)
+        .bind(&location_key)
+        .bind(run.shiny_clause)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+        if used.is_some() {
+            return Err(format!("An encounter is already recorded at {}", location_key));
+        }
+    }
+
+    if let Some(ref name_key) = pokemon_name_key {
+        if run.dupes_clause == 1 && !shiny_exempt && status != "failed" {
+            // The pokemon may not be listed at the location (gifts, static encounters)
+            let chain_id: Option<Option<i64>> = sqlx::query_scalar(
+                "SELECT evolution_chain_id FROM pokemon WHERE name_key = ?1"
+            )
+            .bind(name_key)
+            .fetch_optional(pool)
+            .await
+            .map_err(|e| e.to_string())?;
+            let is_dupe = owned_families(pool, run_id)
+                .await?
+                .contains(&family_key(name_key, chain_id.flatten()));
+
+            let candidates = location_candidates(pool, &run, &location_key).await?;
+            let all_dupes = !candidates.is_empty() && candidates.iter().all(|c| c.is_dupe);
+            if is_dupe && !all_dupes {
+                return Err(format!("{} is a dupe (dupes clause)", name_key));
+            }
+        }
+    }
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let encounter_id = sqlx::query(
+        "INSERT INTO run_encounters (run_id, location_key, pokemon_name_key, nickname, status, is_shiny, notes, updated_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8)"
+    )
+    .bind(run_id)
+    .bind(&location_key)
+    .bind(&pokemon_name_key)
+    .bind(&nickname)
+    .bind(&status)
+    .bind(is_shiny as i64)
+    .bind(&notes)
+    .bind(&now)
+    .execute(pool)
+    .await
+    .map_err(|e| e.to_string())?
+    .last_insert_rowid();
+
+    touch_run(pool, run_id).await?;
+
+    fetch_encounter(pool, encounter_id)
+        .await?
+        .ok_or_else(|| "Failed to record encounter".to_string())
+}
+
+/// Change the status of an encounter (e.g. caught -> dead or boxed).
+#[tauri::command]
+pub async fn update_encounter_status(
+    state: State<'_, AppState>,
+    encounter_id: i64,
+    status: String,
+    notes: Option<String>,
+) -> Result<(), String> {
+    validate_status(&status)?;
+
+    let now = chrono::Utc::now().to_rfc3339();
+    let run_id: Option<i64> = sqlx::query_scalar(
+        "UPDATE run_encounters SET status = ?1, notes = COALESCE(?2, notes), updated_at = ?3
+         WHERE id = ?4
+         RETURNING run_id"
+    )
+    .bind(&status)
+    .bind(&notes)
+    .bind(&now)
+    .bind(encounter_id)
+    .fetch_optional(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    match run_id {
+        Some(run_id) => touch_run(&state.pool, run_id).await,
+        None => Err(format!("Unknown encounter: {}", encounter_id)),
+    }
+}
+
+/// Delete an encounter record.
+#[tauri::command]
+pub async fn delete_encounter(
+    state: State<'_, AppState>,
+    encounter_id: i64,
+) -> Result<(), String> {
+    sqlx::query("DELETE FROM run_encounters WHERE id = ?1")
+        .bind(encounter_id)
+        .execute(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Get all encounters of a run.
+#[tauri::command]
+pub async fn get_run_encounters(
+    state: State<'_, AppState>,
+    run_id: i64,
+) -> Result<Vec<RunEncounter>, String> {
+    let sql = format!("{} WHERE e.run_id = ?1 ORDER BY e.id", RUN_ENCOUNTER_SELECT);
+
+    let rows: Vec<RunEncounter> = sqlx::query_as(&sql)
+        .bind(run_id)
+        .fetch_all(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(rows)
+}
+
+/// Get the locations with wild pokemon that have no encounter recorded yet.
+#[tauri::command]
+pub async fn get_run_next_locations(
+    state: State<'_, AppState>,
+    run_id: i64,
+) -> Result<Vec<GameLocation>, String> {
+    let rows: Vec<GameLocation> = sqlx::query_as(
+        "SELECT gl.game_id, gl.location_key, gl.name, gl.pokemon_count, gl.item_count
+         FROM runs r
+         JOIN game_locations gl ON gl.game_id = r.game_id
+         WHERE r.id = ?1
+           AND gl.pokemon_count > 0
+           AND NOT EXISTS (
+             SELECT 1 FROM run_encounters e
+             WHERE e.run_id = r.id AND e.location_key = gl.location_key
+               AND NOT (e.is_shiny = 1 AND r.shiny_clause = 1)
+           )
+         ORDER BY gl.name"
+    )
+    .bind(run_id)
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(rows)
+}
+
+/// Get the pokemon available at a location, flagged against the run's dupes clause.
+#[tauri::command]
+pub async fn get_run_catch_candidates(
+    state: State<'_, AppState>,
+    run_id: i64,
+    location: String,
+) -> Result<Vec<CatchCandidate>, String> {
+    let run = fetch_run(&state.pool, run_id)
+        .await?
+        .ok_or_else(|| format!("Unknown run: {}", run_id))?;
+    let location_key = match normalize_location(&location) {
+        Some(l) => l.key,
+        None => return Ok(Vec::new()),
+    };
+
+    location_candidates(&state.pool, &run, &location_key).await
+}
+
+/// Get the dead pokemon of a run and a count of encounters per status.
+#[tauri::command]
+pub async fn get_run_graveyard(
+    state: State<'_, AppState>,
+    run_id: i64,
+) -> Result<RunGraveyard, String> {
+    let sql = format!("{} WHERE e.run_id = ?1 AND e.status = 'dead' ORDER BY e.updated_at", RUN_ENCOUNTER_SELECT);
+
+    let dead: Vec<RunEncounter> = sqlx::query_as(&sql)
+        .bind(run_id)
+        .fetch_all(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let counts: Vec<(String, i64)> = sqlx::query_as(
+        "SELECT status, COUNT(*) FROM run_encounters WHERE run_id = ?1 GROUP BY status"
+    )
+    .bind(run_id)
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let count = |status: &str| {
+        counts
+            .iter()
+            .find(|(s, _)| s == status)
+            .map(|(_, n)| *n)
+            .unwrap_or(0)
+    };
+
+    Ok(RunGraveyard {
+        run_id,
+        caught_count: count("caught"),
+        dead_count: count("dead"),
+        boxed_count: count("boxed"),
+        failed_count: count("failed"),
+        skipped_count: count("skipped"),
+        dead,
+    })
+}
+
+// ── Helpers ──────────────────────────────────────────────────────────
+
+fn validate_status(status: &str) -> Result<(), String> {
+    if ENCOUNTER_STATUSES.contains(&status) {
+        Ok(())
+    } else {
+        Err(format!("Invalid encounter status: {}", status))
+    }
+}
+
+async fn fetch_run(pool: &SqlitePool, run_id: i64) -> Result<Option<RunSummary>, String> {
+    let sql = format!("{} WHERE r.id = ?1", RUN_SUMMARY_SELECT);
+
+    sqlx::query_as(&sql)
+        .bind(run_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+async fn fetch_encounter(pool: &SqlitePool, encounter_id: i64) -> Result<Option<RunEncounter>, String> {
+    let sql = format!("{} WHERE e.id = ?1", RUN_ENCOUNTER_SELECT);
+
+    sqlx::query_as(&sql)
+        .bind(encounter_id)
+        .fetch_optional(pool)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+async fn touch_run(pool: &SqlitePool, run_id: i64) -> Result<(), String> {
+    let now = chrono::Utc::now().to_rfc3339();
+    sqlx::query("UPDATE runs SET updated_at = ?1 WHERE id = ?2")
+        .bind(&now)
+        .bind(run_id)
+        .execute(pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(())
+}
+
+/// Evolution family of a pokemon: its evolution chain, or the pokemon itself if unknown.
+fn family_key(name_key: &str, evolution_chain_id: Option<i64>) -> String {
+    match evolution_chain_id {
+        Some(id) => format!("chain:{}", id),
+        None => format!("pokemon:{}", name_key),
+    }
+}
+
+/// Evolution families of the pokemon obtained in a run.
+async fn owned_families(pool: &SqlitePool, run_id: i64) -> Result<HashSet<String>, String> {
+    let owned: Vec<(String, Option<i64>)> = sqlx::query_as(&format!(
+        "SELECT e.pokemon_name_key, p.evolution_chain_id
+         FROM run_encounters e
+         LEFT JOIN pokemon p ON p.name_key = e.pokemon_name_key
+         WHERE e.run_id = ?1 AND e.pokemon_name_key IS NOT NULL AND e.status IN {}",
+        OWNED_STATUSES
+    ))
+    .bind(run_id)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(owned
+        .iter()
+        .map(|(name_key, chain_id)| family_key(name_key, *chain_id))
+        .collect())
+}
+
+/// List the pokemon found at a location and apply the run's dupes clause.
+async fn location_candidates(
+    pool: &SqlitePool,
+    run: &RunSummary,
+    location_key: &str,
+) -> Result<Vec<CatchCandidate>, String> {
+    let mut candidates: Vec<CatchCandidate> = sqlx::query_as(
+        "SELECT DISTINCT
+           gpl.pokemon_name_key,
+           p.id AS pokemon_id,
+           p.name_en,
+           p.name_fr,
+           p.type1_key,
+           p.type2_key,
+           p.sprite_url,
+           p.evolution_chain_id
+         FROM game_pokemon_locations gpl
+         LEFT JOIN pokemon p ON p.name_key = gpl.pokemon_name_key
+         WHERE gpl.game_id = ?1 AND gpl.location_key = ?2
+         ORDER BY COALESCE(p.id, 99999), gpl.pokemon_name_key"
+    )
+    .bind(&run.game_id)
+    .bind(location_key)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let owned_families = owned_families(pool, run.id).await?;
+    for c in &mut candidates {
+        c.is_dupe = owned_families.contains(&family_key(&c.pokemon_name_key, c.evolution_chain_id));
+    }
+
+    // If everything here is a dupe, the clause doesn't apply
+    let all_dupes = candidates.iter().all(|c| c.is_dupe);
+    for c in &mut candidates {
+        c.is_valid = run.dupes_clause == 0 || all_dupes || !c.is_dupe;
+    }
+
+    Ok(candidates)
+}
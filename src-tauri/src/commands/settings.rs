@@ -1,3 +1,4 @@
+use crate::cache::localization::{available_languages, is_language_code};
 use crate::models::AppSettings;
 use crate::AppState;
 use tauri::State;
@@ -22,6 +23,7 @@ pub async fn get_settings(
             "lang_item_names" => settings.lang_item_names = value,
             "lang_descriptions" => settings.lang_descriptions = value,
             "theme" => settings.theme = value,
+            "sync_sprites" => settings.sync_sprites = value,
             _ => {}
         }
     }
@@ -43,10 +45,17 @@ pub async fn set_setting(
         "lang_item_names",
         "lang_descriptions",
         "theme",
+        "sync_sprites",
     ];
     if !valid_keys.contains(&key.as_str()) {
         return Err(format!("Invalid setting key: {}", key));
     }
+    if key.starts_with("lang_") && !is_language_code(&value) {
+        return Err(format!("Invalid language code: {}", value));
+    }
+    if key == "sync_sprites" && value != "true" && value != "false" {
+        return Err(format!("Invalid value for sync_sprites: {}", value));
+    }
 
     sqlx::query("INSERT INTO settings (key, value) VALUES (?1, ?2) ON CONFLICT(key) DO UPDATE SET value = excluded.value")
         .bind(&key)
@@ -57,3 +66,11 @@ pub async fn set_setting(
 
     Ok(())
 }
+
+/// Get the languages with synced names and descriptions ("de", "en", "ja", ...).
+#[tauri::command]
+pub async fn get_available_languages(
+    state: State<'_, AppState>,
+) -> Result<Vec<String>, String> {
+    available_languages(&state.pool).await.map_err(|e| e.to_string())
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use crate::cache::localization::fill_localized;
+use crate::models::{PokemonForm, Species, SpeciesFilter};
+use crate::AppState;
+use tauri::State;
+
+const SPECIES_SELECT: &str =
+    "SELECT
+       s.species_id, s.name_key, s.name_en, s.name_fr, s.genus_en, s.genus_fr,
+       s.capture_rate, s.base_happiness, s.gender_rate, s.growth_rate, s.hatch_counter,
+       s.is_legendary, s.is_mythical, s.is_baby, s.generation,
+       s.evolves_from_species_id, s.evolution_chain_id
+     FROM species s";
+
+/// Get species data (capture rate, gender ratio, egg groups, ...) by species ID, with
+/// name and genus in `lang` if given.
+#[tauri::command]
+pub async fn get_species_by_id(
+    state: State<'_, AppState>,
+    species_id: i64,
+    lang: Option<String>,
+) -> Result<Option<Species>, String> {
+    let sql = format!("{} WHERE s.species_id = ?1", SPECIES_SELECT);
+    let row: Option<Species> = sqlx::query_as(&sql)
+        .bind(species_id)
+        .fetch_optional(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut species = match row {
+        Some(species) => species,
+        None => return Ok(None),
+    };
+    species.egg_groups = sqlx::query_scalar(
+        "SELECT egg_group FROM species_egg_groups WHERE species_id = ?1 ORDER BY egg_group"
+    )
+    .bind(species_id)
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let details = std::slice::from_mut(&mut species);
+    fill_localized(&state.pool, "species", "name", lang.as_deref(), &mut *details, |x| (x.species_id, &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+    fill_localized(&state.pool, "species", "genus", lang.as_deref(), details, |x| (x.species_id, &mut x.genus_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(Some(species))
+}
+
+/// Query species, e.g. all legendaries or egg group "dragon".
+#[tauri::command]
+pub async fn get_species(
+    state: State<'_, AppState>,
+    filter: SpeciesFilter,
+    lang: Option<String>,
+) -> Result<Vec<Species>, String> {
+    let pattern = filter.query.map(|q| format!("%{}%", q.to_lowercase()));
+    let sql = format!(
+        "{}
+         WHERE (?1 IS NULL OR LOWER(s.name_key) LIKE ?1 OR LOWER(s.name_en) LIKE ?1
+                OR LOWER(s.name_fr) LIKE ?1
+                OR s.species_id IN (SELECT entity_id FROM localized_text
+                                    WHERE entity = 'species' AND field = 'name' AND LOWER(text) LIKE ?1))
+           AND (?2 IS NULL OR s.is_legendary = ?2)
+           AND (?3 IS NULL OR s.is_mythical = ?3)
+           AND (?4 IS NULL OR s.is_baby = ?4)
+           AND (?5 IS NULL OR EXISTS (SELECT 1 FROM species_egg_groups seg
+                                      WHERE seg.species_id = s.species_id AND seg.egg_group = ?5))
+           AND (?6 IS NULL OR s.growth_rate = ?6)
+           AND (?7 IS NULL OR s.generation = ?7)
+           AND (?8 IS NULL OR (s.gender_rate = -1) = ?8)
+         ORDER BY s.species_id",
+        SPECIES_SELECT
+    );
+    let mut rows: Vec<Species> = sqlx::query_as(&sql)
+        .bind(&pattern)
+        .bind(filter.is_legendary)
+        .bind(filter.is_mythical)
+        .bind(filter.is_baby)
+        .bind(&filter.egg_group)
+        .bind(&filter.growth_rate)
+        .bind(filter.generation)
+        .bind(filter.genderless)
+        .fetch_all(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let mut egg_groups: HashMap<i64, Vec<String>> = HashMap::new();
+    let pairs: Vec<(i64, String)> = sqlx::query_as(
+        "SELECT species_id, egg_group FROM species_egg_groups ORDER BY egg_group"
+    )
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    for (species_id, egg_group) in pairs {
+        egg_groups.entry(species_id).or_default().push(egg_group);
+    }
+    for species in &mut rows {
+        species.egg_groups = egg_groups.remove(&species.species_id).unwrap_or_default();
+    }
+
+    fill_localized(&state.pool, "species", "name", lang.as_deref(), &mut rows, |x| (x.species_id, &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+    fill_localized(&state.pool, "species", "genus", lang.as_deref(), &mut rows, |x| (x.species_id, &mut x.genus_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(rows)
+}
+
+/// Get every form of a species (all of its pokemon and their cosmetic forms), each with
+/// its category: default, mega, gigantamax, regional, battle, cosmetic or variant.
+#[tauri::command]
+pub async fn get_species_forms(
+    state: State<'_, AppState>,
+    species_id: i64,
+) -> Result<Vec<PokemonForm>, String> {
+    let rows: Vec<PokemonForm> = sqlx::query_as(
+        "SELECT f.id, f.name_key, f.pokemon_id, p.species_id, f.form_name,
+                f.name_en, f.name_fr, f.form_name_en, f.form_name_fr,
+                f.is_default, f.is_mega, f.is_battle_only, f.category, f.regional_tag,
+                f.version_group, f.form_order, f.sprite_url
+         FROM pokemon_forms f
+         JOIN pokemon p ON p.id = f.pokemon_id
+         WHERE COALESCE(p.species_id, p.id) = ?1
+         ORDER BY f.pokemon_id, f.form_order, f.id"
+    )
+    .bind(species_id)
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    Ok(rows)
+}
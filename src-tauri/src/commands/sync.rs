@@ -90,14 +90,28 @@ pub async fn clear_cache(
         "ability_pokemon",
         "pokemon_moves",
         "pokemon_abilities",
+        "pokemon_forms",
+        "pokemon_sprites",
+        "machines",
         "pokemon",
+        "move_past_values",
+        "move_stat_changes",
+        "move_flags",
         "moves",
         "items",
         "type_efficacy",
+        "type_efficacy_generations",
         "types",
+        "pokemon_evolutions",
         "evolution_chains",
+        "locations",
         "natures",
         "abilities",
+        "species_egg_groups",
+        "species",
+        "growth_rate_levels",
+        "localized_text",
+        "sprite_assets",
         "sync_meta",
     ];
 
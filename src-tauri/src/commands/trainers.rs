@@ -0,0 +1,202 @@
+use crate::cache::inheritance::{load_move_overrides, resolve_game_chain};
+use crate::models::games::StatSpread;
+use crate::models::{TrainerDetail, TrainerMove, TrainerPokemon, TrainerSummary};
+use crate::AppState;
+use tauri::State;
+
+const TRAINER_SUMMARY_SELECT: &str =
+    "SELECT
+       t.game_id, t.trainer_id, t.name, t.trainer_class, t.location, t.battle_type, t.level_cap,
+       (SELECT COUNT(*) FROM game_trainer_pokemon tp
+        WHERE tp.game_id = t.game_id AND tp.trainer_id = t.trainer_id) AS team_size,
+       (SELECT MAX(tp.level) FROM game_trainer_pokemon tp
+        WHERE tp.game_id = t.game_id AND tp.trainer_id = t.trainer_id) AS max_level
+     FROM game_trainers t";
+
+/// Raw team row; IVs/EVs are stored as JSON.
+#[derive(sqlx::FromRow)]
+struct TrainerPokemonRow {
+    slot: i64,
+    pokemon_name_key: String,
+    pokemon_id: Option<i64>,
+    name_en: Option<String>,
+    name_fr: Option<String>,
+    type1_key: Option<String>,
+    type2_key: Option<String>,
+    sprite_url: Option<String>,
+    level: i64,
+    ability_key: Option<String>,
+    ability_en: Option<String>,
+    ability_fr: Option<String>,
+    ability_short_effect_en: Option<String>,
+    ability_short_effect_fr: Option<String>,
+    item_key: Option<String>,
+    item_en: Option<String>,
+    item_fr: Option<String>,
+    item_sprite_url: Option<String>,
+    nature_key: Option<String>,
+    nature_en: Option<String>,
+    nature_fr: Option<String>,
+    ivs: Option<String>,
+    evs: Option<String>,
+}
+
+/// Get all trainers of a game, in progression order.
+#[tauri::command]
+pub async fn get_game_trainers(
+    state: State<'_, AppState>,
+    game_id: String,
+) -> Result<Vec<TrainerSummary>, String> {
+    let sql = format!("{} WHERE t.game_id = ?1 ORDER BY t.trainer_id", TRAINER_SUMMARY_SELECT);
+
+    let rows: Vec<TrainerSummary> = sqlx::query_as(&sql)
+        .bind(&game_id)
+        .fetch_all(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    Ok(rows)
+}
+
+/// Get a single trainer with its full team.
+/// Moves are joined with move details, with the move overrides of the game's
+/// inheritance chain applied.
+#[tauri::command]
+pub async fn get_game_trainer(
+    state: State<'_, AppState>,
+    game_id: String,
+    trainer_id: i64,
+) -> Result<Option<TrainerDetail>, String> {
+    let sql = format!("{} WHERE t.game_id = ?1 AND t.trainer_id = ?2", TRAINER_SUMMARY_SELECT);
+
+    let trainer: Option<TrainerSummary> = sqlx::query_as(&sql)
+        .bind(&game_id)
+        .bind(trainer_id)
+        .fetch_optional(&state.pool)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    let trainer = match trainer {
+        Some(t) => t,
+        None => return Ok(None),
+    };
+
+    let rows: Vec<TrainerPokemonRow> = sqlx::query_as(
+        "SELECT
+           tp.slot,
+           tp.pokemon_name_key,
+           p.id AS pokemon_id,
+           p.name_en,
+           p.name_fr,
+           p.type1_key,
+           p.type2_key,
+           p.sprite_url,
+           tp.level,
+           tp.ability_key,
+           a.name_en AS ability_en,
+           a.name_fr AS ability_fr,
+           a.short_effect_en AS ability_short_effect_en,
+           a.short_effect_fr AS ability_short_effect_fr,
+           tp.item_key,
+           i.name_en AS item_en,
+           i.name_fr AS item_fr,
+           i.sprite_url AS item_sprite_url,
+           tp.nature_key,
+           n.name_en AS nature_en,
+           n.name_fr AS nature_fr,
+           tp.ivs,
+           tp.evs
+         FROM game_trainer_pokemon tp
+         LEFT JOIN pokemon p ON p.name_key = tp.pokemon_name_key
+         LEFT JOIN abilities a ON a.name_key = tp.ability_key
+         LEFT JOIN items i ON i.name_key = tp.item_key
+         LEFT JOIN natures n ON n.name_key = tp.nature_key
+         WHERE tp.game_id = ?1 AND tp.trainer_id = ?2
+         ORDER BY tp.slot"
+    )
+    .bind(&game_id)
+    .bind(trainer_id)
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let mut moves: Vec<TrainerMove> = sqlx::query_as(
+        "SELECT
+           tpm.slot,
+           tpm.move_slot,
+           m.id AS move_id,
+           tpm.move_name_key AS name_key,
+           m.name_en,
+           m.name_fr,
+           m.type_key,
+           m.damage_class,
+           m.power,
+           m.accuracy,
+           m.pp,
+           m.priority
+         FROM game_trainer_pokemon_moves tpm
+         LEFT JOIN moves m ON m.name_key = tpm.move_name_key
+         WHERE tpm.game_id = ?1 AND tpm.trainer_id = ?2
+         ORDER BY tpm.slot, tpm.move_slot"
+    )
+    .bind(&game_id)
+    .bind(trainer_id)
+    .fetch_all(&state.pool)
+    .await
+    .map_err(|e| e.to_string())?;
+
+    let chain = resolve_game_chain(&state.pool, &game_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let overrides = load_move_overrides(&state.pool, &chain)
+        .await
+        .map_err(|e| e.to_string())?;
+    for m in &mut moves {
+        if let Some(ov) = overrides.get(&m.name_key) {
+            m.type_key = ov.type_key.clone().or(m.type_key.take());
+            m.damage_class = ov.damage_class.clone().or(m.damage_class.take());
+            m.power = ov.power.or(m.power);
+            m.accuracy = ov.accuracy.or(m.accuracy);
+            m.pp = ov.pp.or(m.pp);
+        }
+    }
+
+    let team = rows
+        .into_iter()
+        .map(|row| {
+            let pokemon_moves = moves.iter().filter(|m| m.slot == row.slot).cloned().collect();
+            TrainerPokemon {
+                slot: row.slot,
+                pokemon_name_key: row.pokemon_name_key,
+                pokemon_id: row.pokemon_id,
+                name_en: row.name_en,
+                name_fr: row.name_fr,
+                type1_key: row.type1_key,
+                type2_key: row.type2_key,
+                sprite_url: row.sprite_url,
+                level: row.level,
+                ability_key: row.ability_key,
+                ability_en: row.ability_en,
+                ability_fr: row.ability_fr,
+                ability_short_effect_en: row.ability_short_effect_en,
+                ability_short_effect_fr: row.ability_short_effect_fr,
+                item_key: row.item_key,
+                item_en: row.item_en,
+                item_fr: row.item_fr,
+                item_sprite_url: row.item_sprite_url,
+                nature_key: row.nature_key,
+                nature_en: row.nature_en,
+                nature_fr: row.nature_fr,
+                ivs: parse_stat_spread(row.ivs.as_deref()),
+                evs: parse_stat_spread(row.evs.as_deref()),
+                moves: pokemon_moves,
+            }
+        })
+        .collect();
+
+    Ok(Some(TrainerDetail { trainer, team }))
+}
+
+fn parse_stat_spread(json: Option<&str>) -> Option<StatSpread> {
+    json.and_then(|s| serde_json::from_str(s).ok())
+}
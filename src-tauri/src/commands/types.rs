@@ -1,19 +1,27 @@
+use crate::cache::inheritance::resolve_game_chain;
+use crate::cache::localization::fill_localized;
+use crate::cache::mechanics;
 use crate::models::{TypeEfficacy, TypeEntry};
 use crate::AppState;
 use tauri::State;
 
-/// Get all types.
+/// Get all types, with names in `lang` if given.
 #[tauri::command]
 pub async fn get_all_types(
     state: State<'_, AppState>,
+    lang: Option<String>,
 ) -> Result<Vec<TypeEntry>, String> {
-    let rows: Vec<TypeEntry> = sqlx::query_as(
-        "SELECT id, name_key, name_en, name_fr FROM types ORDER BY id"
+    let mut rows: Vec<TypeEntry> = sqlx::query_as(
+        "SELECT id, name_key, name_en, name_fr, generation FROM types ORDER BY id"
     )
     .fetch_all(&state.pool)
     .await
     .map_err(|e| e.to_string())?;
 
+    fill_localized(&state.pool, "type", "name", lang.as_deref(), &mut rows, |x| (x.id, &mut x.name_localized))
+        .await
+        .map_err(|e| e.to_string())?;
+
     Ok(rows)
 }
 
@@ -33,3 +41,36 @@ pub async fn get_type_efficacy(
 
     Ok(rows)
 }
+
+/// Get the type efficacy matrix as it was in a generation.
+#[tauri::command]
+pub async fn get_type_efficacy_for_generation(
+    state: State<'_, AppState>,
+    generation: i64,
+) -> Result<Vec<TypeEfficacy>, String> {
+    if !(1..=mechanics::LATEST_GENERATION).contains(&generation) {
+        return Err(format!("Unknown generation: {}", generation));
+    }
+
+    mechanics::type_efficacy_for_generation(&state.pool, generation)
+        .await
+        .map_err(|e| e.to_string())
+}
+
+/// Get the type efficacy matrix under a game's mechanics (Fairy type, chart overrides).
+#[tauri::command]
+pub async fn get_game_type_efficacy(
+    state: State<'_, AppState>,
+    game_id: String,
+) -> Result<Vec<TypeEfficacy>, String> {
+    let chain = resolve_game_chain(&state.pool, &game_id)
+        .await
+        .map_err(|e| e.to_string())?;
+    let profile = mechanics::resolve_game_mechanics(&state.pool, &chain)
+        .await
+        .map_err(|e| e.to_string())?;
+
+    mechanics::game_type_efficacy(&state.pool, &profile)
+        .await
+        .map_err(|e| e.to_string())
+}
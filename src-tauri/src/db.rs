@@ -34,12 +34,36 @@ pub async fn init_db(app_handle: &tauri::AppHandle) -> Result<SqlitePool, Box<dy
 }
 
 /// Run all schema migrations in order.
-async fn run_migrations(pool: &SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
+pub async fn run_migrations(pool: &SqlitePool) -> Result<(), Box<dyn std::error::Error>> {
     let migrations = [
         include_str!("../migrations/001_initial_schema.sql"),
         include_str!("../migrations/002_add_species_id.sql"),
         include_str!("../migrations/003_add_natures_abilities.sql"),
         include_str!("../migrations/004_game_selector.sql"),
+        include_str!("../migrations/005_game_locations.sql"),
+        include_str!("../migrations/006_game_trainers.sql"),
+        include_str!("../migrations/007_runs.sql"),
+        include_str!("../migrations/008_game_inheritance.sql"),
+        include_str!("../migrations/009_game_pokemon_overrides.sql"),
+        include_str!("../migrations/010_game_edits.sql"),
+        include_str!("../migrations/011_game_content_hash.sql"),
+        include_str!("../migrations/012_game_origin.sql"),
+        include_str!("../migrations/013_game_mechanics.sql"),
+        include_str!("../migrations/014_type_chart_generations.sql"),
+        include_str!("../migrations/015_move_past_values.sql"),
+        include_str!("../migrations/016_move_metadata.sql"),
+        include_str!("../migrations/017_species.sql"),
+        include_str!("../migrations/018_experience.sql"),
+        include_str!("../migrations/019_pokemon_evolutions.sql"),
+        include_str!("../migrations/020_locations.sql"),
+        include_str!("../migrations/021_pokemon_forms.sql"),
+        include_str!("../migrations/022_localized_text.sql"),
+        include_str!("../migrations/023_sprite_assets.sql"),
+        include_str!("../migrations/024_pokemon_sprites.sql"),
+        include_str!("../migrations/025_machines.sql"),
+        include_str!("../migrations/026_game_item_entries.sql"),
+        include_str!("../migrations/027_game_orphaned.sql"),
+        include_str!("../migrations/028_game_hidden.sql"),
     ];
 
     for migration_sql in migrations {
@@ -64,6 +88,167 @@ async fn run_migrations(pool: &SqlitePool) -> Result<(), Box<dyn std::error::Err
         }
     }
 
+    rebuild_game_pokemon_moves(pool).await?;
+    add_game_foreign_keys(pool).await?;
+
     log::info!("Database migrations completed successfully");
     Ok(())
 }
+
+/// Include the level in the game_pokemon_moves primary key: a pokemon can learn the
+/// same move at several levels. SQLite can't alter a primary key, so the table is
+/// rebuilt once (migrations run on every launch, hence the schema check).
+async fn rebuild_game_pokemon_moves(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    let schema: Option<String> = sqlx::query_scalar(
+        "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = 'game_pokemon_moves'"
+    )
+    .fetch_optional(pool)
+    .await?;
+
+    if schema.is_some_and(|sql| sql.contains("learn_method, level_learned_at)")) {
+        return Ok(());
+    }
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(
+        "CREATE TABLE game_pokemon_moves_new (
+            game_id TEXT NOT NULL,
+            pokemon_name_key TEXT NOT NULL,
+            move_name_key TEXT NOT NULL,
+            learn_method TEXT NOT NULL,
+            level_learned_at INTEGER NOT NULL DEFAULT 0,
+            PRIMARY KEY (game_id, pokemon_name_key, move_name_key, learn_method, level_learned_at),
+            FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+        )"
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query(
+        "INSERT OR IGNORE INTO game_pokemon_moves_new
+         SELECT game_id, pokemon_name_key, move_name_key, learn_method, COALESCE(level_learned_at, 0)
+         FROM game_pokemon_moves ORDER BY rowid"
+    )
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query("DROP TABLE game_pokemon_moves")
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("ALTER TABLE game_pokemon_moves_new RENAME TO game_pokemon_moves")
+        .execute(&mut *tx)
+        .await?;
+    sqlx::query("CREATE INDEX IF NOT EXISTS idx_gpm_pokemon ON game_pokemon_moves(game_id, pokemon_name_key)")
+        .execute(&mut *tx)
+        .await?;
+
+    tx.commit().await?;
+    log::info!("Rebuilt game_pokemon_moves with level in primary key");
+    Ok(())
+}
+
+/// Game tables created without a foreign key to games get one (with ON DELETE CASCADE),
+/// so deleting a game removes all of its data. Same one-time rebuild as above; rows
+/// of games that no longer exist are dropped on the way.
+async fn add_game_foreign_keys(pool: &SqlitePool) -> Result<(), sqlx::Error> {
+    rebuild_with_game_foreign_key(
+        pool,
+        "game_pokemon_locations",
+        "CREATE TABLE game_pokemon_locations_new (
+            game_id TEXT NOT NULL,
+            pokemon_name_key TEXT NOT NULL,
+            location TEXT NOT NULL,
+            location_key TEXT,
+            PRIMARY KEY (game_id, pokemon_name_key, location),
+            FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+        )",
+        "game_id, pokemon_name_key, location, location_key",
+        &[
+            "CREATE INDEX IF NOT EXISTS idx_gpl_pokemon ON game_pokemon_locations(game_id, pokemon_name_key)",
+            "CREATE INDEX IF NOT EXISTS idx_gpl_location ON game_pokemon_locations(game_id, location_key)",
+        ],
+    )
+    .await?;
+
+    rebuild_with_game_foreign_key(
+        pool,
+        "game_move_overrides",
+        "CREATE TABLE game_move_overrides_new (
+            game_id TEXT NOT NULL,
+            move_name_key TEXT NOT NULL,
+            power INTEGER,
+            accuracy INTEGER,
+            type_key TEXT,
+            pp INTEGER,
+            damage_class TEXT,
+            effect_en TEXT,
+            PRIMARY KEY (game_id, move_name_key),
+            FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+        )",
+        "game_id, move_name_key, power, accuracy, type_key, pp, damage_class, effect_en",
+        &[],
+    )
+    .await?;
+
+    rebuild_with_game_foreign_key(
+        pool,
+        "game_item_locations",
+        "CREATE TABLE game_item_locations_new (
+            game_id TEXT NOT NULL,
+            item_name_key TEXT NOT NULL,
+            location TEXT NOT NULL,
+            location_key TEXT,
+            PRIMARY KEY (game_id, item_name_key, location),
+            FOREIGN KEY (game_id) REFERENCES games(id) ON DELETE CASCADE
+        )",
+        "game_id, item_name_key, location, location_key",
+        &[
+            "CREATE INDEX IF NOT EXISTS idx_gil_item ON game_item_locations(game_id, item_name_key)",
+            "CREATE INDEX IF NOT EXISTS idx_gil_location ON game_item_locations(game_id, location_key)",
+        ],
+    )
+    .await
+}
+
+/// Rebuild `table` from `create_sql` (which creates `<table>_new`), unless it already
+/// references games.
+async fn rebuild_with_game_foreign_key(
+    pool: &SqlitePool,
+    table: &str,
+    create_sql: &str,
+    columns: &str,
+    indexes: &[&str],
+) -> Result<(), sqlx::Error> {
+    let schema: Option<String> = sqlx::query_scalar(
+        "SELECT sql FROM sqlite_master WHERE type = 'table' AND name = ?1"
+    )
+    .bind(table)
+    .fetch_optional(pool)
+    .await?;
+
+    match schema {
+        Some(sql) if !sql.contains("REFERENCES games") => {}
+        _ => return Ok(()),
+    }
+
+    let mut tx = pool.begin().await?;
+
+    sqlx::query(create_sql).execute(&mut *tx).await?;
+    sqlx::query(&format!(
+        "INSERT OR IGNORE INTO {table}_new ({columns})
+         SELECT {columns} FROM {table}
+         WHERE game_id IN (SELECT id FROM games) ORDER BY rowid"
+    ))
+    .execute(&mut *tx)
+    .await?;
+    sqlx::query(&format!("DROP TABLE {table}")).execute(&mut *tx).await?;
+    sqlx::query(&format!("ALTER TABLE {table}_new RENAME TO {table}"))
+        .execute(&mut *tx)
+        .await?;
+    for index in indexes {
+        sqlx::query(index).execute(&mut *tx).await?;
+    }
+
+    tx.commit().await?;
+    log::info!("Rebuilt {} with a foreign key to games", table);
+    Ok(())
+}
@@ -0,0 +1,441 @@
+use std::collections::HashSet;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use notify::{EventKind, RecursiveMode, Watcher};
+use serde::Deserialize;
+use sqlx::SqlitePool;
+use tauri::{Emitter, Manager};
+
+use crate::cache;
+use crate::cache::games::{ORIGIN_BUNDLED, ORIGIN_IMPORTED, ORIGIN_USER};
+use crate::models::{GameDataFile, GameSource};
+use crate::BUNDLED_GAMES;
+
+/// Folder of the app data dir where users drop their own game data files.
+const USER_GAMES_DIR: &str = "games";
+
+/// Wait for file changes to settle before rescanning (editors save in several steps).
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// A game data file waiting to be imported.
+struct PendingImport {
+    data: GameDataFile,
+    hash: String,
+    origin: &'static str,
+    file_name: Option<String>,
+}
+
+/// A `*.json` file of the user games folder, parsed and validated.
+pub struct UserGameFile {
+    pub file_name: String,
+    pub hash: String,
+    pub data: Result<GameDataFile, String>,
+}
+
+/// Just the game id and name of a data file, to avoid building the whole file.
+#[derive(Deserialize)]
+struct FileHeader {
+    game: FileHeaderGame,
+}
+
+#[derive(Deserialize)]
+struct FileHeaderGame {
+    id: String,
+    name_en: String,
+}
+
+/// Import state of a game, as stored in the games table.
+#[derive(sqlx::FromRow)]
+struct GameImportRow {
+    id: String,
+    name_en: String,
+    content_hash: Option<String>,
+    imported_at: Option<String>,
+}
+
+/// A user game whose file left the games folder.
+#[derive(sqlx::FromRow)]
+struct OrphanedGameRow {
+    id: String,
+    name_en: String,
+    origin_file: Option<String>,
+    content_hash: Option<String>,
+    imported_at: Option<String>,
+}
+
+/// Path of the user games folder (created if missing).
+pub fn user_games_dir(handle: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let dir = handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join(USER_GAMES_DIR);
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+/// Ids of the games embedded in the app.
+pub fn bundled_game_ids() -> HashSet<String> {
+    BUNDLED_GAMES
+        .iter()
+        .filter_map(|json| serde_json::from_str::<FileHeader>(json).ok())
+        .map(|header| header.game.id)
+        .collect()
+}
+
+/// Auto-import bundled hackrom data on startup (delete + re-insert).
+/// Only games whose embedded file changed since the last import (by content hash and
+/// import format version) are reimported, so an unchanged startup writes nothing.
+pub async fn import_bundled_games(pool: &SqlitePool, handle: &tauri::AppHandle) {
+    let mut pending = Vec::new();
+
+    for json_str in BUNDLED_GAMES {
+        let data: GameDataFile = match serde_json::from_str(json_str) {
+            Ok(d) => d,
+            Err(e) => {
+                log::warn!("Failed to parse bundled game JSON: {}", e);
+                continue;
+            }
+        };
+
+        let hash = cache::games::content_hash(json_str);
+        if is_up_to_date(pool, &data.game.id, &hash).await {
+            log::info!("Bundled game is up to date: {}", data.game.id);
+            continue;
+        }
+        pending.push(PendingImport { data, hash, origin: ORIGIN_BUNDLED, file_name: None });
+    }
+
+    import_pending(pool, handle, pending).await;
+}
+
+/// Read every `*.json` file of the user games folder, sorted by file name.
+/// Files reusing the id of a bundled game or of an earlier file are rejected.
+pub fn read_user_game_files(dir: &Path) -> Result<Vec<UserGameFile>, String> {
+    let mut paths: Vec<PathBuf> = std::fs::read_dir(dir)
+        .map_err(|e| format!("Failed to read user games folder {}: {}", dir.display(), e))?
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.is_file() && is_json_file(path))
+        .collect();
+    paths.sort();
+
+    let bundled_ids = bundled_game_ids();
+    let mut seen_ids: HashSet<String> = HashSet::new();
+
+    Ok(paths
+        .into_iter()
+        .map(|path| {
+            let file_name = path
+                .file_name()
+                .map(|name| name.to_string_lossy().to_string())
+                .unwrap_or_default();
+
+            let json = match std::fs::read_to_string(&path) {
+                Ok(json) => json,
+                Err(e) => {
+                    return UserGameFile {
+                        file_name,
+                        hash: String::new(),
+                        data: Err(format!("Failed to read file: {}", e)),
+                    }
+                }
+            };
+
+            let hash = cache::games::content_hash(&json);
+            let data = serde_json::from_str::<GameDataFile>(&json)
+                .map_err(|e| format!("Failed to parse game JSON: {}", e))
+                .and_then(|data| {
+                    cache::games::validate_game_data(&data)?;
+                    if bundled_ids.contains(&data.game.id) {
+                        return Err(format!("Game id '{}' is used by a bundled game", data.game.id));
+                    }
+                    if !seen_ids.insert(data.game.id.clone()) {
+                        return Err(format!("Game id '{}' is used by another file", data.game.id));
+                    }
+                    Ok(data)
+                });
+
+            UserGameFile { file_name, hash, data }
+        })
+        .collect())
+}
+
+/// Bring the user games in line with the games folder: import new or changed files
+/// and unlist games whose file is gone. Invalid files are logged and skipped
+/// (a game whose file became invalid keeps its last good import).
+/// An unlisted game keeps its runs and edits, and is listed again when its file comes back;
+/// only `delete_game`/`remove_game` delete it.
+pub async fn sync_user_games(pool: &SqlitePool, handle: &tauri::AppHandle, dir: &Path) {
+    let files = match read_user_game_files(dir) {
+        Ok(files) => files,
+        Err(e) => {
+            log::warn!("Skipping user games sync: {}", e);
+            return;
+        }
+    };
+    let file_names: HashSet<String> = files.iter().map(|f| f.file_name.clone()).collect();
+
+    let mut present_ids: HashSet<String> = HashSet::new();
+    let mut pending = Vec::new();
+
+    for file in files {
+        let data = match file.data {
+            Ok(data) => data,
+            Err(e) => {
+                log::warn!("Skipping user game file '{}': {}", file.file_name, e);
+                continue;
+            }
+        };
+
+        present_ids.insert(data.game.id.clone());
+        if is_up_to_date(pool, &data.game.id, &file.hash).await {
+            if let Err(e) = cache::games::set_game_orphaned(pool, &data.game.id, false).await {
+                log::error!("Failed to relist game '{}': {}", data.game.id, e);
+            }
+            continue;
+        }
+        pending.push(PendingImport {
+            data,
+            hash: file.hash,
+            origin: ORIGIN_USER,
+            file_name: Some(file.file_name),
+        });
+    }
+
+    let user_games: Vec<(String, Option<String>)> = sqlx::query_as(
+        "SELECT id, origin_file FROM games WHERE origin = ?1 AND orphaned = 0"
+    )
+    .bind(ORIGIN_USER)
+    .fetch_all(pool)
+    .await
+    .unwrap_or_default();
+
+    for (game_id, origin_file) in user_games {
+        let file_exists = origin_file.is_some_and(|f| file_names.contains(&f));
+        if present_ids.contains(&game_id) || file_exists {
+            continue;
+        }
+
+        log::info!("User game file removed, unlisting game: {}", game_id);
+        match cache::games::set_game_orphaned(pool, &game_id, true).await {
+            Ok(_) => {
+                let _ = handle.emit("game-import-progress", serde_json::json!({
+                    "status": "orphaned",
+                    "game": game_id,
+                }));
+            }
+            Err(e) => log::error!("Failed to unlist game '{}': {}", game_id, e),
+        }
+    }
+
+    import_pending(pool, handle, pending).await;
+}
+
+/// List every game source: bundled files, user folder files (valid or not), user games
+/// whose file is gone and manually imported games.
+pub async fn list_game_sources(
+    pool: &SqlitePool,
+    dir: &Path,
+) -> Result<Vec<GameSource>, String> {
+    let mut sources = Vec::new();
+
+    for json_str in BUNDLED_GAMES {
+        let header: FileHeader = match serde_json::from_str(json_str) {
+            Ok(h) => h,
+            Err(_) => continue,
+        };
+        let hash = cache::games::content_hash(json_str);
+        let row = import_row(pool, &header.game.id).await.map_err(|e| e.to_string())?;
+        sources.push(GameSource {
+            up_to_date: cache::games::is_import_current(pool, &header.game.id, &hash)
+                .await
+                .map_err(|e| e.to_string())?,
+            game_id: Some(header.game.id),
+            name_en: Some(header.game.name_en),
+            origin: ORIGIN_BUNDLED.to_string(),
+            file_name: None,
+            content_hash: Some(hash),
+            imported_at: row.and_then(|r| r.imported_at),
+            error: None,
+        });
+    }
+
+    for file in read_user_game_files(dir)? {
+        let source = match file.data {
+            Ok(data) => {
+                let row = import_row(pool, &data.game.id).await.map_err(|e| e.to_string())?;
+                GameSource {
+                    up_to_date: cache::games::is_import_current(pool, &data.game.id, &file.hash)
+                        .await
+                        .map_err(|e| e.to_string())?,
+                    game_id: Some(data.game.id),
+                    name_en: Some(data.game.name_en),
+                    origin: ORIGIN_USER.to_string(),
+                    file_name: Some(file.file_name),
+                    content_hash: Some(file.hash),
+                    imported_at: row.and_then(|r| r.imported_at),
+                    error: None,
+                }
+            }
+            Err(e) => GameSource {
+                game_id: None,
+                name_en: None,
+                origin: ORIGIN_USER.to_string(),
+                file_name: Some(file.file_name),
+                content_hash: Some(file.hash).filter(|h| !h.is_empty()),
+                imported_at: None,
+                up_to_date: false,
+                error: Some(e),
+            },
+        };
+        sources.push(source);
+    }
+
+    let orphaned: Vec<OrphanedGameRow> = sqlx::query_as(
+        "SELECT id, name_en, origin_file, content_hash, imported_at FROM games
+         WHERE origin = ?1 AND orphaned = 1 ORDER BY sort_order, name_en"
+    )
+    .bind(ORIGIN_USER)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    for row in orphaned {
+        sources.push(GameSource {
+            game_id: Some(row.id),
+            name_en: Some(row.name_en),
+            origin: ORIGIN_USER.to_string(),
+            file_name: row.origin_file,
+            content_hash: row.content_hash,
+            imported_at: row.imported_at,
+            up_to_date: false,
+            error: Some("File removed from the games folder".to_string()),
+        });
+    }
+
+    let imported: Vec<GameImportRow> = sqlx::query_as(
+        "SELECT id, name_en, content_hash, imported_at FROM games
+         WHERE origin = ?1 ORDER BY sort_order, name_en"
+    )
+    .bind(ORIGIN_IMPORTED)
+    .fetch_all(pool)
+    .await
+    .map_err(|e| e.to_string())?;
+    for row in imported {
+        sources.push(GameSource {
+            game_id: Some(row.id),
+            name_en: Some(row.name_en),
+            origin: ORIGIN_IMPORTED.to_string(),
+            file_name: None,
+            content_hash: row.content_hash,
+            imported_at: row.imported_at,
+            up_to_date: true,
+            error: None,
+        });
+    }
+
+    Ok(sources)
+}
+
+async fn import_row(pool: &SqlitePool, game_id: &str) -> Result<Option<GameImportRow>, sqlx::Error> {
+    sqlx::query_as("SELECT id, name_en, content_hash, imported_at FROM games WHERE id = ?1")
+        .bind(game_id)
+        .fetch_optional(pool)
+        .await
+}
+
+/// Watch the user games folder and resync it whenever a `*.json` file changes.
+pub fn watch_user_games(
+    pool: SqlitePool,
+    handle: tauri::AppHandle,
+    dir: PathBuf,
+) -> Result<(), String> {
+    let (tx, mut rx) = tokio::sync::mpsc::unbounded_channel();
+
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<notify::Event>| {
+        if let Ok(event) = res {
+            let relevant = !matches!(event.kind, EventKind::Access(_))
+                && event.paths.iter().any(|path| is_json_file(path));
+            if relevant {
+                let _ = tx.send(());
+            }
+        }
+    })
+    .map_err(|e| e.to_string())?;
+    watcher
+        .watch(&dir, RecursiveMode::NonRecursive)
+        .map_err(|e| e.to_string())?;
+
+    log::info!("Watching user games folder: {}", dir.display());
+    tauri::async_runtime::spawn(async move {
+        // The watcher stops when dropped; keep it alive with the task
+        let _watcher = watcher;
+
+        while rx.recv().await.is_some() {
+            while let Ok(Some(_)) = tokio::time::timeout(WATCH_DEBOUNCE, rx.recv()).await {}
+            sync_user_games(&pool, &handle, &dir).await;
+        }
+    });
+
+    Ok(())
+}
+
+fn is_json_file(path: &Path) -> bool {
+    path.extension()
+        .is_some_and(|ext| ext.eq_ignore_ascii_case("json"))
+}
+
+async fn is_up_to_date(pool: &SqlitePool, game_id: &str, hash: &str) -> bool {
+    match cache::games::is_import_current(pool, game_id, hash).await {
+        Ok(current) => current,
+        Err(e) => {
+            log::warn!("Failed to check game '{}': {}", game_id, e);
+            false
+        }
+    }
+}
+
+/// Import games one by one, emitting `game-import-progress` events.
+async fn import_pending(pool: &SqlitePool, handle: &tauri::AppHandle, pending: Vec<PendingImport>) {
+    let total = pending.len();
+    let mut imported = 0u32;
+
+    for item in pending {
+        let game_id = item.data.game.id.clone();
+        let _ = handle.emit("game-import-progress", serde_json::json!({
+            "status": "importing",
+            "game": item.data.game.name_en,
+            "current": imported + 1,
+            "total": total,
+        }));
+
+        log::info!("Auto-importing {} game: {}", item.origin, game_id);
+        let result = match cache::games::import_game_data(pool, &item.data).await {
+            Ok(_) => {
+                cache::games::record_game_import(
+                    pool,
+                    &game_id,
+                    &item.hash,
+                    item.origin,
+                    item.file_name.as_deref(),
+                )
+                .await
+            }
+            Err(e) => Err(e),
+        };
+        match result {
+            Ok(_) => {
+                imported += 1;
+                log::info!("Successfully imported game: {}", game_id);
+            }
+            Err(e) => log::error!("Failed to import game '{}': {}", game_id, e),
+        }
+    }
+
+    if imported > 0 {
+        let _ = handle.emit("game-import-progress", serde_json::json!({
+            "status": "done",
+            "imported": imported,
+        }));
+    }
+}
@@ -1,7 +1,7 @@
 use std::sync::Arc;
 
 use sqlx::SqlitePool;
-use tauri::{Emitter, Manager};
+use tauri::Manager;
 
 use api::PokeApiClient;
 
@@ -9,7 +9,9 @@ mod api;
 mod cache;
 mod commands;
 mod db;
+mod game_files;
 pub mod models;
+mod sprites;
 mod sync;
 
 /// Shared application state accessible from all Tauri commands.
@@ -25,54 +27,19 @@ const BUNDLED_GAMES: &[&str] = &[
     include_str!("../data/games/emerald-imperium.json"),
 ];
 
-/// Auto-import bundled hackrom data on every startup (delete + re-insert).
-/// This ensures updates shipped with new app versions are always applied.
-/// Runs in a background task, emits `game-import-progress` events.
-async fn auto_import_bundled_games(pool: &SqlitePool, handle: &tauri::AppHandle) {
-    let total = BUNDLED_GAMES.len();
-    let mut imported = 0u32;
-
-    for json_str in BUNDLED_GAMES {
-        let data: models::GameDataFile = match serde_json::from_str(json_str) {
-            Ok(d) => d,
-            Err(e) => {
-                log::warn!("Failed to parse bundled game JSON: {}", e);
-                continue;
-            }
-        };
-
-        let game_name = data.game.name_en.clone();
-        let _ = handle.emit("game-import-progress", serde_json::json!({
-            "status": "importing",
-            "game": game_name,
-            "current": imported + 1,
-            "total": total,
-        }));
-
-        log::info!("Auto-importing bundled game: {}", data.game.id);
-        match cache::games::import_game_data(pool, &data).await {
-            Ok(_) => {
-                imported += 1;
-                log::info!("Successfully imported game: {}", data.game.id);
-            }
-            Err(e) => log::error!("Failed to import game '{}': {}", data.game.id, e),
-        }
-    }
-
-    if imported > 0 {
-        let _ = handle.emit("game-import-progress", serde_json::json!({
-            "status": "done",
-            "imported": imported,
-        }));
-    }
-}
-
 /// Entry point for the Tauri application.
 #[cfg_attr(mobile, tauri::mobile_entry_point)]
 pub fn run() {
     tauri::Builder::default()
         .plugin(tauri_plugin_log::Builder::new().build())
         .plugin(tauri_plugin_window_state::Builder::default().build())
+        // Sprites from the offline cache, e.g. pokedia-asset://pokemon/25
+        .register_asynchronous_uri_scheme_protocol(sprites::ASSET_SCHEME, |ctx, request, responder| {
+            let handle = ctx.app_handle().clone();
+            tauri::async_runtime::spawn(async move {
+                responder.respond(sprites::serve_asset(&handle, &request).await);
+            });
+        })
         .setup(|app| {
             let handle = app.handle().clone();
 
@@ -85,10 +52,21 @@ pub fn run() {
 
             app.manage(AppState { pool: pool.clone(), api_client });
 
-            // Auto-import bundled hackrom data in background (non-blocking)
+            // Auto-import bundled and user game data in background (non-blocking),
+            // then keep watching the user games folder
             let import_handle = handle.clone();
             tauri::async_runtime::spawn(async move {
-                auto_import_bundled_games(&pool, &import_handle).await;
+                game_files::import_bundled_games(&pool, &import_handle).await;
+
+                match game_files::user_games_dir(&import_handle) {
+                    Ok(dir) => {
+                        game_files::sync_user_games(&pool, &import_handle, &dir).await;
+                        if let Err(e) = game_files::watch_user_games(pool, import_handle, dir) {
+                            log::error!("Failed to watch user games folder: {}", e);
+                        }
+                    }
+                    Err(e) => log::error!("Failed to open user games folder: {}", e),
+                }
             });
 
             log::info!("Pokedia application initialized successfully");
@@ -98,17 +76,36 @@ pub fn run() {
             // Settings
             commands::settings::get_settings,
             commands::settings::set_setting,
+            commands::settings::get_available_languages,
             // Pokemon
             commands::pokemon::get_all_pokemon,
             commands::pokemon::get_pokemon_by_id,
             commands::pokemon::search_pokemon,
             commands::pokemon::get_pokemon_abilities,
             commands::pokemon::get_pokemon_evolution_chain,
+            commands::pokemon::find_evolutions,
             commands::pokemon::get_alternate_forms,
+            commands::pokemon::get_pokemon_sprites,
+            commands::pokemon::get_game_sprite_set,
+            // Species
+            commands::species::get_species,
+            commands::species::get_species_by_id,
+            commands::species::get_species_forms,
+            // Breeding
+            commands::breeding::check_breeding_compatibility,
+            commands::breeding::get_game_egg_moves,
+            commands::breeding::find_breeding_chain,
+            // Experience
+            commands::experience::get_experience_between_levels,
+            commands::experience::calculate_exp_gain,
+            commands::experience::get_ev_training_targets,
+            // Catching
+            commands::catching::calculate_catch_chance,
             // Moves
             commands::moves::get_all_moves,
             commands::moves::get_move_by_id,
             commands::moves::search_moves,
+            commands::moves::filter_moves,
             commands::moves::get_pokemon_moves,
             commands::moves::get_move_pokemon,
             // Items
@@ -118,6 +115,8 @@ pub fn run() {
             // Types
             commands::types::get_all_types,
             commands::types::get_type_efficacy,
+            commands::types::get_type_efficacy_for_generation,
+            commands::types::get_game_type_efficacy,
             // Sync
             commands::sync::start_sync,
             commands::sync::get_sync_status,
@@ -137,11 +136,54 @@ pub fn run() {
             commands::games::get_all_games,
             commands::games::get_game_coverage,
             commands::games::get_game_pokemon_moves,
+            commands::games::get_game_mechanics,
             commands::games::get_game_pokemon_abilities,
             commands::games::get_game_pokemon_locations,
             commands::games::get_game_move_override,
             commands::games::get_game_item_locations,
+            commands::games::get_game_machines,
+            commands::games::get_game_locations,
+            commands::games::get_location_contents,
             commands::games::import_game_data,
+            commands::games::export_game_data,
+            commands::games::force_reimport_game,
+            commands::games::list_game_sources,
+            commands::games::remove_game,
+            commands::games::delete_game,
+            // Trainers
+            commands::trainers::get_game_trainers,
+            commands::trainers::get_game_trainer,
+            // Runs
+            commands::runs::create_run,
+            commands::runs::get_all_runs,
+            commands::runs::get_run,
+            commands::runs::update_run_rules,
+            commands::runs::set_run_progress,
+            commands::runs::delete_run,
+            commands::runs::record_encounter,
+            commands::runs::update_encounter_status,
+            commands::runs::delete_encounter,
+            commands::runs::get_run_encounters,
+            commands::runs::get_run_next_locations,
+            commands::runs::get_run_catch_candidates,
+            commands::runs::get_run_graveyard,
+            // Diff
+            commands::diff::diff_games,
+            commands::diff::diff_game_pokemon,
+            commands::diff::export_game_changelog,
+            // Edits
+            commands::edits::get_game_edits,
+            commands::edits::add_game_learnset_entry,
+            commands::edits::update_game_learnset_entry,
+            commands::edits::remove_game_learnset_entry,
+            commands::edits::set_game_pokemon_ability,
+            commands::edits::remove_game_pokemon_ability,
+            commands::edits::add_game_pokemon_location,
+            commands::edits::remove_game_pokemon_location,
+            commands::edits::add_game_item_location,
+            commands::edits::remove_game_item_location,
+            commands::edits::set_game_move_override,
+            commands::edits::remove_game_move_override,
         ])
         .run(tauri::generate_context!())
         .expect("error while running Pokedia");
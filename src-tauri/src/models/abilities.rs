@@ -9,6 +9,11 @@ pub struct AbilitySummary {
     pub short_effect_en: Option<String>,
     pub short_effect_fr: Option<String>,
     pub generation: Option<i64>,
+    // Texts in the language requested with `lang`
+    #[sqlx(default)]
+    pub name_localized: Option<String>,
+    #[sqlx(default)]
+    pub short_effect_localized: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
@@ -22,6 +27,13 @@ pub struct AbilityDetail {
     pub short_effect_en: Option<String>,
     pub short_effect_fr: Option<String>,
     pub generation: Option<i64>,
+    // Texts in the language requested with `lang`
+    #[sqlx(default)]
+    pub name_localized: Option<String>,
+    #[sqlx(default)]
+    pub effect_localized: Option<String>,
+    #[sqlx(default)]
+    pub short_effect_localized: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
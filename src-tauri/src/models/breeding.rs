@@ -0,0 +1,28 @@
+use serde::{Deserialize, Serialize};
+
+/// Whether two pokemon can produce an egg together.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BreedingCompatibility {
+    pub compatible: bool,
+    pub shared_egg_groups: Vec<String>,
+    /// Why they can't breed, when they can't.
+    pub reason: Option<String>,
+}
+
+/// One breeding: the father passes the move to an egg of the mother's species.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BreedingStep {
+    pub father: String,
+    pub mother: String,
+    /// How the father knows the move ("level-up", "machine", "egg", ...).
+    pub father_learn_method: String,
+}
+
+/// Shortest sequence of breedings bringing an egg move onto a pokemon.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BreedingChain {
+    pub pokemon_name_key: String,
+    pub move_name_key: String,
+    /// Empty when the pokemon learns the move by other means.
+    pub steps: Vec<BreedingStep>,
+}
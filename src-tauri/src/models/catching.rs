@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+
+/// Encounter conditions affecting some balls.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct CatchConditions {
+    pub is_night: bool,
+    pub in_cave: bool,
+    /// Surfing, fishing or diving.
+    pub on_water: bool,
+    pub fishing: bool,
+    /// The species is already registered as caught.
+    pub already_caught: bool,
+}
+
+/// Inputs of a catch probability calculation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatchChanceParams {
+    pub pokemon_name_key: String,
+    pub level: i64,
+    /// Remaining HP, 1-100.
+    pub hp_percent: f64,
+    /// "sleep", "freeze", "paralysis", "poison" or "burn".
+    #[serde(default)]
+    pub status: Option<String>,
+    /// Ball item name key ("ultra-ball").
+    pub ball: String,
+    /// Battle turn of the first throw (1 = first turn), 1-100.
+    #[serde(default = "default_turn")]
+    pub turn: i64,
+    /// Number of throws, one per turn, 1-100.
+    #[serde(default = "default_throws")]
+    pub throws: i64,
+    pub generation: i64,
+    #[serde(default)]
+    pub conditions: CatchConditions,
+}
+
+fn default_turn() -> i64 {
+    1
+}
+
+fn default_throws() -> i64 {
+    1
+}
+
+/// Catch probability per throw and cumulated over the throws.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CatchChance {
+    pub capture_rate: i64,
+    /// "gen3" (Gen 3-4) or "gen5" (Gen 5+).
+    pub formula: String,
+    pub status_modifier: f64,
+    /// Ball modifier of each throw (turn-dependent balls change between throws).
+    pub ball_modifiers: Vec<f64>,
+    pub per_throw: Vec<f64>,
+    /// Probability of having caught it by each throw.
+    pub cumulative: Vec<f64>,
+}
@@ -0,0 +1,78 @@
+use serde::{Deserialize, Serialize};
+
+/// A learnset entry present in only one of the two games.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LearnsetDiffEntry {
+    pub move_name_key: String,
+    pub move_name_en: Option<String>,
+    pub move_name_fr: Option<String>,
+    pub learn_method: String,
+    pub level: i64,
+}
+
+/// A move learned by the same method in both games, at a different level.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LevelChange {
+    pub move_name_key: String,
+    pub move_name_en: Option<String>,
+    pub move_name_fr: Option<String>,
+    pub learn_method: String,
+    pub level_a: i64,
+    pub level_b: i64,
+}
+
+/// An ability slot whose ability differs between the two games.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct AbilityChange {
+    pub slot: i64,
+    pub is_hidden: i64,
+    pub ability_a: Option<String>,
+    pub ability_a_en: Option<String>,
+    pub ability_b: Option<String>,
+    pub ability_b_en: Option<String>,
+}
+
+/// Learnset and ability differences of one pokemon (game A -> game B).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PokemonDiff {
+    pub pokemon_name_key: String,
+    pub pokemon_id: Option<i64>,
+    pub name_en: Option<String>,
+    pub name_fr: Option<String>,
+    /// Layer each side's learnset was resolved from (game id, version group or "global").
+    pub learnset_source_a: Option<String>,
+    pub learnset_source_b: Option<String>,
+    /// Layer each side's abilities were resolved from.
+    pub ability_source_a: Option<String>,
+    pub ability_source_b: Option<String>,
+    pub added_moves: Vec<LearnsetDiffEntry>,
+    pub removed_moves: Vec<LearnsetDiffEntry>,
+    pub level_changes: Vec<LevelChange>,
+    pub ability_changes: Vec<AbilityChange>,
+}
+
+/// A single move stat that differs between the two games.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StatChange {
+    pub field: String,
+    pub value_a: Option<String>,
+    pub value_b: Option<String>,
+}
+
+/// Stat differences of one move (game A -> game B).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MoveDiff {
+    pub move_name_key: String,
+    pub name_en: Option<String>,
+    pub name_fr: Option<String>,
+    pub changes: Vec<StatChange>,
+}
+
+/// Everything that changed between two games.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameDiff {
+    pub game_a: String,
+    pub game_b: String,
+    pub pokemon: Vec<PokemonDiff>,
+    pub moves: Vec<MoveDiff>,
+}
@@ -0,0 +1,52 @@
+use serde::{Deserialize, Serialize};
+
+use super::games::{AbilityOverrideEntry, LearnsetEntry, MoveOverrideEntry};
+
+/// A single change to a game's data. `before: None` adds, `after: None` removes,
+/// both set replaces. Values use the game data file format.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(tag = "entity", rename_all = "snake_case")]
+pub enum GameEditChange {
+    Learnset {
+        pokemon_name_key: String,
+        before: Option<LearnsetEntry>,
+        after: Option<LearnsetEntry>,
+    },
+    Ability {
+        pokemon_name_key: String,
+        before: Option<AbilityOverrideEntry>,
+        after: Option<AbilityOverrideEntry>,
+    },
+    PokemonLocation {
+        pokemon_name_key: String,
+        before: Option<String>,
+        after: Option<String>,
+    },
+    MoveOverride {
+        move_name_key: String,
+        before: Option<MoveOverrideEntry>,
+        after: Option<MoveOverrideEntry>,
+    },
+    ItemLocation {
+        item_name_key: String,
+        before: Option<String>,
+        after: Option<String>,
+    },
+}
+
+/// An entry of the game edit log.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameEdit {
+    pub id: i64,
+    pub game_id: String,
+    /// "learnset", "ability", "pokemon_location", "move_override" or "item_location".
+    pub entity: String,
+    /// "add", "update" or "remove".
+    pub action: String,
+    /// Pokemon, move or item the change applies to.
+    pub target_key: String,
+    pub before: Option<serde_json::Value>,
+    pub after: Option<serde_json::Value>,
+    pub author: String,
+    pub created_at: String,
+}
@@ -0,0 +1,56 @@
+use serde::{Deserialize, Serialize};
+
+/// Experience needed to go from one level to another.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExperienceRange {
+    pub growth_rate: String,
+    pub from_level: i64,
+    pub to_level: i64,
+    /// Total experience at each level.
+    pub from_experience: i64,
+    pub to_experience: i64,
+    pub needed: i64,
+}
+
+/// Inputs of an experience gain calculation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpGainParams {
+    pub defeated_pokemon: String,
+    /// Levels of the defeated and the winning pokemon, 1-100.
+    pub defeated_level: i64,
+    pub winner_level: i64,
+    pub generation: i64,
+    #[serde(default)]
+    pub trainer_battle: bool,
+    /// Pokemon sharing the experience, at least 1 (default 1).
+    #[serde(default)]
+    pub participants: Option<i64>,
+    #[serde(default)]
+    pub lucky_egg: bool,
+    #[serde(default)]
+    pub traded: bool,
+}
+
+/// Result of an experience gain calculation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExpGain {
+    pub base_experience: i64,
+    /// "flat" (Gen 1-4 and 6) or "scaled" (Gen 5 and 7+).
+    pub formula: String,
+    pub experience: i64,
+}
+
+/// A pokemon worth defeating to train one stat's EVs, with where it's found.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct EvTrainingTarget {
+    pub pokemon_name_key: String,
+    pub pokemon_id: Option<i64>,
+    pub name_en: Option<String>,
+    pub name_fr: Option<String>,
+    pub sprite_url: Option<String>,
+    /// EVs of the trained stat.
+    pub ev_yield: i64,
+    /// EVs of all stats together.
+    pub total_ev_yield: i64,
+    pub locations: Vec<String>,
+}
@@ -0,0 +1,35 @@
+use serde::{Deserialize, Serialize};
+
+/// A form of a pokemon (regional variant, mega evolution, cosmetic form, ...).
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PokemonForm {
+    pub id: i64,
+    pub name_key: String,
+    pub pokemon_id: i64,
+    pub species_id: Option<i64>,
+    pub form_name: String,
+    pub name_en: Option<String>,
+    pub name_fr: Option<String>,
+    pub form_name_en: Option<String>,
+    pub form_name_fr: Option<String>,
+    /// Default form of its pokemon (not necessarily of the species).
+    pub is_default: bool,
+    pub is_mega: bool,
+    pub is_battle_only: bool,
+    /// default, mega, gigantamax, regional, battle, cosmetic or variant.
+    pub category: String,
+    pub regional_tag: Option<String>,
+    /// Version group the form was introduced in.
+    pub version_group: Option<String>,
+    pub form_order: Option<i64>,
+    pub sprite_url: Option<String>,
+}
+
+/// Form criteria for browsing pokemon; unset fields don't filter.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FormFilter {
+    /// Pokemon whose default form is in one of these categories.
+    pub categories: Vec<String>,
+    pub regional_tag: Option<String>,
+}
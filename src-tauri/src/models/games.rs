@@ -12,6 +12,7 @@ pub struct GameSummary {
     pub is_hackrom: i64,
     pub sort_order: i64,
     pub coverage: String,
+    pub extends: Option<String>,
 }
 
 /// Move override for a specific game.
@@ -25,11 +26,98 @@ pub struct GameMoveOverride {
     pub pp: Option<i64>,
     pub damage_class: Option<String>,
     pub effect_en: Option<String>,
+    /// Nearest layer of the inheritance chain that overrides this move.
+    #[sqlx(default)]
+    pub source: Option<String>,
 }
 
-// ── JSON import structs (deserialized from hackrom JSON files) ──────
+/// A TM/HM/TR of a game and the move it teaches.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameMachine {
+    /// Machine item ("tm01", "hm05", "tr12").
+    pub item: String,
+    pub move_name_key: String,
+    pub move_id: Option<i64>,
+    pub name_en: Option<String>,
+    pub name_fr: Option<String>,
+    pub type_key: Option<String>,
+    pub damage_class: Option<String>,
+    /// Layer of the inheritance chain the machine comes from.
+    pub source: String,
+    /// Where the machine is found, from the game's item locations.
+    pub locations: Vec<String>,
+}
+
+/// A normalized location within a game, with counts of what can be found there.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct GameLocation {
+    pub game_id: String,
+    pub location_key: String,
+    pub name: String,
+    pub pokemon_count: i64,
+    pub item_count: i64,
+}
+
+/// A pokemon obtainable at a location (one row per raw location string).
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct LocationPokemonEntry {
+    pub pokemon_name_key: String,
+    pub pokemon_id: Option<i64>,
+    pub name_en: Option<String>,
+    pub name_fr: Option<String>,
+    pub type1_key: Option<String>,
+    pub type2_key: Option<String>,
+    pub sprite_url: Option<String>,
+    pub raw_location: String,
+}
+
+/// An item obtainable at a location (one row per raw location string).
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct LocationItemEntry {
+    pub item_name_key: String,
+    pub item_id: Option<i64>,
+    pub name_en: Option<String>,
+    pub name_fr: Option<String>,
+    pub category: Option<String>,
+    pub sprite_url: Option<String>,
+    pub raw_location: String,
+}
+
+/// Everything obtainable at a single location of a game.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct LocationContents {
+    pub location: GameLocation,
+    pub pokemon: Vec<LocationPokemonEntry>,
+    pub items: Vec<LocationItemEntry>,
+}
 
-#[derive(Debug, Clone, Deserialize)]
+/// Where a game's data comes from: a bundled file, a file of the user games
+/// folder, or a manual import. Invalid user files are listed with their error.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameSource {
+    pub game_id: Option<String>,
+    pub name_en: Option<String>,
+    /// "bundled", "user" or "imported".
+    pub origin: String,
+    /// File name in the user games folder.
+    pub file_name: Option<String>,
+    pub content_hash: Option<String>,
+    pub imported_at: Option<String>,
+    /// False when the file changed (or was never imported) and awaits import.
+    pub up_to_date: bool,
+    pub error: Option<String>,
+}
+
+/// All game sources, with the user games folder path.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GameSources {
+    pub user_games_dir: String,
+    pub sources: Vec<GameSource>,
+}
+
+// ── JSON import structs (hackrom JSON files, also produced by export) ──────
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameDataFile {
     pub game: GameMeta,
     pub pokemon_overrides: Vec<PokemonOverride>,
@@ -37,9 +125,11 @@ pub struct GameDataFile {
     pub move_overrides: Vec<MoveOverrideEntry>,
     #[serde(default)]
     pub item_locations: Vec<ItemLocationEntry>,
+    #[serde(default)]
+    pub trainers: Vec<TrainerEntry>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct GameMeta {
     pub id: String,
     pub name_en: String,
@@ -53,13 +143,54 @@ pub struct GameMeta {
     pub sort_order: i64,
     #[serde(default = "default_coverage")]
     pub coverage: String,
+    /// Game id or official version this game inherits from (defaults to base_rom for changes_only games).
+    #[serde(default)]
+    pub extends: Option<String>,
+    /// Battle mechanics; unset fields are inherited, then derived from the generation.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub mechanics: Option<GameMechanics>,
+}
+
+/// Battle mechanics block of a game data file.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct GameMechanics {
+    /// Generation the game's mechanics are based on (3 for GBA hackroms).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub generation: Option<i64>,
+    /// "type" (damage class follows the move type, gen 1-3) or "move" (gen 4+).
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub damage_split: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub has_fairy: Option<bool>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub crit_multiplier: Option<f64>,
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub type_chart_overrides: Vec<TypeChartOverride>,
+}
+
+/// A changed cell of the type chart (damage factor in percent, as in type_efficacy).
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TypeChartOverride {
+    pub attacking_type: String,
+    pub defending_type: String,
+    pub damage_factor: i64,
+}
+
+/// Effective mechanics of a game, after inheritance and generation defaults.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MechanicsProfile {
+    pub generation: i64,
+    pub damage_split: String,
+    pub has_fairy: bool,
+    pub crit_multiplier: f64,
+    pub type_chart_overrides: Vec<TypeChartOverride>,
 }
 
 fn default_coverage() -> String {
     "full".to_string()
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct PokemonOverride {
     pub name_key: String,
     #[serde(default)]
@@ -71,7 +202,7 @@ pub struct PokemonOverride {
     pub locations: Vec<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct LearnsetEntry {
     pub move_name_key: String,
     pub learn_method: String,
@@ -79,7 +210,7 @@ pub struct LearnsetEntry {
     pub level: i64,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct AbilityOverrideEntry {
     pub ability_key: String,
     pub slot: i64,
@@ -87,7 +218,7 @@ pub struct AbilityOverrideEntry {
     pub is_hidden: bool,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
 pub struct MoveOverrideEntry {
     pub name_key: String,
     pub power: Option<i64>,
@@ -98,8 +229,59 @@ pub struct MoveOverrideEntry {
     pub effect_en: Option<String>,
 }
 
-#[derive(Debug, Clone, Deserialize)]
+#[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct ItemLocationEntry {
     pub name_key: String,
     pub locations: Vec<String>,
+    /// Machine item ("tm01") when the entry is a TM/HM/TR; `name_key` is then its move.
+    /// Defaults to the "TM01 - Move: place" label of the locations.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub machine: Option<String>,
+}
+
+/// A trainer battle. Trainers are listed in progression order.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainerEntry {
+    pub name: String,
+    pub trainer_class: Option<String>,
+    pub location: Option<String>,
+    #[serde(default = "default_battle_type")]
+    pub battle_type: String,
+    pub level_cap: Option<i64>,
+    #[serde(default)]
+    pub team: Vec<TrainerPokemonEntry>,
+}
+
+fn default_battle_type() -> String {
+    "single".to_string()
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainerPokemonEntry {
+    pub name_key: String,
+    pub level: i64,
+    pub ability_key: Option<String>,
+    pub item_key: Option<String>,
+    pub nature_key: Option<String>,
+    #[serde(default)]
+    pub moves: Vec<String>,
+    pub ivs: Option<StatSpread>,
+    pub evs: Option<StatSpread>,
+}
+
+/// A full set of six stat values (IVs or EVs).
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct StatSpread {
+    #[serde(default)]
+    pub hp: i64,
+    #[serde(default)]
+    pub atk: i64,
+    #[serde(default)]
+    pub def: i64,
+    #[serde(default)]
+    pub spa: i64,
+    #[serde(default)]
+    pub spd: i64,
+    #[serde(default)]
+    pub spe: i64,
 }
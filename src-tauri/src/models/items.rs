@@ -11,6 +11,11 @@ pub struct ItemSummary {
     pub effect_en: Option<String>,
     pub effect_fr: Option<String>,
     pub sprite_url: Option<String>,
+    // Texts in the language requested with `lang`
+    #[sqlx(default)]
+    pub name_localized: Option<String>,
+    #[sqlx(default)]
+    pub effect_localized: Option<String>,
 }
 
 /// Full item data for detail views (same fields, separate type for clarity).
@@ -24,4 +29,9 @@ pub struct ItemDetail {
     pub effect_en: Option<String>,
     pub effect_fr: Option<String>,
     pub sprite_url: Option<String>,
+    // Texts in the language requested with `lang`
+    #[sqlx(default)]
+    pub name_localized: Option<String>,
+    #[sqlx(default)]
+    pub effect_localized: Option<String>,
 }
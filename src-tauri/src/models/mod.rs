@@ -7,6 +7,16 @@ pub mod sync;
 pub mod natures;
 pub mod abilities;
 pub mod games;
+pub mod trainers;
+pub mod runs;
+pub mod diff;
+pub mod edits;
+pub mod species;
+pub mod breeding;
+pub mod experience;
+pub mod catching;
+pub mod forms;
+pub mod sprites;
 
 pub use pokemon::*;
 pub use moves::*;
@@ -17,3 +27,13 @@ pub use sync::*;
 pub use natures::*;
 pub use abilities::*;
 pub use games::*;
+pub use trainers::*;
+pub use runs::*;
+pub use diff::*;
+pub use edits::*;
+pub use species::*;
+pub use breeding::*;
+pub use experience::*;
+pub use catching::*;
+pub use forms::*;
+pub use sprites::*;
@@ -12,6 +12,9 @@ pub struct MoveSummary {
     pub power: Option<i64>,
     pub accuracy: Option<i64>,
     pub pp: Option<i64>,
+    // Texts in the language requested with `lang`
+    #[sqlx(default)]
+    pub name_localized: Option<String>,
 }
 
 /// Full move detail.
@@ -29,6 +32,73 @@ pub struct MoveDetail {
     pub priority: Option<i64>,
     pub effect_en: Option<String>,
     pub effect_fr: Option<String>,
+    #[sqlx(default)]
+    pub effect_chance: Option<i64>,
+    #[sqlx(default)]
+    pub target: Option<String>,
+    /// Critical hit stage bonus.
+    #[sqlx(default)]
+    pub crit_rate: Option<i64>,
+    /// HP drained (positive) or recoil (negative), in % of damage dealt.
+    #[sqlx(default)]
+    pub drain: Option<i64>,
+    /// HP healed, in % of max HP.
+    #[sqlx(default)]
+    pub healing: Option<i64>,
+    #[sqlx(default)]
+    pub min_hits: Option<i64>,
+    #[sqlx(default)]
+    pub max_hits: Option<i64>,
+    #[sqlx(default)]
+    pub ailment: Option<String>,
+    #[sqlx(default)]
+    pub ailment_chance: Option<i64>,
+    #[sqlx(default)]
+    pub flinch_chance: Option<i64>,
+    #[sqlx(default)]
+    pub stat_chance: Option<i64>,
+    /// Generation the move was introduced in.
+    #[sqlx(default)]
+    pub generation: Option<i64>,
+    #[sqlx(skip)]
+    pub stat_changes: Vec<MoveStatChange>,
+    /// "contact", "sound", "punch", ...
+    #[sqlx(skip)]
+    pub flags: Vec<String>,
+    // Texts in the language requested with `lang`
+    #[sqlx(default)]
+    pub name_localized: Option<String>,
+    #[sqlx(default)]
+    pub effect_localized: Option<String>,
+}
+
+/// Stat stage change caused by a move.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct MoveStatChange {
+    pub stat: String,
+    pub change: i64,
+}
+
+/// Criteria for querying moves; unset fields don't filter.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MoveFilter {
+    /// Partial name match.
+    pub query: Option<String>,
+    pub type_key: Option<String>,
+    pub damage_class: Option<String>,
+    pub target: Option<String>,
+    pub ailment: Option<String>,
+    /// Moves having all of these flags.
+    pub flags: Vec<String>,
+    /// Moves changing this stat.
+    pub stat: Option<String>,
+    pub min_priority: Option<i64>,
+    pub max_priority: Option<i64>,
+    pub min_power: Option<i64>,
+    pub max_power: Option<i64>,
+    pub generation: Option<i64>,
+    pub multi_hit: Option<bool>,
 }
 
 /// Pokemon that learns a specific move.
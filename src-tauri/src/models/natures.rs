@@ -10,4 +10,7 @@ pub struct NatureSummary {
     pub decreased_stat: Option<String>,
     pub likes_flavor: Option<String>,
     pub hates_flavor: Option<String>,
+    // Texts in the language requested with `lang`
+    #[sqlx(default)]
+    pub name_localized: Option<String>,
 }
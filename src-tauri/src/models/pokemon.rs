@@ -18,6 +18,9 @@ pub struct PokemonSummary {
     pub base_stat_total: Option<i64>,
     pub sprite_url: Option<String>,
     pub species_id: Option<i64>,
+    // Texts in the language requested with `lang`
+    #[sqlx(default)]
+    pub name_localized: Option<String>,
 }
 
 /// Full pokemon detail including stats, abilities, description.
@@ -43,6 +46,25 @@ pub struct PokemonDetail {
     pub height: Option<i64>,
     pub weight: Option<i64>,
     pub species_id: Option<i64>,
+    #[sqlx(default)]
+    pub base_experience: Option<i64>,
+    #[sqlx(default)]
+    pub ev_hp: Option<i64>,
+    #[sqlx(default)]
+    pub ev_atk: Option<i64>,
+    #[sqlx(default)]
+    pub ev_def: Option<i64>,
+    #[sqlx(default)]
+    pub ev_spa: Option<i64>,
+    #[sqlx(default)]
+    pub ev_spd: Option<i64>,
+    #[sqlx(default)]
+    pub ev_spe: Option<i64>,
+    // Texts in the language requested with `lang`
+    #[sqlx(default)]
+    pub name_localized: Option<String>,
+    #[sqlx(default)]
+    pub description_localized: Option<String>,
 }
 
 /// A pokemon's ability.
@@ -57,6 +79,9 @@ pub struct PokemonAbility {
     pub short_effect_fr: Option<String>,
     pub is_hidden: i64,
     pub slot: i64,
+    /// Layer a game-aware row came from (game id, version group or "global").
+    #[sqlx(default)]
+    pub source: Option<String>,
 }
 
 /// An entry in a pokemon's move learnset.
@@ -75,6 +100,12 @@ pub struct PokemonMoveEntry {
     pub power: Option<i64>,
     pub accuracy: Option<i64>,
     pub pp: Option<i64>,
+    /// Layer a game-aware row came from (game id, version group or "global").
+    #[sqlx(default)]
+    pub source: Option<String>,
+    /// Machine teaching the move in the game ("tm24"), for game-aware machine moves.
+    #[sqlx(default)]
+    pub machine: Option<String>,
 }
 
 /// A node in an evolution tree (recursive, not FromRow).
@@ -87,5 +118,106 @@ pub struct EvolutionNode {
     pub sprite_url: Option<String>,
     pub trigger: Option<String>,
     pub trigger_detail: Option<String>,
+    /// Every way of evolving into this node (empty for the root).
+    #[serde(default)]
+    pub conditions: Vec<EvolutionCondition>,
     pub evolves_to: Vec<EvolutionNode>,
 }
+
+/// One way of evolving: the trigger and every requirement PokéAPI lists for it.
+#[derive(Debug, Clone, Default, Serialize, Deserialize, sqlx::FromRow)]
+pub struct EvolutionCondition {
+    pub trigger: Option<String>,
+    pub min_level: Option<i64>,
+    pub item: Option<String>,
+    pub held_item: Option<String>,
+    pub known_move: Option<String>,
+    pub known_move_type: Option<String>,
+    pub location: Option<String>,
+    pub min_happiness: Option<i64>,
+    pub min_affection: Option<i64>,
+    pub min_beauty: Option<i64>,
+    pub time_of_day: Option<String>,
+    /// 1 = female, 2 = male.
+    pub gender: Option<i64>,
+    pub party_species: Option<String>,
+    pub party_type: Option<String>,
+    pub trade_species: Option<String>,
+    /// 1 = Atk > Def, 0 = Atk = Def, -1 = Atk < Def.
+    pub relative_physical_stats: Option<i64>,
+    pub needs_overworld_rain: bool,
+    pub turn_upside_down: bool,
+    // Localized names of the keys above, filled in at query time
+    #[sqlx(default)]
+    pub item_en: Option<String>,
+    #[sqlx(default)]
+    pub item_fr: Option<String>,
+    #[sqlx(default)]
+    pub held_item_en: Option<String>,
+    #[sqlx(default)]
+    pub held_item_fr: Option<String>,
+    #[sqlx(default)]
+    pub known_move_en: Option<String>,
+    #[sqlx(default)]
+    pub known_move_fr: Option<String>,
+    #[sqlx(default)]
+    pub known_move_type_en: Option<String>,
+    #[sqlx(default)]
+    pub known_move_type_fr: Option<String>,
+    #[sqlx(default)]
+    pub location_en: Option<String>,
+    #[sqlx(default)]
+    pub location_fr: Option<String>,
+    #[sqlx(default)]
+    pub party_species_en: Option<String>,
+    #[sqlx(default)]
+    pub party_species_fr: Option<String>,
+    #[sqlx(default)]
+    pub party_type_en: Option<String>,
+    #[sqlx(default)]
+    pub party_type_fr: Option<String>,
+    #[sqlx(default)]
+    pub trade_species_en: Option<String>,
+    #[sqlx(default)]
+    pub trade_species_fr: Option<String>,
+}
+
+/// A stored evolution step with both ends resolved, for reverse lookups.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct EvolutionEntry {
+    pub chain_id: i64,
+    pub from_species_id: i64,
+    pub from_name_key: Option<String>,
+    pub from_name_en: Option<String>,
+    pub from_name_fr: Option<String>,
+    pub from_sprite_url: Option<String>,
+    pub to_species_id: i64,
+    pub to_name_key: Option<String>,
+    pub to_name_en: Option<String>,
+    pub to_name_fr: Option<String>,
+    pub to_sprite_url: Option<String>,
+    #[sqlx(flatten)]
+    #[serde(flatten)]
+    pub condition: EvolutionCondition,
+}
+
+/// Criteria for finding evolutions; unset fields don't filter.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EvolutionFilter {
+    /// Evolutions from or into this species.
+    pub species_id: Option<i64>,
+    pub trigger: Option<String>,
+    pub item: Option<String>,
+    pub held_item: Option<String>,
+    pub known_move: Option<String>,
+    pub known_move_type: Option<String>,
+    pub location: Option<String>,
+    pub time_of_day: Option<String>,
+    pub gender: Option<i64>,
+    pub party_species: Option<String>,
+    pub trade_species: Option<String>,
+    /// Evolutions requiring (or not requiring) a minimum happiness.
+    pub needs_happiness: Option<bool>,
+    pub needs_overworld_rain: Option<bool>,
+}
@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+
+/// A Nuzlocke run with its ruleset and progress counters.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct RunSummary {
+    pub id: i64,
+    pub game_id: String,
+    pub game_name: Option<String>,
+    pub name: String,
+    pub dupes_clause: i64,
+    pub shiny_clause: i64,
+    pub level_caps: i64,
+    pub last_trainer_id: i64,
+    /// Level cap of the next trainer with a cap (only when level caps are enabled).
+    pub level_cap: Option<i64>,
+    pub visited_count: i64,
+    pub caught_count: i64,
+    pub dead_count: i64,
+    pub created_at: String,
+    pub updated_at: String,
+}
+
+/// An encounter recorded at a location during a run.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct RunEncounter {
+    pub id: i64,
+    pub run_id: i64,
+    pub location_key: String,
+    pub location_name: Option<String>,
+    pub pokemon_name_key: Option<String>,
+    pub pokemon_id: Option<i64>,
+    pub name_en: Option<String>,
+    pub name_fr: Option<String>,
+    pub sprite_url: Option<String>,
+    pub nickname: Option<String>,
+    pub status: String,
+    pub is_shiny: i64,
+    pub notes: Option<String>,
+    pub updated_at: String,
+}
+
+/// A pokemon that can be encountered at a location, checked against the run's dupes clause.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct CatchCandidate {
+    pub pokemon_name_key: String,
+    pub pokemon_id: Option<i64>,
+    pub name_en: Option<String>,
+    pub name_fr: Option<String>,
+    pub type1_key: Option<String>,
+    pub type2_key: Option<String>,
+    pub sprite_url: Option<String>,
+    pub evolution_chain_id: Option<i64>,
+    #[sqlx(default)]
+    pub is_dupe: bool,
+    #[sqlx(default)]
+    pub is_valid: bool,
+}
+
+/// Deaths of a run plus a count of encounters per status.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RunGraveyard {
+    pub run_id: i64,
+    pub dead: Vec<RunEncounter>,
+    pub caught_count: i64,
+    pub dead_count: i64,
+    pub boxed_count: i64,
+    pub failed_count: i64,
+    pub skipped_count: i64,
+}
@@ -8,6 +8,8 @@ pub struct AppSettings {
     pub lang_item_names: String,
     pub lang_descriptions: String,
     pub theme: String,
+    /// "true" to download sprites for offline use during sync.
+    pub sync_sprites: String,
 }
 
 impl Default for AppSettings {
@@ -18,6 +20,7 @@ impl Default for AppSettings {
             lang_item_names: "en".to_string(),
             lang_descriptions: "fr".to_string(),
             theme: "dark".to_string(),
+            sync_sprites: "false".to_string(),
         }
     }
 }
@@ -0,0 +1,46 @@
+use serde::{Deserialize, Serialize};
+
+/// Species-level data shared by all forms of a pokemon.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct Species {
+    pub species_id: i64,
+    pub name_key: String,
+    pub name_en: Option<String>,
+    pub name_fr: Option<String>,
+    pub genus_en: Option<String>,
+    pub genus_fr: Option<String>,
+    pub capture_rate: Option<i64>,
+    pub base_happiness: Option<i64>,
+    /// Chance of being female in eighths, -1 for genderless.
+    pub gender_rate: Option<i64>,
+    pub growth_rate: Option<String>,
+    pub hatch_counter: Option<i64>,
+    pub is_legendary: bool,
+    pub is_mythical: bool,
+    pub is_baby: bool,
+    pub generation: Option<i64>,
+    pub evolves_from_species_id: Option<i64>,
+    pub evolution_chain_id: Option<i64>,
+    #[sqlx(skip)]
+    pub egg_groups: Vec<String>,
+    // Texts in the language requested with `lang`
+    #[sqlx(default)]
+    pub name_localized: Option<String>,
+    #[sqlx(default)]
+    pub genus_localized: Option<String>,
+}
+
+/// Criteria for querying species; unset fields don't filter.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SpeciesFilter {
+    /// Partial name match.
+    pub query: Option<String>,
+    pub is_legendary: Option<bool>,
+    pub is_mythical: Option<bool>,
+    pub is_baby: Option<bool>,
+    pub egg_group: Option<String>,
+    pub growth_rate: Option<String>,
+    pub generation: Option<i64>,
+    pub genderless: Option<bool>,
+}
@@ -0,0 +1,36 @@
+use serde::{Deserialize, Serialize};
+
+/// One sprite of a pokemon, e.g. the shiny front sprite of the Emerald set.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct PokemonSpriteVariant {
+    /// "default", "official-artwork", "home", "showdown", or a game's set ("emerald", ...).
+    pub source: String,
+    /// Generation of a game's set.
+    pub generation: Option<i64>,
+    pub animated: bool,
+    /// "front_default", "front_shiny", "back_female", ...
+    pub variant: String,
+    pub url: String,
+}
+
+/// Sprites of a pokemon, with the set matching a game picked out.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PokemonSprites {
+    pub pokemon_id: i64,
+    /// Set the sprites below come from ("default" without a game or a matching set).
+    pub source: String,
+    pub generation: Option<i64>,
+    pub front_default: Option<String>,
+    pub front_shiny: Option<String>,
+    pub front_female: Option<String>,
+    pub front_shiny_female: Option<String>,
+    pub back_default: Option<String>,
+    pub back_shiny: Option<String>,
+    pub artwork: Option<String>,
+    pub artwork_shiny: Option<String>,
+    /// Animated sprites (Showdown).
+    pub animated: Option<String>,
+    pub animated_shiny: Option<String>,
+    /// Every sprite of the pokemon.
+    pub variants: Vec<PokemonSpriteVariant>,
+}
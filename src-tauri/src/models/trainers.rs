@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use super::games::StatSpread;
+
+/// A trainer battle for list views (progression order = trainer_id).
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TrainerSummary {
+    pub game_id: String,
+    pub trainer_id: i64,
+    pub name: String,
+    pub trainer_class: Option<String>,
+    pub location: Option<String>,
+    pub battle_type: String,
+    pub level_cap: Option<i64>,
+    pub team_size: i64,
+    pub max_level: Option<i64>,
+}
+
+/// A trainer with its full team.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainerDetail {
+    pub trainer: TrainerSummary,
+    pub team: Vec<TrainerPokemon>,
+}
+
+/// A pokemon on a trainer's team, joined with species, ability, item and nature details.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct TrainerPokemon {
+    pub slot: i64,
+    pub pokemon_name_key: String,
+    pub pokemon_id: Option<i64>,
+    pub name_en: Option<String>,
+    pub name_fr: Option<String>,
+    pub type1_key: Option<String>,
+    pub type2_key: Option<String>,
+    pub sprite_url: Option<String>,
+    pub level: i64,
+    pub ability_key: Option<String>,
+    pub ability_en: Option<String>,
+    pub ability_fr: Option<String>,
+    pub ability_short_effect_en: Option<String>,
+    pub ability_short_effect_fr: Option<String>,
+    pub item_key: Option<String>,
+    pub item_en: Option<String>,
+    pub item_fr: Option<String>,
+    pub item_sprite_url: Option<String>,
+    pub nature_key: Option<String>,
+    pub nature_en: Option<String>,
+    pub nature_fr: Option<String>,
+    pub ivs: Option<StatSpread>,
+    pub evs: Option<StatSpread>,
+    pub moves: Vec<TrainerMove>,
+}
+
+/// A move of a trainer's pokemon, with the game's move overrides applied.
+#[derive(Debug, Clone, Serialize, Deserialize, sqlx::FromRow)]
+pub struct TrainerMove {
+    pub slot: i64,
+    pub move_slot: i64,
+    pub move_id: Option<i64>,
+    pub name_key: String,
+    pub name_en: Option<String>,
+    pub name_fr: Option<String>,
+    pub type_key: Option<String>,
+    pub damage_class: Option<String>,
+    pub power: Option<i64>,
+    pub accuracy: Option<i64>,
+    pub pp: Option<i64>,
+    pub priority: Option<i64>,
+}
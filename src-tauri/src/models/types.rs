@@ -7,6 +7,12 @@ pub struct TypeEntry {
     pub name_key: String,
     pub name_en: Option<String>,
     pub name_fr: Option<String>,
+    /// Generation the type was introduced in.
+    #[sqlx(default)]
+    pub generation: Option<i64>,
+    // Texts in the language requested with `lang`
+    #[sqlx(default)]
+    pub name_localized: Option<String>,
 }
 
 /// Type effectiveness entry.
@@ -0,0 +1,127 @@
+use std::path::{Path, PathBuf};
+
+use tauri::http::{header, Request, Response, StatusCode};
+use tauri::Manager;
+
+use crate::cache::sprites::{get_sprite_asset, image_mime, set_sprite_hash, sprite_hash};
+use crate::AppState;
+
+/// URI scheme serving cached sprites, e.g. `pokedia-asset://pokemon/25`.
+pub const ASSET_SCHEME: &str = "pokedia-asset";
+
+/// Folder of the app data dir holding downloaded sprites, named by their SHA-256.
+const SPRITES_DIR: &str = "sprites";
+
+/// Path of the sprites folder (created if missing).
+pub fn sprites_dir(handle: &tauri::AppHandle) -> Result<PathBuf, String> {
+    let dir = handle
+        .path()
+        .app_data_dir()
+        .map_err(|e| e.to_string())?
+        .join(SPRITES_DIR);
+    std::fs::create_dir_all(&dir).map_err(|e| e.to_string())?;
+    Ok(dir)
+}
+
+/// Read a cached sprite, `None` if it is missing or doesn't match its hash.
+pub fn read_sprite(dir: &Path, hash: &str) -> Option<Vec<u8>> {
+    let bytes = std::fs::read(dir.join(hash)).ok()?;
+    if sprite_hash(&bytes) == hash {
+        Some(bytes)
+    } else {
+        None
+    }
+}
+
+/// Store a downloaded sprite and return its hash. Identical images are only stored once.
+pub fn store_sprite(dir: &Path, bytes: &[u8]) -> Result<String, String> {
+    if image_mime(bytes).is_none() {
+        return Err("Downloaded file is not an image".to_string());
+    }
+
+    let hash = sprite_hash(bytes);
+    if read_sprite(dir, &hash).is_none() {
+        // Write then rename, so an interrupted write never leaves a partial sprite
+        let tmp = dir.join(format!("{}.tmp", hash));
+        std::fs::write(&tmp, bytes).map_err(|e| e.to_string())?;
+        std::fs::rename(&tmp, dir.join(&hash)).map_err(|e| e.to_string())?;
+    }
+    Ok(hash)
+}
+
+/// Kind and id of an asset URL. The kind is the host of `pokedia-asset://pokemon/25`,
+/// or the first path segment when the webview uses `localhost` as host (`convertFileSrc`),
+/// followed by any further segments but the id (`pokemon-set/emerald/front_default/25`).
+pub fn parse_asset_path(host: Option<&str>, path: &str) -> Option<(String, i64)> {
+    let path = path.replace("%2F", "/").replace("%2f", "/");
+    let path = path.trim_matches('/');
+    let full = match host {
+        Some(host) if host != "localhost" && !host.ends_with(".localhost") => {
+            format!("{}/{}", host, path)
+        }
+        _ => path.to_string(),
+    };
+
+    let (kind, id) = full.rsplit_once('/')?;
+    Some((kind.to_string(), id.parse().ok()?))
+}
+
+/// Answer a `pokedia-asset://` request with the cached sprite, or else with the remote
+/// image (which gets cached on the way).
+pub async fn serve_asset(handle: &tauri::AppHandle, request: &Request<Vec<u8>>) -> Response<Vec<u8>> {
+    let state = match handle.try_state::<AppState>() {
+        Some(state) => state,
+        None => return error_response(StatusCode::SERVICE_UNAVAILABLE),
+    };
+    let (kind, id) = match parse_asset_path(request.uri().host(), request.uri().path()) {
+        Some(asset) => asset,
+        None => return error_response(StatusCode::BAD_REQUEST),
+    };
+    let asset = match get_sprite_asset(&state.pool, &kind, id).await {
+        Ok(Some(asset)) => asset,
+        Ok(None) => return error_response(StatusCode::NOT_FOUND),
+        Err(e) => {
+            log::warn!("Failed to look up sprite {}/{}: {}", kind, id, e);
+            return error_response(StatusCode::INTERNAL_SERVER_ERROR);
+        }
+    };
+
+    let dir = sprites_dir(handle).ok();
+    if let (Some(dir), Some(hash)) = (&dir, &asset.hash) {
+        if let Some(bytes) = read_sprite(dir, hash) {
+            return image_response(bytes);
+        }
+    }
+
+    match state.api_client.get_bytes(&asset.url).await {
+        Ok(bytes) if image_mime(&bytes).is_some() => {
+            if let Some(dir) = &dir {
+                if let Ok(hash) = store_sprite(dir, &bytes) {
+                    let _ = set_sprite_hash(&state.pool, &kind, id, &asset.url, &hash).await;
+                }
+            }
+            image_response(bytes)
+        }
+        Ok(_) => error_response(StatusCode::BAD_GATEWAY),
+        Err(e) => {
+            log::warn!("Failed to fetch sprite {}: {}", asset.url, e);
+            error_response(StatusCode::BAD_GATEWAY)
+        }
+    }
+}
+
+fn image_response(bytes: Vec<u8>) -> Response<Vec<u8>> {
+    let mime = image_mime(&bytes).unwrap_or("application/octet-stream");
+    Response::builder()
+        .header(header::CONTENT_TYPE, mime)
+        .header(header::CACHE_CONTROL, "max-age=86400")
+        .body(bytes)
+        .unwrap_or_default()
+}
+
+fn error_response(status: StatusCode) -> Response<Vec<u8>> {
+    Response::builder()
+        .status(status)
+        .body(Vec::new())
+        .unwrap_or_default()
+}
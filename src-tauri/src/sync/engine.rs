@@ -1,3 +1,4 @@
+use std::collections::HashSet;
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::Arc;
 
@@ -44,13 +45,18 @@ impl SyncEngine {
         }
     }
 
-    /// Run the full sync pipeline: types -> moves -> (pokemon + items) -> evolution chains.
+    /// Run the full sync pipeline: types -> moves -> (pokemon + items) -> evolution chains
+    /// -> (natures + abilities) -> species -> pokemon forms -> sprites (when enabled).
     /// Supports partial resume: resources with status "done" are skipped.
     pub async fn sync_all(&self) -> Result<(), String> {
         log::info!("Starting full sync...");
 
-        // Phase 1: Types
-        if self.is_resource_done("types").await {
+        // Phase 1: Types (re-synced when the per-generation charts or translations are missing)
+        let types_current = cache::types::has_type_efficacy_generations(&self.pool)
+            .await
+            .unwrap_or(false)
+            && self.has_localized_texts("type").await;
+        if self.is_resource_done("types").await && types_current {
             log::info!("Skipping types (already done)");
         } else if let Err(e) = self.sync_types().await {
             log::error!("Types sync failed: {}", e);
@@ -62,8 +68,12 @@ impl SyncEngine {
             return Ok(());
         }
 
-        // Phase 2: Moves
-        if self.is_resource_done("moves").await {
+        // Phase 2: Moves (re-synced when past values, metadata, flags or translations are missing)
+        let moves_current = cache::moves::is_move_data_current(&self.pool)
+            .await
+            .unwrap_or(false)
+            && self.has_localized_texts("move").await;
+        if self.is_resource_done("moves").await && moves_current {
             log::info!("Skipping moves (already done)");
         } else if let Err(e) = self.sync_moves().await {
             log::error!("Moves sync failed: {}", e);
@@ -75,9 +85,11 @@ impl SyncEngine {
             return Ok(());
         }
 
-        // Phase 3: Pokemon and Items in parallel
-        let pk_done = self.is_resource_done("pokemon").await;
-        let it_done = self.is_resource_done("items").await;
+        // Phase 3: Pokemon and Items in parallel (pokemon re-synced when EV yields or sprite variants are missing)
+        let pk_done = self.is_resource_done("pokemon").await
+            && cache::experience::has_ev_yields(&self.pool).await.unwrap_or(false)
+            && cache::sprites::has_pokemon_sprites(&self.pool).await.unwrap_or(false);
+        let it_done = self.is_resource_done("items").await && self.has_localized_texts("item").await;
 
         if pk_done {
             log::info!("Skipping pokemon (already done)");
@@ -134,8 +146,11 @@ impl SyncEngine {
             return Ok(());
         }
 
-        // Phase 4: Evolution chains
-        if self.is_resource_done("evolution_chains").await {
+        // Phase 4: Evolution chains (re-synced when structured conditions are missing)
+        let evolutions_current = cache::evolution::has_evolution_details(&self.pool)
+            .await
+            .unwrap_or(false);
+        if self.is_resource_done("evolution_chains").await && evolutions_current {
             log::info!("Skipping evolution chains (already done)");
         } else if let Err(e) = self.sync_evolution_chains().await {
             log::error!("Evolution chains sync failed: {}", e);
@@ -147,9 +162,9 @@ impl SyncEngine {
             return Ok(());
         }
 
-        // Phase 5: Natures + Abilities in parallel
-        let nat_done = self.is_resource_done("natures").await;
-        let abi_done = self.is_resource_done("abilities").await;
+        // Phase 5: Natures + Abilities in parallel (re-synced when translations are missing)
+        let nat_done = self.is_resource_done("natures").await && self.has_localized_texts("nature").await;
+        let abi_done = self.is_resource_done("abilities").await && self.has_localized_texts("ability").await;
 
         if nat_done {
             log::info!("Skipping natures (already done)");
@@ -201,6 +216,65 @@ impl SyncEngine {
             }
         }
 
+        if self.is_cancelled() {
+            log::info!("Sync cancelled after natures/abilities phase");
+            return Ok(());
+        }
+
+        // Phase 6: Species and growth rates (re-synced when translations are missing)
+        let species_current = cache::experience::has_growth_rates(&self.pool)
+            .await
+            .unwrap_or(false)
+            && self.has_localized_texts("species").await;
+        if self.is_resource_done("species").await && species_current {
+            log::info!("Skipping species (already done)");
+        } else if let Err(e) = self.sync_species().await {
+            log::error!("Species sync failed: {}", e);
+            self.update_sync_meta("species", 0, 0, "error", Some(&e)).await;
+        }
+
+        if self.is_cancelled() {
+            log::info!("Sync cancelled after species phase");
+            return Ok(());
+        }
+
+        // Phase 7: Pokemon forms
+        if self.is_resource_done("pokemon_forms").await {
+            log::info!("Skipping pokemon forms (already done)");
+        } else if let Err(e) = self.sync_pokemon_forms().await {
+            log::error!("Pokemon forms sync failed: {}", e);
+            self.update_sync_meta("pokemon_forms", 0, 0, "error", Some(&e)).await;
+        }
+
+        if self.is_cancelled() {
+            log::info!("Sync cancelled after pokemon forms phase");
+            return Ok(());
+        }
+
+        // Phase 8: TM/HM/TR machines
+        if self.is_resource_done("machines").await {
+            log::info!("Skipping machines (already done)");
+        } else if let Err(e) = self.sync_machines().await {
+            log::error!("Machines sync failed: {}", e);
+            self.update_sync_meta("machines", 0, 0, "error", Some(&e)).await;
+        }
+
+        if self.is_cancelled() {
+            log::info!("Sync cancelled after machines phase");
+            return Ok(());
+        }
+
+        // Phase 9: Sprites for offline use (opt-in with the sync_sprites setting), re-run while
+        // sprites registered since (new pokemon, items, game sets) aren't downloaded
+        if !self.is_sprite_sync_enabled().await {
+            log::info!("Skipping sprites (disabled)");
+        } else if self.is_resource_done("sprites").await && !self.has_pending_sprites().await {
+            log::info!("Skipping sprites (already done)");
+        } else if let Err(e) = self.sync_sprites().await {
+            log::error!("Sprites sync failed: {}", e);
+            self.update_sync_meta("sprites", 0, 0, "error", Some(&e)).await;
+        }
+
         // Validate data integrity
         self.validate_sync().await;
 
@@ -228,6 +302,7 @@ impl SyncEngine {
         self.update_sync_meta(resource, total, 0, "syncing", None).await;
 
         let mut completed: i64 = 0;
+        let mut relations = Vec::new();
 
         for entry in &list {
             if self.is_cancelled() {
@@ -246,11 +321,12 @@ impl SyncEngine {
             }).await;
 
             match result {
-                Ok((parsed_type, efficacies)) => {
+                Ok((parsed_type, efficacies, type_relations)) => {
                     let _ = cache::types::upsert_type(&self.pool, &parsed_type).await;
                     for te in &efficacies {
                         let _ = cache::types::upsert_type_efficacy(&self.pool, te).await;
                     }
+                    relations.push(type_relations);
                 }
                 Err(e) => {
                     log::warn!("Failed to fetch type {}: {}", id, e);
@@ -261,6 +337,10 @@ impl SyncEngine {
             self.update_sync_meta(resource, total, completed, "syncing", None).await;
         }
 
+        if let Err(e) = cache::types::replace_type_efficacy_generations(&self.pool, &relations).await {
+            log::warn!("Failed to build per-generation type charts: {}", e);
+        }
+
         self.update_sync_meta(resource, total, completed, "done", None).await;
         Ok(())
     }
@@ -305,6 +385,9 @@ impl SyncEngine {
             match result {
                 Ok(parsed_move) => {
                     let _ = cache::moves::upsert_move(&self.pool, &parsed_move).await;
+                    let _ = cache::moves::replace_move_past_values(
+                        &self.pool, parsed_move.id, &parsed_move.past_values,
+                    ).await;
                 }
                 Err(e) => {
                     log::warn!("Failed to fetch move {}: {}", id, e);
@@ -317,6 +400,19 @@ impl SyncEngine {
             }
         }
 
+        // Without flags, filtering moves by flag finds nothing: fail the phase so it's retried
+        let flags = self.retry(3, || async { self.client.fetch_move_flags().await }).await;
+        let stored = match flags {
+            Ok(flags) => cache::moves::replace_move_flags(&self.pool, &flags)
+                .await
+                .map_err(|e| format!("Failed to store move flags: {}", e)),
+            Err(e) => Err(format!("Failed to fetch move flags: {}", e)),
+        };
+        if let Err(msg) = stored {
+            self.update_sync_meta(resource, total, completed, "error", Some(&msg)).await;
+            return Err(msg);
+        }
+
         self.update_sync_meta(resource, total, completed, "done", None).await;
         Ok(())
     }
@@ -341,6 +437,7 @@ impl SyncEngine {
         self.update_sync_meta(resource, total, 0, "syncing", None).await;
 
         let mut completed: i64 = 0;
+        let mut version_groups: HashSet<String> = HashSet::new();
 
         for entry in &list {
             if self.is_cancelled() {
@@ -363,6 +460,7 @@ impl SyncEngine {
                 Ok(parsed) => {
                     // Upsert pokemon
                     let _ = cache::pokemon::upsert_pokemon(&self.pool, &parsed).await;
+                    let _ = cache::sprites::replace_pokemon_sprites(&self.pool, parsed.id, &parsed.sprites).await;
 
                     // Upsert abilities
                     for ability in &parsed.abilities {
@@ -375,7 +473,11 @@ impl SyncEngine {
                     }
 
                     // Store per-version-group moves in game_pokemon_moves
+                    // (the version group needs a games row first: foreign keys are enforced)
                     for vgm in &parsed.version_group_moves {
+                        if version_groups.insert(vgm.version_group.clone()) {
+                            let _ = cache::games::ensure_version_group_game(&self.pool, &vgm.version_group).await;
+                        }
                         let _ = cache::games::upsert_game_pokemon_move(
                             &self.pool,
                             &vgm.version_group,
@@ -423,8 +525,11 @@ impl SyncEngine {
     async fn register_official_games(&self) {
         let official_games = [
             ("red-blue", "Red / Blue", 100),
+            ("yellow", "Yellow", 100),
             ("gold-silver", "Gold / Silver", 101),
+            ("crystal", "Crystal", 101),
             ("ruby-sapphire", "Ruby / Sapphire", 102),
+            ("emerald", "Emerald", 102),
             ("firered-leafgreen", "FireRed / LeafGreen", 103),
             ("diamond-pearl", "Diamond / Pearl", 104),
             ("platinum", "Platinum", 105),
@@ -454,12 +559,19 @@ impl SyncEngine {
             }
 
             let _ = sqlx::query(
-                "INSERT OR IGNORE INTO games (id, name_en, name_fr, is_hackrom, sort_order, coverage)
-                 VALUES (?1, ?2, ?2, 0, ?3, 'full')"
+                "INSERT INTO games (id, name_en, name_fr, is_hackrom, sort_order, coverage, generation)
+                 VALUES (?1, ?2, ?2, 0, ?3, 'full', ?4)
+                 ON CONFLICT(id) DO UPDATE SET
+                   name_en = excluded.name_en,
+                   name_fr = excluded.name_fr,
+                   sort_order = excluded.sort_order,
+                   generation = excluded.generation,
+                   hidden = 0"
             )
             .bind(id)
             .bind(name)
             .bind(sort_order)
+            .bind(cache::mechanics::generation_for(id))
             .execute(&self.pool)
             .await;
         }
@@ -573,6 +685,31 @@ impl SyncEngine {
             }
         }
 
+        // Names of the few locations evolution conditions refer to
+        let locations = cache::evolution::missing_evolution_locations(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        for name in &locations {
+            if self.is_cancelled() {
+                self.update_sync_meta(resource, total, completed, "cancelled", None).await;
+                return Ok(());
+            }
+
+            let result = self.retry(3, || async {
+                let _permit = self.semaphore.acquire().await.unwrap();
+                self.client.fetch_location(name).await
+            }).await;
+
+            match result {
+                Ok(location) => {
+                    let _ = cache::evolution::upsert_location(&self.pool, &location).await;
+                }
+                Err(e) => {
+                    log::warn!("Failed to fetch location {}: {}", name, e);
+                }
+            }
+        }
+
         self.update_sync_meta(resource, total, completed, "done", None).await;
         Ok(())
     }
@@ -631,6 +768,190 @@ impl SyncEngine {
         Ok(())
     }
 
+    // ── Species ───────────────────────────────────────────────────────
+
+    async fn sync_species(&self) -> Result<(), String> {
+        let resource = "species";
+        self.update_sync_meta(resource, 0, 0, "syncing", None).await;
+
+        let list = match self.retry(3, || async {
+            self.client.get_resource_list("pokemon-species").await
+        }).await {
+            Ok(l) => l,
+            Err(e) => {
+                let msg = e.to_string();
+                self.update_sync_meta(resource, 0, 0, "error", Some(&msg)).await;
+                return Err(msg);
+            }
+        };
+        let total = list.len() as i64;
+        self.update_sync_meta(resource, total, 0, "syncing", None).await;
+
+        let mut completed: i64 = 0;
+
+        for entry in &list {
+            if self.is_cancelled() {
+                self.update_sync_meta(resource, total, completed, "cancelled", None).await;
+                return Ok(());
+            }
+
+            let id = match PokeApiClient::id_from_url(&entry.url) {
+                Some(id) => id,
+                None => continue,
+            };
+
+            let result = self.retry(3, || async {
+                let _permit = self.semaphore.acquire().await.unwrap();
+                self.client.fetch_species(id).await
+            }).await;
+
+            match result {
+                Ok(species) => {
+                    let _ = cache::species::upsert_species(&self.pool, &species).await;
+                }
+                Err(e) => {
+                    log::warn!("Failed to fetch species {}: {}", id, e);
+                }
+            }
+
+            completed += 1;
+            if completed % 10 == 0 || completed == total {
+                self.update_sync_meta(resource, total, completed, "syncing", None).await;
+            }
+        }
+
+        let growth_rates = self.retry(3, || async {
+            self.client.get_resource_list("growth-rate").await
+        }).await.unwrap_or_default();
+        for entry in &growth_rates {
+            let id = match PokeApiClient::id_from_url(&entry.url) {
+                Some(id) => id,
+                None => continue,
+            };
+            match self.retry(3, || async { self.client.fetch_growth_rate(id).await }).await {
+                Ok(growth_rate) => {
+                    let _ = cache::experience::replace_growth_rate(&self.pool, &growth_rate).await;
+                }
+                Err(e) => log::warn!("Failed to fetch growth rate {}: {}", id, e),
+            }
+        }
+
+        self.update_sync_meta(resource, total, completed, "done", None).await;
+        Ok(())
+    }
+
+    // ── Pokemon Forms ─────────────────────────────────────────────────
+
+    async fn sync_pokemon_forms(&self) -> Result<(), String> {
+        let resource = "pokemon_forms";
+        self.update_sync_meta(resource, 0, 0, "syncing", None).await;
+
+        let list = match self.retry(3, || async {
+            self.client.get_resource_list("pokemon-form").await
+        }).await {
+            Ok(l) => l,
+            Err(e) => {
+                let msg = e.to_string();
+                self.update_sync_meta(resource, 0, 0, "error", Some(&msg)).await;
+                return Err(msg);
+            }
+        };
+        let total = list.len() as i64;
+        self.update_sync_meta(resource, total, 0, "syncing", None).await;
+
+        let mut completed: i64 = 0;
+
+        for entry in &list {
+            if self.is_cancelled() {
+                self.update_sync_meta(resource, total, completed, "cancelled", None).await;
+                return Ok(());
+            }
+
+            let id = match PokeApiClient::id_from_url(&entry.url) {
+                Some(id) => id,
+                None => continue,
+            };
+
+            let result = self.retry(3, || async {
+                let _permit = self.semaphore.acquire().await.unwrap();
+                self.client.fetch_pokemon_form(id).await
+            }).await;
+
+            match result {
+                Ok(form) => {
+                    let _ = cache::forms::upsert_pokemon_form(&self.pool, &form).await;
+                }
+                Err(e) => {
+                    log::warn!("Failed to fetch pokemon form {}: {}", id, e);
+                }
+            }
+
+            completed += 1;
+            if completed % 20 == 0 || completed == total {
+                self.update_sync_meta(resource, total, completed, "syncing", None).await;
+            }
+        }
+
+        self.update_sync_meta(resource, total, completed, "done", None).await;
+        Ok(())
+    }
+
+    // ── Machines ─────────────────────────────────────────────────────
+
+    async fn sync_machines(&self) -> Result<(), String> {
+        let resource = "machines";
+        self.update_sync_meta(resource, 0, 0, "syncing", None).await;
+
+        let list = match self.retry(3, || async {
+            self.client.get_resource_list("machine").await
+        }).await {
+            Ok(l) => l,
+            Err(e) => {
+                let msg = e.to_string();
+                self.update_sync_meta(resource, 0, 0, "error", Some(&msg)).await;
+                return Err(msg);
+            }
+        };
+        let total = list.len() as i64;
+        self.update_sync_meta(resource, total, 0, "syncing", None).await;
+
+        let mut completed: i64 = 0;
+
+        for entry in &list {
+            if self.is_cancelled() {
+                self.update_sync_meta(resource, total, completed, "cancelled", None).await;
+                return Ok(());
+            }
+
+            let id = match PokeApiClient::id_from_url(&entry.url) {
+                Some(id) => id,
+                None => continue,
+            };
+
+            let result = self.retry(3, || async {
+                let _permit = self.semaphore.acquire().await.unwrap();
+                self.client.fetch_machine(id).await
+            }).await;
+
+            match result {
+                Ok(machine) => {
+                    let _ = cache::machines::upsert_machine(&self.pool, &machine).await;
+                }
+                Err(e) => {
+                    log::warn!("Failed to fetch machine {}: {}", id, e);
+                }
+            }
+
+            completed += 1;
+            if completed % 20 == 0 || completed == total {
+                self.update_sync_meta(resource, total, completed, "syncing", None).await;
+            }
+        }
+
+        self.update_sync_meta(resource, total, completed, "done", None).await;
+        Ok(())
+    }
+
     // ── Abilities ─────────────────────────────────────────────────────
 
     async fn sync_abilities(&self) -> Result<(), String> {
@@ -690,6 +1011,78 @@ impl SyncEngine {
         Ok(())
     }
 
+    // ── Sprites ──────────────────────────────────────────────────────
+
+    async fn sync_sprites(&self) -> Result<(), String> {
+        let resource = "sprites";
+        self.update_sync_meta(resource, 0, 0, "syncing", None).await;
+
+        let dir = crate::sprites::sprites_dir(&self.app_handle)?;
+        cache::sprites::refresh_sprite_sources(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        cache::sprites::refresh_game_sprite_sets(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        let assets = cache::sprites::get_sprite_assets(&self.pool)
+            .await
+            .map_err(|e| e.to_string())?;
+        let total = assets.len() as i64;
+        self.update_sync_meta(resource, total, 0, "syncing", None).await;
+
+        let mut completed: i64 = 0;
+
+        for asset in &assets {
+            if self.is_cancelled() {
+                self.update_sync_meta(resource, total, completed, "cancelled", None).await;
+                return Ok(());
+            }
+
+            // Cached files are checked against their hash, and re-downloaded if corrupted
+            let cached = asset
+                .hash
+                .as_deref()
+                .is_some_and(|hash| crate::sprites::read_sprite(&dir, hash).is_some());
+
+            if !cached {
+                // Reuse the file of another sprite with the same URL
+                let result = match cache::sprites::hash_for_url(&self.pool, &asset.url).await {
+                    Ok(Some(hash)) if crate::sprites::read_sprite(&dir, &hash).is_some() => Ok(hash),
+                    _ => self
+                        .retry(3, || async {
+                            let _permit = self.semaphore.acquire().await.unwrap();
+                            self.client.get_bytes(&asset.url).await
+                        })
+                        .await
+                        .and_then(|bytes| crate::sprites::store_sprite(&dir, &bytes)),
+                };
+
+                match result {
+                    Ok(hash) => {
+                        let _ = cache::sprites::set_sprite_hash(
+                            &self.pool,
+                            &asset.kind,
+                            asset.entity_id,
+                            &asset.url,
+                            &hash,
+                        ).await;
+                    }
+                    Err(e) => {
+                        log::warn!("Failed to download sprite {}/{}: {}", asset.kind, asset.entity_id, e);
+                    }
+                }
+            }
+
+            completed += 1;
+            if completed % 20 == 0 || completed == total {
+                self.update_sync_meta(resource, total, completed, "syncing", None).await;
+            }
+        }
+
+        self.update_sync_meta(resource, total, completed, "done", None).await;
+        Ok(())
+    }
+
     // ── Helpers ──────────────────────────────────────────────────────
 
     fn is_cancelled(&self) -> bool {
@@ -708,6 +1101,41 @@ impl SyncEngine {
         matches!(row, Ok(Some(status)) if status == "done")
     }
 
+    /// Whether translations of an entity have been synced.
+    async fn has_localized_texts(&self, entity: &str) -> bool {
+        cache::localization::has_localized_texts(&self.pool, entity)
+            .await
+            .unwrap_or(false)
+    }
+
+    /// Register the sprites of the current data, and tell whether some aren't downloaded.
+    async fn has_pending_sprites(&self) -> bool {
+        let registered = async {
+            cache::sprites::refresh_sprite_sources(&self.pool).await?;
+            cache::sprites::refresh_game_sprite_sets(&self.pool).await?;
+            cache::sprites::has_missing_sprites(&self.pool).await
+        };
+        match registered.await {
+            Ok(missing) => missing,
+            Err(e) => {
+                log::warn!("Failed to check sprites: {}", e);
+                true
+            }
+        }
+    }
+
+    /// Whether the user opted in to downloading sprites.
+    async fn is_sprite_sync_enabled(&self) -> bool {
+        let value: Option<String> = sqlx::query_scalar(
+            "SELECT value FROM settings WHERE key = 'sync_sprites'"
+        )
+        .fetch_optional(&self.pool)
+        .await
+        .unwrap_or(None);
+
+        value.as_deref() == Some("true")
+    }
+
     /// Validate data integrity: check row counts against sync_meta totals.
     async fn validate_sync(&self) {
         let checks = [
@@ -718,6 +1146,10 @@ impl SyncEngine {
             ("evolution_chains", "SELECT COUNT(*) FROM evolution_chains"),
             ("natures", "SELECT COUNT(*) FROM natures"),
             ("abilities", "SELECT COUNT(*) FROM abilities"),
+            ("species", "SELECT COUNT(*) FROM species"),
+            ("pokemon_forms", "SELECT COUNT(*) FROM pokemon_forms"),
+            ("machines", "SELECT COUNT(*) FROM machines"),
+            ("sprites", "SELECT COUNT(*) FROM sprite_assets WHERE hash IS NOT NULL"),
         ];
 
         for (resource, count_query) in checks {
@@ -12,6 +12,7 @@ import { useAllAbilities } from "@/hooks/use-abilities";
 import { TypeBadge } from "@/components/pokemon/TypeBadge";
 import { DamageClassIcon } from "@/components/moves/DamageClassIcon";
 import { staggerContainer, staggerItem } from "@/lib/motion";
+import { assetUrl } from "@/lib/tauri";
 import type { PokemonSummary, MoveSummary, ItemSummary, AbilitySummary } from "@/types";
 
 const MAX_PER_CATEGORY = 10;
@@ -186,7 +187,7 @@ export function SearchCrossResults({ exclude, onNavigate }: SearchCrossResultsPr
                 className="flex w-full items-center gap-2.5 rounded-lg px-2 py-1.5 text-left text-sm transition-colors hover:bg-white/8"
               >
                 <img
-                  src={p.sprite_url ?? `${spriteBase}/${p.id}.png`}
+                  src={p.sprite_url ? assetUrl("pokemon", p.id) : `${spriteBase}/${p.id}.png`}
                   alt=""
                   className="h-6 w-6 shrink-0 object-contain"
                   loading="lazy"
@@ -280,7 +281,7 @@ export function SearchCrossResults({ exclude, onNavigate }: SearchCrossResultsPr
               >
                 {i.sprite_url ? (
                   <img
-                    src={i.sprite_url}
+                    src={assetUrl("item", i.id)}
                     alt=""
                     className="h-6 w-6 shrink-0 object-contain"
                     loading="lazy"
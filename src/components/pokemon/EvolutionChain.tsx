@@ -7,8 +7,10 @@ import { useSettingsStore } from "@/stores/settings-store";
 import { useTabStore } from "@/stores/tab-store";
 import { PokemonSprite } from "@/components/ui/pokemon-sprite";
 import { staggerContainer, staggerItem } from "@/lib/motion";
+import { pokemonAssetUrl } from "@/lib/tauri";
+import { useGameSpriteSet } from "@/hooks/use-games";
 
-import type { EvolutionNode, PokemonSummary } from "@/types";
+import type { EvolutionCondition, EvolutionNode, PokemonSummary } from "@/types";
 
 interface EvolutionChainProps {
   chain: EvolutionNode | null;
@@ -96,6 +98,52 @@ function formatTrigger(trigger: string | null, detail: string | null): string |
   }
 }
 
+type NamePicker = (nameEn: string | null, nameFr: string | null) => string;
+
+interface ConditionNames {
+  pokemon: NamePicker;
+  move: NamePicker;
+  item: NamePicker;
+  text: NamePicker;
+}
+
+/** Build a label from a structured condition, with names in the chosen languages. */
+function formatCondition(c: EvolutionCondition, names: ConditionNames): string | null {
+  const pick = (picker: NamePicker, en: string | null, fr: string | null, key: string) =>
+    picker(en, fr) || key.replace(/-/g, " ");
+  const parts: string[] = [];
+
+  if (c.min_level !== null) parts.push(`Level ${c.min_level}`);
+  if (c.item) parts.push(pick(names.item, c.item_en, c.item_fr, c.item));
+  if (c.held_item) parts.push(`Hold ${pick(names.item, c.held_item_en, c.held_item_fr, c.held_item)}`);
+  if (c.known_move) parts.push(`Know ${pick(names.move, c.known_move_en, c.known_move_fr, c.known_move)}`);
+  if (c.known_move_type) {
+    parts.push(`Know a ${pick(names.text, c.known_move_type_en, c.known_move_type_fr, c.known_move_type)} move`);
+  }
+  if (c.min_happiness !== null) parts.push("Friendship");
+  if (c.min_affection !== null) parts.push("Affection");
+  if (c.min_beauty !== null) parts.push("Beauty");
+  if (c.time_of_day) parts.push(`at ${c.time_of_day}`);
+  if (c.gender !== null) parts.push(c.gender === 1 ? "♀" : "♂");
+  if (c.location) parts.push(`at ${pick(names.text, c.location_en, c.location_fr, c.location)}`);
+  if (c.party_species) {
+    parts.push(`with ${pick(names.pokemon, c.party_species_en, c.party_species_fr, c.party_species)}`);
+  }
+  if (c.party_type) {
+    parts.push(`with a ${pick(names.text, c.party_type_en, c.party_type_fr, c.party_type)} ally`);
+  }
+  if (c.trade_species) {
+    parts.push(`for ${pick(names.pokemon, c.trade_species_en, c.trade_species_fr, c.trade_species)}`);
+  }
+  if (c.relative_physical_stats !== null) {
+    parts.push(c.relative_physical_stats > 0 ? "Atk > Def" : c.relative_physical_stats < 0 ? "Atk < Def" : "Atk = Def");
+  }
+  if (c.needs_overworld_rain) parts.push("in rain");
+  if (c.turn_upside_down) parts.push("upside down");
+
+  return formatTrigger(c.trigger, parts.length > 0 ? parts.join(", ") : null);
+}
+
 function NodeCard({
   node,
   currentId,
@@ -105,6 +153,7 @@ function NodeCard({
 }) {
   const { pokemonName } = useSettingsStore();
   const { openTab } = useTabStore();
+  const { data: spriteSet } = useGameSpriteSet();
   const isCurrent = node.pokemon_id !== null && node.pokemon_id === currentId;
   const name = pokemonName(node.name_en, node.name_fr);
   const linkTo = node.pokemon_id !== null ? `/pokemon/${node.pokemon_id}` : "#";
@@ -124,7 +173,7 @@ function NodeCard({
         )}
       >
         <PokemonSprite
-          src={node.sprite_url}
+          src={node.sprite_url && node.pokemon_id ? pokemonAssetUrl(node.pokemon_id, spriteSet) : node.sprite_url}
           pokemonId={node.pokemon_id ?? undefined}
           alt={name}
           className="h-14 w-14 hover:-translate-y-1 hover:scale-110 transition-transform"
@@ -144,6 +193,7 @@ function FormCard({
 }) {
   const { pokemonName } = useSettingsStore();
   const { openTab } = useTabStore();
+  const { data: spriteSet } = useGameSpriteSet();
   const isCurrent = form.id === currentId;
   const name = pokemonName(form.name_en, form.name_fr);
 
@@ -162,7 +212,7 @@ function FormCard({
         )}
       >
         <PokemonSprite
-          src={form.sprite_url}
+          src={form.sprite_url && pokemonAssetUrl(form.id, spriteSet)}
           pokemonId={form.id}
           alt={name}
           className="h-14 w-14 hover:-translate-y-1 hover:scale-110 transition-transform"
@@ -173,8 +223,12 @@ function FormCard({
   );
 }
 
-function ArrowWithLabel({ trigger, detail }: { trigger: string | null; detail: string | null }) {
-  const label = formatTrigger(trigger, detail);
+function ArrowWithLabel({ node }: { node: EvolutionNode }) {
+  const { pokemonName, moveName, itemName, description } = useSettingsStore();
+  const condition = node.conditions[0];
+  const label = condition
+    ? formatCondition(condition, { pokemon: pokemonName, move: moveName, item: itemName, text: description })
+    : formatTrigger(node.trigger, node.trigger_detail);
   return (
     <motion.div variants={staggerItem} className="flex flex-col items-center justify-center gap-0.5 px-1">
       <svg width="32" height="16" viewBox="0 0 32 16" className="text-muted-foreground">
@@ -250,7 +304,7 @@ function BranchNode({
     return (
       <div className="flex items-center gap-1">
         <NodeCard node={node} currentId={currentId} />
-        <ArrowWithLabel trigger={child.trigger} detail={child.trigger_detail} />
+        <ArrowWithLabel node={child} />
         <BranchNode node={child} currentId={currentId} formsBySpecies={formsBySpecies} />
       </div>
     );
@@ -274,7 +328,7 @@ function BranchNode({
       <div className="grid grid-cols-[auto_1fr] items-center gap-x-1 gap-y-2">
         {children.map((child, idx) => (
           <Fragment key={child.pokemon_id ?? `evo-${idx}`}>
-            <ArrowWithLabel trigger={child.trigger} detail={child.trigger_detail} />
+            <ArrowWithLabel node={child} />
             <BranchNode node={child} currentId={currentId} formsBySpecies={formsBySpecies} />
           </Fragment>
         ))}
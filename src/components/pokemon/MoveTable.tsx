@@ -18,6 +18,15 @@ const TABS = [
   { key: "egg", label: "Egg" },
 ] as const;
 
+const MACHINE_KINDS = ["tm", "hm", "tr"];
+
+/** Sort key of a machine item: TMs, then HMs, then TRs, by number. */
+function machineOrder(item: string): [number, number] {
+  const kind = MACHINE_KINDS.indexOf(item.slice(0, 2));
+  const number = parseInt(item.slice(2), 10);
+  return [kind === -1 ? MACHINE_KINDS.length : kind, Number.isNaN(number) ? Infinity : number];
+}
+
 export function MoveTable({ moves }: MoveTableProps) {
   const { moveName } = useSettingsStore();
   const { openTab } = useTabStore();
@@ -36,6 +45,15 @@ export function MoveTable({ moves }: MoveTableProps) {
       return subset.sort((a, b) => a.level_learned_at - b.level_learned_at);
     }
 
+    if (tab === "machine" && subset.some((m) => m.machine)) {
+      return subset.sort((a, b) => {
+        if (!a.machine || !b.machine) return a.machine ? -1 : b.machine ? 1 : 0;
+        const [kindA, numA] = machineOrder(a.machine);
+        const [kindB, numB] = machineOrder(b.machine);
+        return kindA - kindB || numA - numB;
+      });
+    }
+
     return subset.sort((a, b) => {
       const nameA = moveName(a.name_en, a.name_fr);
       const nameB = moveName(b.name_en, b.name_fr);
@@ -43,6 +61,9 @@ export function MoveTable({ moves }: MoveTableProps) {
     });
   }, [moves, tab, moveName]);
 
+  // Machine numbers are only known for the selected game
+  const showMachine = tab === "machine" && filteredMoves.some((m) => m.machine);
+
   if (availableTabs.length === 0) {
     return (
       <p className="py-4 text-center text-sm text-muted-foreground">
@@ -87,6 +108,9 @@ export function MoveTable({ moves }: MoveTableProps) {
               {tab === "level-up" && (
                 <th scope="col" className="w-12 px-4 py-3 text-left">Lv.</th>
               )}
+              {showMachine && (
+                <th scope="col" className="w-16 px-4 py-3 text-left">TM</th>
+              )}
               <th scope="col" className="px-4 py-3 text-left">Move</th>
               <th scope="col" className="w-20 px-4 py-3 text-left">Type</th>
               <th scope="col" className="w-12 px-4 py-3 text-center">Cat.</th>
@@ -116,6 +140,11 @@ export function MoveTable({ moves }: MoveTableProps) {
                       {m.level_learned_at || "\u2014"}
                     </td>
                   )}
+                  {showMachine && (
+                    <td className="px-4 py-3 font-mono text-muted-foreground">
+                      {m.machine?.toUpperCase() ?? "\u2014"}
+                    </td>
+                  )}
                   <td className="px-4 py-3 font-heading font-medium">
                     <Link
                       to={`/moves/${m.move_id}`}
@@ -154,7 +183,7 @@ export function MoveTable({ moves }: MoveTableProps) {
               {filteredMoves.length === 0 && (
                 <tr>
                   <td
-                    colSpan={tab === "level-up" ? 7 : 6}
+                    colSpan={tab === "level-up" || showMachine ? 7 : 6}
                     className="px-2 py-4 text-center text-muted-foreground"
                   >
                     No moves found.
@@ -11,6 +11,8 @@ import { useTabStore } from "@/stores/tab-store";
 import { useIsFavorite, useToggleFavorite } from "@/hooks/use-favorites";
 import { getBaseId, getFormLabel } from "@/lib/pokemon-utils";
 import { TYPE_COLORS } from "@/lib/constants";
+import { pokemonAssetUrl } from "@/lib/tauri";
+import { useGameSpriteSet } from "@/hooks/use-games";
 import type { PokemonSummary } from "@/types";
 import { memo, useCallback, useRef } from "react";
 
@@ -24,6 +26,7 @@ export const PokemonCard = memo(function PokemonCard({ pokemon, nameToIdMap }: P
   const { pokemonName } = useSettingsStore();
   const { addPokemon, removePokemon, hasPokemon } = useComparisonStore();
   const { openTab } = useTabStore();
+  const { data: spriteSet } = useGameSpriteSet();
   const isCompared = hasPokemon(pokemon.id);
   const isFavorite = useIsFavorite(pokemon.id);
   const { mutate: toggleFav } = useToggleFavorite();
@@ -132,7 +135,7 @@ export const PokemonCard = memo(function PokemonCard({ pokemon, nameToIdMap }: P
             {/* Sprite */}
             <div className="h-20 w-20 transition-transform duration-200 ease-out group-hover:-translate-y-2">
               <PokemonSprite
-                src={pokemon.sprite_url}
+                src={pokemon.sprite_url && pokemonAssetUrl(pokemon.id, spriteSet)}
                 pokemonId={pokemon.id}
                 alt={name}
                 className="h-20 w-20"
@@ -4,7 +4,7 @@ import { getAllAbilities, getAbilityById, searchAbilities, getAbilityPokemon } f
 export function useAllAbilities() {
   return useQuery({
     queryKey: ["abilities", "all"],
-    queryFn: getAllAbilities,
+    queryFn: () => getAllAbilities(),
     staleTime: Infinity,
   });
 }
@@ -0,0 +1,9 @@
+import { useQuery } from "@tanstack/react-query";
+import { getSettings } from "@/lib/tauri";
+
+export function useAppSettings() {
+  return useQuery({
+    queryKey: ["settings"],
+    queryFn: getSettings,
+  });
+}
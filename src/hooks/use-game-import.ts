@@ -4,7 +4,7 @@ import { listen } from "@tauri-apps/api/event";
 import { toast } from "sonner";
 
 interface GameImportPayload {
-  status: "importing" | "done";
+  status: "importing" | "done" | "orphaned";
   game?: string;
   current?: number;
   total?: number;
@@ -13,8 +13,9 @@ interface GameImportPayload {
 
 /**
  * Listens to Tauri `game-import-progress` events emitted during
- * background auto-import of bundled hackrom data.
- * Shows a toast during import and invalidates the games query on completion.
+ * background auto-import of bundled hackrom data and user game files.
+ * Shows a toast during import and invalidates the games query on completion
+ * or when a user game file is removed (the game is unlisted, its runs kept).
  */
 export function useGameImport() {
   const queryClient = useQueryClient();
@@ -28,7 +29,7 @@ export function useGameImport() {
       if (status === "importing" && game) {
         toastId = toast.loading(`Importing ${game}...`, {
           id: toastId,
-          description: "First-run game data import",
+          description: "Game data import",
         });
       }
 
@@ -41,6 +42,13 @@ export function useGameImport() {
         });
         queryClient.invalidateQueries({ queryKey: ["games"] });
       }
+
+      if (status === "orphaned") {
+        toast.info("Game file removed", {
+          description: `${game} is hidden until its file is back; its runs and edits are kept.`,
+        });
+        queryClient.invalidateQueries({ queryKey: ["games"] });
+      }
     });
 
     return () => {
@@ -7,6 +7,10 @@ import {
   getGamePokemonLocations,
   getGameMoveOverride,
   getGameItemLocations,
+  getGameMachines,
+  getGameSpriteSet,
+  getGameLocations,
+  getLocationContents,
 } from "@/lib/tauri";
 import { useSettingsStore } from "@/stores/settings-store";
 
@@ -83,3 +87,44 @@ export function useGameItemLocations(nameKey: string | undefined | null) {
     staleTime: Infinity,
   });
 }
+
+export function useGameMachines() {
+  const selectedGameId = useSettingsStore((s) => s.selectedGameId);
+  return useQuery({
+    queryKey: ["game-machines", selectedGameId],
+    queryFn: () => getGameMachines(selectedGameId!),
+    enabled: selectedGameId !== null,
+    staleTime: Infinity,
+  });
+}
+
+/** Sprite set of the selected game as a whole (e.g. "emerald"), for lists of pokemon. */
+export function useGameSpriteSet() {
+  const selectedGameId = useSettingsStore((s) => s.selectedGameId);
+  return useQuery({
+    queryKey: ["game-sprite-set", selectedGameId],
+    queryFn: () => getGameSpriteSet(selectedGameId!),
+    enabled: selectedGameId !== null,
+    staleTime: Infinity,
+  });
+}
+
+export function useGameLocations() {
+  const selectedGameId = useSettingsStore((s) => s.selectedGameId);
+  return useQuery({
+    queryKey: ["game-locations", selectedGameId],
+    queryFn: () => getGameLocations(selectedGameId!),
+    enabled: selectedGameId !== null,
+    staleTime: Infinity,
+  });
+}
+
+export function useLocationContents(location: string | undefined | null) {
+  const selectedGameId = useSettingsStore((s) => s.selectedGameId);
+  return useQuery({
+    queryKey: ["location-contents", selectedGameId, location],
+    queryFn: () => getLocationContents(selectedGameId!, location!),
+    enabled: selectedGameId !== null && !!location,
+    staleTime: Infinity,
+  });
+}
@@ -4,7 +4,7 @@ import { getAllItems, getItemById, searchItems } from "@/lib/tauri";
 export function useAllItems() {
   return useQuery({
     queryKey: ["items", "all"],
-    queryFn: getAllItems,
+    queryFn: () => getAllItems(),
     staleTime: Infinity,
   });
 }
@@ -4,15 +4,15 @@ import { getAllMoves, getMoveById, searchMoves, getPokemonMoves, getMovePokemon
 export function useAllMoves() {
   return useQuery({
     queryKey: ["moves", "all"],
-    queryFn: getAllMoves,
+    queryFn: () => getAllMoves(),
     staleTime: Infinity,
   });
 }
 
-export function useMoveById(id: number | null) {
+export function useMoveById(id: number | null, gameId?: string | null) {
   return useQuery({
-    queryKey: ["moves", id],
-    queryFn: () => getMoveById(id!),
+    queryKey: ["moves", id, gameId ?? null],
+    queryFn: () => getMoveById(id!, gameId ?? undefined),
     enabled: id !== null,
     staleTime: Infinity,
   });
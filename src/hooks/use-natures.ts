@@ -4,7 +4,7 @@ import { getAllNatures } from "@/lib/tauri";
 export function useAllNatures() {
   return useQuery({
     queryKey: ["natures", "all"],
-    queryFn: getAllNatures,
+    queryFn: () => getAllNatures(),
     staleTime: Infinity,
   });
 }
@@ -6,12 +6,13 @@ import {
   getPokemonEvolutionChain,
   getPokemonMoves,
   getAlternateForms,
+  getPokemonSprites,
 } from "@/lib/tauri";
 
 export function useAllPokemon() {
   return useQuery({
     queryKey: ["pokemon", "all"],
-    queryFn: getAllPokemon,
+    queryFn: () => getAllPokemon(),
     staleTime: Infinity,
   });
 }
@@ -52,6 +53,16 @@ export function useAlternateForms(chainId: number | null) {
   });
 }
 
+/** Sprites of a pokemon, from the selected game's sprite set when there is one. */
+export function usePokemonSprites(pokemonId: number | null, gameId: string | null) {
+  return useQuery({
+    queryKey: ["pokemon", "sprites", pokemonId, gameId],
+    queryFn: () => getPokemonSprites(pokemonId!, gameId ?? undefined),
+    enabled: pokemonId !== null,
+    staleTime: Infinity,
+  });
+}
+
 export function usePokemonMovesList(pokemonId: number | null) {
   return useQuery({
     queryKey: ["pokemon-moves", pokemonId],
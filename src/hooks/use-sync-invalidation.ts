@@ -13,16 +13,19 @@ interface SyncProgressPayload {
 
 /** Maps backend resource names to TanStack Query key prefixes. */
 const RESOURCE_QUERY_KEYS: Record<string, string[][]> = {
-  pokemon: [["pokemon"]],
+  pokemon: [["pokemon"], ["game-sprite-set"]],
   moves: [["moves"]],
   items: [["items"]],
   types: [["types"]],
   evolution_chains: [["pokemon-evolution"]],
   natures: [["natures"]],
   abilities: [["abilities"]],
+  species: [["species"]],
+  pokemon_forms: [["pokemon"]],
+  machines: [["game-machines"], ["game-pokemon-moves"]],
 };
 
-const ALL_RESOURCES = ["types", "moves", "pokemon", "items", "evolution_chains", "natures", "abilities"];
+const ALL_RESOURCES = ["types", "moves", "pokemon", "items", "evolution_chains", "natures", "abilities", "species", "pokemon_forms", "machines"];
 
 const RESOURCE_LABELS: Record<string, string> = {
   types: "Types",
@@ -32,6 +35,10 @@ const RESOURCE_LABELS: Record<string, string> = {
   evolution_chains: "Evolution Chains",
   natures: "Natures",
   abilities: "Abilities",
+  species: "Species",
+  pokemon_forms: "Pokemon Forms",
+  machines: "Machines",
+  sprites: "Sprites",
 };
 
 /**
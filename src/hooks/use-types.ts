@@ -4,7 +4,7 @@ import { getAllTypes, getTypeEfficacy } from "@/lib/tauri";
 export function useAllTypes() {
   return useQuery({
     queryKey: ["types", "all"],
-    queryFn: getAllTypes,
+    queryFn: () => getAllTypes(),
     staleTime: Infinity,
   });
 }
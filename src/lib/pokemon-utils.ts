@@ -170,6 +170,7 @@ function mapNode(
     sprite_url: form.sprite_url,
     trigger: node.trigger,
     trigger_detail: node.trigger_detail,
+    conditions: node.conditions,
     evolves_to: mappedChildren,
   };
 }
@@ -1,12 +1,17 @@
-import { invoke } from "@tauri-apps/api/core";
+import { convertFileSrc, invoke } from "@tauri-apps/api/core";
 import type {
   PokemonSummary,
   PokemonDetail,
   PokemonAbility,
   PokemonMoveEntry,
   EvolutionNode,
+  EvolutionEntry,
+  EvolutionFilter,
+  PokemonForm,
+  FormFilter,
   MoveSummary,
   MoveDetail,
+  MoveFilter,
   MovePokemonEntry,
   ItemSummary,
   ItemDetail,
@@ -19,13 +24,55 @@ import type {
   AbilityDetail,
   AbilityPokemonEntry,
   GameSummary,
+  GameSources,
+  MechanicsProfile,
   GameMoveOverride,
+  GameMachine,
+  GameLocation,
+  LocationContents,
+  TrainerSummary,
+  TrainerDetail,
+  RunSummary,
+  RunEncounter,
+  CatchCandidate,
+  RunGraveyard,
+  EncounterStatus,
+  GameDiff,
+  PokemonDiff,
+  GameEdit,
+  LearnsetEntry,
+  AbilityOverrideEntry,
+  MoveOverrideEntry,
+  Species,
+  SpeciesFilter,
+  BreedingCompatibility,
+  BreedingChain,
+  ExperienceRange,
+  ExpGainParams,
+  ExpGain,
+  EvStat,
+  EvTrainingTarget,
+  CatchChanceParams,
+  CatchChance,
+  SpriteKind,
+  PokemonSprites,
 } from "@/types";
 
 // Settings
 export const getSettings = () => invoke<AppSettings>("get_settings");
 export const setSetting = (key: string, value: string) =>
   invoke<void>("set_setting", { key, value });
+export const getAvailableLanguages = () => invoke<string[]>("get_available_languages");
+
+// Sprites
+/** URL of a sprite served from the offline cache (or from PokéAPI when not cached). */
+export const assetUrl = (kind: SpriteKind, id: number) =>
+  convertFileSrc(`${kind}/${id}`, "pokedia-asset");
+/** Sprite of a pokemon from a game's sprite set ("emerald"), or its artwork without one. */
+export const pokemonAssetUrl = (pokemonId: number, spriteSet?: string | null, variant = "front_default") =>
+  spriteSet && spriteSet !== "default"
+    ? assetUrl(`pokemon-set/${spriteSet}/${variant}`, pokemonId)
+    : assetUrl("pokemon", pokemonId);
 
 // Sync
 export const startSync = () => invoke<void>("start_sync");
@@ -34,49 +81,91 @@ export const cancelSync = () => invoke<void>("cancel_sync");
 export const clearCache = () => invoke<void>("clear_cache");
 
 // Pokemon
-export const getAllPokemon = () => invoke<PokemonSummary[]>("get_all_pokemon");
-export const getPokemonById = (id: number) =>
-  invoke<PokemonDetail | null>("get_pokemon_by_id", { id });
-export const searchPokemon = (query: string) =>
-  invoke<PokemonSummary[]>("search_pokemon", { query });
+export const getAllPokemon = (forms?: FormFilter, lang?: string) =>
+  invoke<PokemonSummary[]>("get_all_pokemon", { forms, lang });
+export const getPokemonById = (id: number, lang?: string) =>
+  invoke<PokemonDetail | null>("get_pokemon_by_id", { id, lang });
+export const searchPokemon = (query: string, forms?: FormFilter, lang?: string) =>
+  invoke<PokemonSummary[]>("search_pokemon", { query, forms, lang });
 export const getPokemonAbilities = (pokemonId: number) =>
   invoke<PokemonAbility[]>("get_pokemon_abilities", { pokemonId });
 export const getPokemonEvolutionChain = (pokemonId: number) =>
   invoke<EvolutionNode | null>("get_pokemon_evolution_chain", { pokemonId });
+export const findEvolutions = (filter: EvolutionFilter) =>
+  invoke<EvolutionEntry[]>("find_evolutions", { filter });
 export const getAlternateForms = (chainId: number) =>
   invoke<PokemonSummary[]>("get_alternate_forms", { chainId });
+export const getPokemonSprites = (pokemonId: number, gameId?: string) =>
+  invoke<PokemonSprites>("get_pokemon_sprites", { pokemonId, gameId });
+export const getGameSpriteSet = (gameId: string) =>
+  invoke<string>("get_game_sprite_set", { gameId });
+
+// Species
+export const getSpecies = (filter: SpeciesFilter, lang?: string) =>
+  invoke<Species[]>("get_species", { filter, lang });
+export const getSpeciesById = (speciesId: number, lang?: string) =>
+  invoke<Species | null>("get_species_by_id", { speciesId, lang });
+export const getSpeciesForms = (speciesId: number) =>
+  invoke<PokemonForm[]>("get_species_forms", { speciesId });
+
+// Breeding
+export const checkBreedingCompatibility = (pokemonA: string, pokemonB: string) =>
+  invoke<BreedingCompatibility>("check_breeding_compatibility", { pokemonA, pokemonB });
+export const getGameEggMoves = (gameId: string, pokemonNameKey: string) =>
+  invoke<PokemonMoveEntry[]>("get_game_egg_moves", { gameId, pokemonNameKey });
+export const findBreedingChain = (gameId: string, pokemonNameKey: string, moveNameKey: string) =>
+  invoke<BreedingChain | null>("find_breeding_chain", { gameId, pokemonNameKey, moveNameKey });
+
+// Experience
+export const getExperienceBetweenLevels = (pokemonNameKey: string, fromLevel: number, toLevel: number) =>
+  invoke<ExperienceRange>("get_experience_between_levels", { pokemonNameKey, fromLevel, toLevel });
+export const calculateExpGain = (params: ExpGainParams) =>
+  invoke<ExpGain>("calculate_exp_gain", { params });
+export const getEvTrainingTargets = (gameId: string, stat: EvStat) =>
+  invoke<EvTrainingTarget[]>("get_ev_training_targets", { gameId, stat });
+
+// Catching
+export const calculateCatchChance = (params: CatchChanceParams) =>
+  invoke<CatchChance>("calculate_catch_chance", { params });
 
 // Moves
-export const getAllMoves = () => invoke<MoveSummary[]>("get_all_moves");
-export const getMoveById = (id: number) =>
-  invoke<MoveDetail | null>("get_move_by_id", { id });
-export const searchMoves = (query: string) =>
-  invoke<MoveSummary[]>("search_moves", { query });
+export const getAllMoves = (lang?: string) => invoke<MoveSummary[]>("get_all_moves", { lang });
+export const getMoveById = (id: number, gameId?: string, lang?: string) =>
+  invoke<MoveDetail | null>("get_move_by_id", { id, gameId, lang });
+export const searchMoves = (query: string, lang?: string) =>
+  invoke<MoveSummary[]>("search_moves", { query, lang });
+export const filterMoves = (filter: MoveFilter, lang?: string) =>
+  invoke<MoveSummary[]>("filter_moves", { filter, lang });
 export const getPokemonMoves = (pokemonId: number) =>
   invoke<PokemonMoveEntry[]>("get_pokemon_moves", { pokemonId });
 export const getMovePokemon = (moveId: number) =>
   invoke<MovePokemonEntry[]>("get_move_pokemon", { moveId });
 
 // Items
-export const getAllItems = () => invoke<ItemSummary[]>("get_all_items");
-export const getItemById = (id: number) =>
-  invoke<ItemDetail | null>("get_item_by_id", { id });
-export const searchItems = (query: string) =>
-  invoke<ItemSummary[]>("search_items", { query });
+export const getAllItems = (lang?: string) => invoke<ItemSummary[]>("get_all_items", { lang });
+export const getItemById = (id: number, lang?: string) =>
+  invoke<ItemDetail | null>("get_item_by_id", { id, lang });
+export const searchItems = (query: string, lang?: string) =>
+  invoke<ItemSummary[]>("search_items", { query, lang });
 
 // Types
-export const getAllTypes = () => invoke<TypeEntry[]>("get_all_types");
+export const getAllTypes = (lang?: string) => invoke<TypeEntry[]>("get_all_types", { lang });
 export const getTypeEfficacy = () => invoke<TypeEfficacy[]>("get_type_efficacy");
+export const getTypeEfficacyForGeneration = (generation: number) =>
+  invoke<TypeEfficacy[]>("get_type_efficacy_for_generation", { generation });
+export const getGameTypeEfficacy = (gameId: string) =>
+  invoke<TypeEfficacy[]>("get_game_type_efficacy", { gameId });
 
 // Natures
-export const getAllNatures = () => invoke<NatureSummary[]>("get_all_natures");
+export const getAllNatures = (lang?: string) => invoke<NatureSummary[]>("get_all_natures", { lang });
 
 // Abilities
-export const getAllAbilities = () => invoke<AbilitySummary[]>("get_all_abilities");
-export const getAbilityById = (id: number) =>
-  invoke<AbilityDetail | null>("get_ability_by_id", { id });
-export const searchAbilities = (query: string) =>
-  invoke<AbilitySummary[]>("search_abilities", { query });
+export const getAllAbilities = (lang?: string) =>
+  invoke<AbilitySummary[]>("get_all_abilities", { lang });
+export const getAbilityById = (id: number, lang?: string) =>
+  invoke<AbilityDetail | null>("get_ability_by_id", { id, lang });
+export const searchAbilities = (query: string, lang?: string) =>
+  invoke<AbilitySummary[]>("search_abilities", { query, lang });
 export const getAbilityPokemon = (abilityId: number) =>
   invoke<AbilityPokemonEntry[]>("get_ability_pokemon", { abilityId });
 
@@ -87,6 +176,8 @@ export const getFavorites = () => invoke<number[]>("get_favorites");
 
 // Games
 export const getAllGames = () => invoke<GameSummary[]>("get_all_games");
+export const getGameMechanics = (gameId: string) =>
+  invoke<MechanicsProfile>("get_game_mechanics", { gameId });
 export const getGameCoverage = (gameId: string) =>
   invoke<string>("get_game_coverage", { gameId });
 export const getGamePokemonMoves = (gameId: string, pokemonNameKey: string) =>
@@ -99,5 +190,119 @@ export const getGameMoveOverride = (gameId: string, moveNameKey: string) =>
   invoke<GameMoveOverride | null>("get_game_move_override", { gameId, moveNameKey });
 export const getGameItemLocations = (gameId: string, itemNameKey: string) =>
   invoke<string[]>("get_game_item_locations", { gameId, itemNameKey });
+export const getGameMachines = (gameId: string) =>
+  invoke<GameMachine[]>("get_game_machines", { gameId });
+export const getGameLocations = (gameId: string) =>
+  invoke<GameLocation[]>("get_game_locations", { gameId });
+export const getLocationContents = (gameId: string, location: string) =>
+  invoke<LocationContents | null>("get_location_contents", { gameId, location });
 export const importGameData = (jsonData: string) =>
   invoke<string>("import_game_data", { jsonData });
+export const exportGameData = (gameId: string) =>
+  invoke<string>("export_game_data", { gameId });
+export const forceReimportGame = (gameId: string) =>
+  invoke<string>("force_reimport_game", { gameId });
+export const listGameSources = () => invoke<GameSources>("list_game_sources");
+export const removeGame = (gameId: string) => invoke<void>("remove_game", { gameId });
+export const deleteGame = (gameId: string) => invoke<void>("delete_game", { gameId });
+
+// Trainers
+export const getGameTrainers = (gameId: string) =>
+  invoke<TrainerSummary[]>("get_game_trainers", { gameId });
+export const getGameTrainer = (gameId: string, trainerId: number) =>
+  invoke<TrainerDetail | null>("get_game_trainer", { gameId, trainerId });
+
+// Runs
+export const createRun = (
+  gameId: string,
+  name: string,
+  dupesClause: boolean,
+  shinyClause: boolean,
+  levelCaps: boolean
+) =>
+  invoke<RunSummary>("create_run", { gameId, name, dupesClause, shinyClause, levelCaps });
+export const getAllRuns = () => invoke<RunSummary[]>("get_all_runs");
+export const getRun = (runId: number) =>
+  invoke<RunSummary | null>("get_run", { runId });
+export const updateRunRules = (
+  runId: number,
+  dupesClause: boolean,
+  shinyClause: boolean,
+  levelCaps: boolean
+) => invoke<void>("update_run_rules", { runId, dupesClause, shinyClause, levelCaps });
+export const setRunProgress = (runId: number, lastTrainerId: number) =>
+  invoke<void>("set_run_progress", { runId, lastTrainerId });
+export const deleteRun = (runId: number) =>
+  invoke<void>("delete_run", { runId });
+export const recordEncounter = (
+  runId: number,
+  location: string,
+  pokemonNameKey: string | null,
+  status: EncounterStatus,
+  nickname: string | null = null,
+  isShiny = false,
+  notes: string | null = null
+) =>
+  invoke<RunEncounter>("record_encounter", {
+    runId,
+    location,
+    pokemonNameKey,
+    status,
+    nickname,
+    isShiny,
+    notes,
+  });
+export const updateEncounterStatus = (
+  encounterId: number,
+  status: EncounterStatus,
+  notes: string | null = null
+) => invoke<void>("update_encounter_status", { encounterId, status, notes });
+export const deleteEncounter = (encounterId: number) =>
+  invoke<void>("delete_encounter", { encounterId });
+export const getRunEncounters = (runId: number) =>
+  invoke<RunEncounter[]>("get_run_encounters", { runId });
+export const getRunNextLocations = (runId: number) =>
+  invoke<GameLocation[]>("get_run_next_locations", { runId });
+export const getRunCatchCandidates = (runId: number, location: string) =>
+  invoke<CatchCandidate[]>("get_run_catch_candidates", { runId, location });
+export const getRunGraveyard = (runId: number) =>
+  invoke<RunGraveyard>("get_run_graveyard", { runId });
+
+// Diff
+export const diffGames = (gameA: string, gameB: string) =>
+  invoke<GameDiff>("diff_games", { gameA, gameB });
+export const diffGamePokemon = (gameA: string, gameB: string, pokemonNameKey: string) =>
+  invoke<PokemonDiff>("diff_game_pokemon", { gameA, gameB, pokemonNameKey });
+export const exportGameChangelog = (gameA: string, gameB: string) =>
+  invoke<string>("export_game_changelog", { gameA, gameB });
+
+// Edits
+export const getGameEdits = (gameId: string) =>
+  invoke<GameEdit[]>("get_game_edits", { gameId });
+export const addGameLearnsetEntry = (gameId: string, pokemonNameKey: string, entry: LearnsetEntry, author?: string) =>
+  invoke<void>("add_game_learnset_entry", { gameId, pokemonNameKey, entry, author });
+export const updateGameLearnsetEntry = (
+  gameId: string,
+  pokemonNameKey: string,
+  before: LearnsetEntry,
+  after: LearnsetEntry,
+  author?: string,
+) => invoke<void>("update_game_learnset_entry", { gameId, pokemonNameKey, before, after, author });
+export const removeGameLearnsetEntry = (gameId: string, pokemonNameKey: string, entry: LearnsetEntry, author?: string) =>
+  invoke<void>("remove_game_learnset_entry", { gameId, pokemonNameKey, entry, author });
+export const setGamePokemonAbility = (gameId: string, pokemonNameKey: string, ability: AbilityOverrideEntry, author?: string) =>
+  invoke<void>("set_game_pokemon_ability", { gameId, pokemonNameKey, ability, author });
+export const removeGamePokemonAbility = (gameId: string, pokemonNameKey: string, slot: number, author?: string) =>
+  invoke<void>("remove_game_pokemon_ability", { gameId, pokemonNameKey, slot, author });
+export const addGamePokemonLocation = (gameId: string, pokemonNameKey: string, location: string, author?: string) =>
+  invoke<void>("add_game_pokemon_location", { gameId, pokemonNameKey, location, author });
+export const removeGamePokemonLocation = (gameId: string, pokemonNameKey: string, location: string, author?: string) =>
+  invoke<void>("remove_game_pokemon_location", { gameId, pokemonNameKey, location, author });
+export const addGameItemLocation = (gameId: string, itemNameKey: string, location: string, author?: string) =>
+  invoke<void>("add_game_item_location", { gameId, itemNameKey, location, author });
+export const removeGameItemLocation = (gameId: string, itemNameKey: string, location: string, author?: string) =>
+  invoke<void>("remove_game_item_location", { gameId, itemNameKey, location, author });
+export const setGameMoveOverride = (gameId: string, moveOverride: MoveOverrideEntry, author?: string) =>
+  invoke<void>("set_game_move_override", { gameId, moveOverride, author });
+export const removeGameMoveOverride = (gameId: string, moveNameKey: string, author?: string) =>
+  invoke<void>("remove_game_move_override", { gameId, moveNameKey, author });
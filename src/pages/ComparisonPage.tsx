@@ -11,6 +11,7 @@ import { STAT_COLORS } from "@/lib/constants";
 import { dialogOverlay, dialogContent } from "@/lib/motion";
 import type { PokemonSummary } from "@/types";
 import type { PokemonTypeName } from "@/lib/constants";
+import { assetUrl } from "@/lib/tauri";
 import { Link } from "react-router-dom";
 import { motion, AnimatePresence } from "framer-motion";
 import { GlassCard, GlassModal } from "@/components/ui/liquid-glass";
@@ -167,7 +168,7 @@ export default function ComparisonPage() {
                         className="hover:scale-110 transition-transform"
                       >
                         <PokemonSprite
-                          src={p.sprite_url}
+                          src={p.sprite_url && assetUrl("pokemon", p.id)}
                           pokemonId={p.id}
                           alt={pokemonName(p.name_en, p.name_fr)}
                           className="h-14 w-14"
@@ -564,7 +565,7 @@ function PokemonPicker({ onClose }: { onClose: () => void }) {
                   className="flex w-full items-center gap-3 rounded-xl px-3 py-2 text-sm hover:bg-white/8 disabled:opacity-40 transition-colors"
                 >
                   <PokemonSprite
-                    src={p.sprite_url}
+                    src={p.sprite_url && assetUrl("pokemon", p.id)}
                     pokemonId={p.id}
                     alt={pokemonName(p.name_en, p.name_fr)}
                     className="h-8 w-8"
@@ -9,6 +9,7 @@ import { useSearchStore } from "@/stores/search-store";
 import { useSettingsStore } from "@/stores/settings-store";
 import { useTabStore } from "@/stores/tab-store";
 import { cn } from "@/lib/utils";
+import { assetUrl } from "@/lib/tauri";
 import { SearchCrossResults } from "@/components/layout/SearchCrossResults";
 import { GlassCard, GlassToolbar } from "@/components/ui/liquid-glass";
 import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
@@ -290,7 +291,7 @@ const GridItemCard = memo(function GridItemCard({ item, name, effect, onClick, o
     >
       {item.sprite_url ? (
         <img
-          src={item.sprite_url}
+          src={assetUrl("item", item.id)}
           alt=""
           loading="lazy"
           className="h-12 w-12 object-contain flex-shrink-0 mt-0.5 group-hover:scale-110 transition-transform duration-200"
@@ -399,7 +400,7 @@ function VirtualizedItemList({ items }: { items: ItemSummary[] }) {
                 <td className="px-2 py-1.5">
                   {item.sprite_url ? (
                     <img
-                      src={item.sprite_url}
+                      src={assetUrl("item", item.id)}
                       alt=""
                       className="h-10 w-10 object-contain"
                       loading="lazy"
@@ -5,6 +5,7 @@ import { useSettingsStore } from "@/stores/settings-store";
 import { useTabStore } from "@/stores/tab-store";
 import { usePageTitle } from "@/hooks/use-page-title";
 import { detailStagger, detailSection, spriteFloat } from "@/lib/motion";
+import { assetUrl } from "@/lib/tauri";
 import { ArrowLeft, ChevronLeft, ChevronRight, Package, Tag, MapPin, Gamepad2 } from "lucide-react";
 import { motion } from "framer-motion";
 import { useEffect, useCallback, useMemo } from "react";
@@ -172,7 +173,7 @@ export default function ItemDetailPage() {
         <div className="relative flex items-center justify-center">
           {item.sprite_url ? (
             <motion.img
-              src={item.sprite_url}
+              src={assetUrl("item", item.id)}
               alt={name}
               className="h-24 w-24 object-contain"
               variants={spriteFloat}
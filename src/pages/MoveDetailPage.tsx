@@ -13,6 +13,7 @@ import { ArrowLeft, ChevronLeft, ChevronRight, Swords, Gamepad2 } from "lucide-r
 import { motion, AnimatePresence } from "framer-motion";
 import { useEffect, useCallback, useMemo, useState } from "react";
 import { cn } from "@/lib/utils";
+import { assetUrl } from "@/lib/tauri";
 import { GlassCard, GlassPill } from "@/components/ui/liquid-glass";
 import type { MovePokemonEntry } from "@/types";
 
@@ -352,7 +353,7 @@ function MovePokemonSection({
               className="flex items-center gap-2.5 rounded-xl glass-flat border border-border/30 px-3 py-2.5 hover:border-primary/30 hover:shadow-warm transition-all"
             >
               <img
-                src={p.sprite_url ?? `${spriteBase}/${p.pokemon_id}.png`}
+                src={p.sprite_url ? assetUrl("pokemon", p.pokemon_id) : `${spriteBase}/${p.pokemon_id}.png`}
                 alt=""
                 className="h-8 w-8 shrink-0 object-contain"
                 loading="lazy"
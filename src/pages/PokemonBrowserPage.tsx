@@ -14,6 +14,7 @@ import { TypeBadge } from "@/components/pokemon/TypeBadge";
 import { ALL_TYPES, STAT_COLORS, TYPE_COLORS } from "@/lib/constants";
 import { cn } from "@/lib/utils";
 import { buildNameToIdMap, getBaseId, getFormLabel } from "@/lib/pokemon-utils";
+import { assetUrl } from "@/lib/tauri";
 import { SearchCrossResults } from "@/components/layout/SearchCrossResults";
 import { GlassToolbar } from "@/components/ui/liquid-glass";
 import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
@@ -524,7 +525,7 @@ const PokemonGridCard = memo(function PokemonGridCard({
         {/* Sprite — plain img, no hooks */}
         <div className="h-24 w-24 transition-transform duration-200 ease-out group-hover:-translate-y-2">
           <img
-            src={pokemon.sprite_url ?? `${SPRITE_BASE}/${pokemon.id}.png`}
+            src={pokemon.sprite_url ? assetUrl("pokemon", pokemon.id) : `${SPRITE_BASE}/${pokemon.id}.png`}
             alt={name}
             className="h-24 w-24 object-contain"
             loading="lazy"
@@ -649,7 +650,7 @@ function VirtualizedList({ pokemon, nameToIdMap, children }: { pokemon: PokemonS
                 <td className="px-1 py-1">
                   <Link to={`/pokemon/${p.id}`}>
                     <img
-                      src={p.sprite_url ?? `${spriteBase}/${p.id}.png`}
+                      src={p.sprite_url ? assetUrl("pokemon", p.id) : `${spriteBase}/${p.id}.png`}
                       alt=""
                       className="h-12 w-12 object-contain"
                       loading="lazy"
@@ -6,6 +6,7 @@ import {
   usePokemonEvolutionChain,
   usePokemonMovesList,
   useAlternateForms,
+  usePokemonSprites,
 } from "@/hooks/use-pokemon";
 import {
   useSelectedGame,
@@ -51,6 +52,7 @@ import { staggerContainer, staggerItem, spriteFloat, detailStagger, detailSectio
 
 import { GlassCard, GlassPill } from "@/components/ui/liquid-glass";
 import type { PokemonTypeName } from "@/lib/constants";
+import { assetUrl, pokemonAssetUrl } from "@/lib/tauri";
 
 export default function PokemonDetailPage() {
   const { id } = useParams<{ id: string }>();
@@ -68,6 +70,7 @@ export default function PokemonDetailPage() {
   const { pokemonName, abilityName, description } = useSettingsStore();
   const selectedGameId = useSettingsStore((s) => s.selectedGameId);
   const selectedGame = useSelectedGame();
+  const { data: sprites } = usePokemonSprites(pokemonId, selectedGameId);
 
   // Game-specific data (only fetched when a game is selected)
   const { data: gameMoves } = useGamePokemonMoves(pokemon?.name_key);
@@ -212,9 +215,15 @@ export default function PokemonDetailPage() {
 
   const spriteBase =
     "https://raw.githubusercontent.com/PokeAPI/sprites/master/sprites/pokemon";
-  const defaultSprite = pokemon.sprite_url ?? `${spriteBase}/${pokemon.id}.png`;
+  // The selected game's own sprite set (e.g. Gen 3 sprites for an Emerald hack) wins over artwork
+  const gameSprites = sprites && sprites.source !== "default" ? sprites : null;
+  // Served through the offline cache, like the artwork
+  const defaultSprite = gameSprites?.front_default
+    ? pokemonAssetUrl(pokemon.id, gameSprites.source)
+    : (pokemon.sprite_url ? assetUrl("pokemon", pokemon.id) : `${spriteBase}/${pokemon.id}.png`);
   const spriteUrl = showShiny
-    ? `${spriteBase}/shiny/${pokemon.id}.png`
+    ? (gameSprites?.front_shiny ? pokemonAssetUrl(pokemon.id, gameSprites.source, "front_shiny") : null)
+      ?? sprites?.artwork_shiny ?? `${spriteBase}/shiny/${pokemon.id}.png`
     : defaultSprite;
 
   const heightStr =
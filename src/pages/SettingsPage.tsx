@@ -1,8 +1,9 @@
 import { useState } from "react";
 import { useSettingsStore } from "@/stores/settings-store";
 import { useSyncStatus } from "@/hooks/use-sync";
+import { useAppSettings } from "@/hooks/use-app-settings";
 import { usePageTitle } from "@/hooks/use-page-title";
-import { startSync, clearCache } from "@/lib/tauri";
+import { startSync, clearCache, setSetting } from "@/lib/tauri";
 import {
   Moon,
   Sun,
@@ -22,6 +23,8 @@ import { GlassCard, GlassModal } from "@/components/ui/liquid-glass";
 import { motion, AnimatePresence } from "framer-motion";
 import { springSnappy, dialogOverlay, dialogContent } from "@/lib/motion";
 import { toast } from "sonner";
+import { useQueryClient } from "@tanstack/react-query";
+import { Switch } from "@/components/ui/switch";
 
 type Lang = "en" | "fr";
 
@@ -45,8 +48,19 @@ export default function SettingsPage() {
     setAllLangs,
   } = useSettingsStore();
   const { data: syncStatus } = useSyncStatus();
+  const { data: appSettings } = useAppSettings();
+  const queryClient = useQueryClient();
   const [showClearConfirm, setShowClearConfirm] = useState(false);
 
+  const handleSyncSpritesChange = async (checked: boolean) => {
+    try {
+      await setSetting("sync_sprites", String(checked));
+      queryClient.invalidateQueries({ queryKey: ["settings"] });
+    } catch {
+      toast.error("Failed to save setting");
+    }
+  };
+
   const handleClearCache = async () => {
     setShowClearConfirm(false);
     try {
@@ -172,6 +186,19 @@ export default function SettingsPage() {
           </div>
         )}
 
+        <label className="flex items-center justify-between gap-3 text-sm">
+          <span>
+            Download sprites for offline use
+            <span className="block text-xs text-muted-foreground">
+              Artwork, sprites and item icons are saved during the next sync.
+            </span>
+          </span>
+          <Switch
+            checked={appSettings?.sync_sprites === "true"}
+            onCheckedChange={handleSyncSpritesChange}
+          />
+        </label>
+
         {syncStatus?.is_syncing && (
           <div className="flex items-center gap-2 text-xs text-primary">
             <Loader2 className="h-3.5 w-3.5 animate-spin" />
@@ -5,6 +5,7 @@ export interface PokemonSummary {
   name_key: string;
   name_en: string | null;
   name_fr: string | null;
+  name_localized: string | null;
   type1_key: string | null;
   type2_key: string | null;
   hp: number | null;
@@ -23,6 +24,7 @@ export interface PokemonDetail {
   name_key: string;
   name_en: string | null;
   name_fr: string | null;
+  name_localized: string | null;
   type1_key: string | null;
   type2_key: string | null;
   hp: number | null;
@@ -36,9 +38,17 @@ export interface PokemonDetail {
   evolution_chain_id: number | null;
   description_en: string | null;
   description_fr: string | null;
+  description_localized: string | null;
   height: number | null;
   weight: number | null;
   species_id: number | null;
+  base_experience: number | null;
+  ev_hp: number | null;
+  ev_atk: number | null;
+  ev_def: number | null;
+  ev_spa: number | null;
+  ev_spd: number | null;
+  ev_spe: number | null;
 }
 
 export interface PokemonAbility {
@@ -51,6 +61,7 @@ export interface PokemonAbility {
   short_effect_fr: string | null;
   is_hidden: number; // 0 or 1 (SQLite integer)
   slot: number;
+  source: string | null; // game id, version group or "global" (game queries only)
 }
 
 export interface PokemonMoveEntry {
@@ -66,6 +77,8 @@ export interface PokemonMoveEntry {
   power: number | null;
   accuracy: number | null;
   pp: number | null;
+  source: string | null; // game id, version group or "global" (game queries only)
+  machine: string | null; // machine item teaching the move, e.g. "tm24" (game queries only)
 }
 
 export interface EvolutionNode {
@@ -76,9 +89,78 @@ export interface EvolutionNode {
   sprite_url: string | null;
   trigger: string | null;
   trigger_detail: string | null;
+  conditions: EvolutionCondition[];
   evolves_to: EvolutionNode[];
 }
 
+export interface EvolutionCondition {
+  trigger: string | null;
+  min_level: number | null;
+  item: string | null;
+  held_item: string | null;
+  known_move: string | null;
+  known_move_type: string | null;
+  location: string | null;
+  min_happiness: number | null;
+  min_affection: number | null;
+  min_beauty: number | null;
+  time_of_day: string | null;
+  gender: number | null; // 1 = female, 2 = male
+  party_species: string | null;
+  party_type: string | null;
+  trade_species: string | null;
+  relative_physical_stats: number | null; // 1 = Atk > Def, 0 = equal, -1 = Atk < Def
+  needs_overworld_rain: boolean;
+  turn_upside_down: boolean;
+  // Localized names of the keys above
+  item_en: string | null;
+  item_fr: string | null;
+  held_item_en: string | null;
+  held_item_fr: string | null;
+  known_move_en: string | null;
+  known_move_fr: string | null;
+  known_move_type_en: string | null;
+  known_move_type_fr: string | null;
+  location_en: string | null;
+  location_fr: string | null;
+  party_species_en: string | null;
+  party_species_fr: string | null;
+  party_type_en: string | null;
+  party_type_fr: string | null;
+  trade_species_en: string | null;
+  trade_species_fr: string | null;
+}
+
+export interface EvolutionEntry extends EvolutionCondition {
+  chain_id: number;
+  from_species_id: number;
+  from_name_key: string | null;
+  from_name_en: string | null;
+  from_name_fr: string | null;
+  from_sprite_url: string | null;
+  to_species_id: number;
+  to_name_key: string | null;
+  to_name_en: string | null;
+  to_name_fr: string | null;
+  to_sprite_url: string | null;
+}
+
+export interface EvolutionFilter {
+  species_id?: number;
+  trigger?: string;
+  item?: string;
+  held_item?: string;
+  known_move?: string;
+  known_move_type?: string;
+  location?: string;
+  time_of_day?: string;
+  gender?: number;
+  party_species?: string;
+  trade_species?: string;
+  needs_happiness?: boolean;
+  needs_overworld_rain?: boolean;
+}
+
 // ── Moves ────────────────────────────────────────────────────────
 
 export interface MoveSummary {
@@ -86,6 +168,7 @@ export interface MoveSummary {
   name_key: string;
   name_en: string | null;
   name_fr: string | null;
+  name_localized: string | null;
   type_key: string | null;
   damage_class: string | null;
   power: number | null;
@@ -98,6 +181,7 @@ export interface MoveDetail {
   name_key: string;
   name_en: string | null;
   name_fr: string | null;
+  name_localized: string | null;
   type_key: string | null;
   damage_class: string | null;
   power: number | null;
@@ -106,6 +190,42 @@ export interface MoveDetail {
   priority: number | null;
   effect_en: string | null;
   effect_fr: string | null;
+  effect_localized: string | null;
+  effect_chance: number | null;
+  target: string | null;
+  crit_rate: number | null;
+  drain: number | null;
+  healing: number | null;
+  min_hits: number | null;
+  max_hits: number | null;
+  ailment: string | null;
+  ailment_chance: number | null;
+  flinch_chance: number | null;
+  stat_chance: number | null;
+  generation: number | null;
+  stat_changes: MoveStatChange[];
+  flags: string[];
+}
+
+export interface MoveStatChange {
+  stat: string;
+  change: number;
+}
+
+export interface MoveFilter {
+  query?: string;
+  type_key?: string;
+  damage_class?: string;
+  target?: string;
+  ailment?: string;
+  flags?: string[];
+  stat?: string;
+  min_priority?: number;
+  max_priority?: number;
+  min_power?: number;
+  max_power?: number;
+  generation?: number;
+  multi_hit?: boolean;
 }
 
 export interface MovePokemonEntry {
@@ -127,9 +247,11 @@ export interface ItemSummary {
   name_key: string;
   name_en: string | null;
   name_fr: string | null;
+  name_localized: string | null;
   category: string | null;
   effect_en: string | null;
   effect_fr: string | null;
+  effect_localized: string | null;
   sprite_url: string | null;
 }
 
@@ -138,9 +260,11 @@ export interface ItemDetail {
   name_key: string;
   name_en: string | null;
   name_fr: string | null;
+  name_localized: string | null;
   category: string | null;
   effect_en: string | null;
   effect_fr: string | null;
+  effect_localized: string | null;
   sprite_url: string | null;
 }
 
@@ -151,6 +275,8 @@ export interface TypeEntry {
   name_key: string;
   name_en: string | null;
   name_fr: string | null;
+  name_localized: string | null;
+  generation: number | null;
 }
 
 export interface TypeEfficacy {
@@ -181,6 +307,7 @@ export interface NatureSummary {
   name_key: string;
   name_en: string | null;
   name_fr: string | null;
+  name_localized: string | null;
   increased_stat: string | null;
   decreased_stat: string | null;
   likes_flavor: string | null;
@@ -194,8 +321,10 @@ export interface AbilitySummary {
   name_key: string;
   name_en: string | null;
   name_fr: string | null;
+  name_localized: string | null;
   short_effect_en: string | null;
   short_effect_fr: string | null;
+  short_effect_localized: string | null;
   generation: number | null;
 }
 
@@ -204,10 +333,13 @@ export interface AbilityDetail {
   name_key: string;
   name_en: string | null;
   name_fr: string | null;
+  name_localized: string | null;
   effect_en: string | null;
   effect_fr: string | null;
+  effect_localized: string | null;
   short_effect_en: string | null;
   short_effect_fr: string | null;
+  short_effect_localized: string | null;
   generation: number | null;
 }
 
@@ -234,6 +366,37 @@ export interface GameSummary {
   is_hackrom: number; // 0 or 1
   sort_order: number;
   coverage: string; // "full" | "changes_only"
+  extends: string | null; // game id or official version inherited from
+}
+
+export interface TypeChartOverride {
+  attacking_type: string;
+  defending_type: string;
+  damage_factor: number; // percent, as in TypeEfficacy
+}
+
+export interface MechanicsProfile {
+  generation: number;
+  damage_split: "type" | "move"; // "type": damage class follows the move type (gen 1-3)
+  has_fairy: boolean;
+  crit_multiplier: number;
+  type_chart_overrides: TypeChartOverride[];
+}
+
+export interface GameSource {
+  game_id: string | null; // null when the file is invalid
+  name_en: string | null;
+  origin: "bundled" | "user" | "imported";
+  file_name: string | null; // file in the user games folder
+  content_hash: string | null;
+  imported_at: string | null;
+  up_to_date: boolean;
+  error: string | null;
+}
+
+export interface GameSources {
+  user_games_dir: string;
+  sources: GameSource[];
 }
 
 export interface GameMoveOverride {
@@ -245,6 +408,491 @@ export interface GameMoveOverride {
   pp: number | null;
   damage_class: string | null;
   effect_en: string | null;
+  source: string | null; // nearest layer overriding the move
+}
+
+export interface GameLocation {
+  game_id: string;
+  location_key: string;
+  name: string;
+  pokemon_count: number;
+  item_count: number;
+}
+
+export interface LocationPokemonEntry {
+  pokemon_name_key: string;
+  pokemon_id: number | null;
+  name_en: string | null;
+  name_fr: string | null;
+  type1_key: string | null;
+  type2_key: string | null;
+  sprite_url: string | null;
+  raw_location: string;
+}
+
+export interface LocationItemEntry {
+  item_name_key: string;
+  item_id: number | null;
+  name_en: string | null;
+  name_fr: string | null;
+  category: string | null;
+  sprite_url: string | null;
+  raw_location: string;
+}
+
+export interface LocationContents {
+  location: GameLocation;
+  pokemon: LocationPokemonEntry[];
+  items: LocationItemEntry[];
+}
+
+/** A TM/HM/TR of a game and the move it teaches. */
+export interface GameMachine {
+  item: string; // "tm01", "hm05", "tr12"
+  move_name_key: string;
+  move_id: number | null;
+  name_en: string | null;
+  name_fr: string | null;
+  type_key: string | null;
+  damage_class: string | null;
+  source: string; // layer the machine list comes from
+  locations: string[];
+}
+
+// ── Trainers ─────────────────────────────────────────────────────
+
+export interface StatSpread {
+  hp: number;
+  atk: number;
+  def: number;
+  spa: number;
+  spd: number;
+  spe: number;
+}
+
+export interface TrainerSummary {
+  game_id: string;
+  trainer_id: number; // progression order
+  name: string;
+  trainer_class: string | null;
+  location: string | null;
+  battle_type: string; // "single" | "double"
+  level_cap: number | null;
+  team_size: number;
+  max_level: number | null;
+}
+
+export interface TrainerMove {
+  slot: number;
+  move_slot: number;
+  move_id: number | null;
+  name_key: string;
+  name_en: string | null;
+  name_fr: string | null;
+  type_key: string | null;
+  damage_class: string | null;
+  power: number | null;
+  accuracy: number | null;
+  pp: number | null;
+  priority: number | null;
+}
+
+export interface TrainerPokemon {
+  slot: number;
+  pokemon_name_key: string;
+  pokemon_id: number | null;
+  name_en: string | null;
+  name_fr: string | null;
+  type1_key: string | null;
+  type2_key: string | null;
+  sprite_url: string | null;
+  level: number;
+  ability_key: string | null;
+  ability_en: string | null;
+  ability_fr: string | null;
+  ability_short_effect_en: string | null;
+  ability_short_effect_fr: string | null;
+  item_key: string | null;
+  item_en: string | null;
+  item_fr: string | null;
+  item_sprite_url: string | null;
+  nature_key: string | null;
+  nature_en: string | null;
+  nature_fr: string | null;
+  ivs: StatSpread | null;
+  evs: StatSpread | null;
+  moves: TrainerMove[];
+}
+
+export interface TrainerDetail {
+  trainer: TrainerSummary;
+  team: TrainerPokemon[];
+}
+
+// ── Runs ─────────────────────────────────────────────────────────
+
+export type EncounterStatus = "caught" | "failed" | "skipped" | "dead" | "boxed";
+
+export interface RunSummary {
+  id: number;
+  game_id: string;
+  game_name: string | null;
+  name: string;
+  dupes_clause: number;
+  shiny_clause: number;
+  level_caps: number;
+  last_trainer_id: number;
+  level_cap: number | null; // next trainer's cap when level caps are on
+  visited_count: number;
+  caught_count: number;
+  dead_count: number;
+  created_at: string;
+  updated_at: string;
+}
+
+export interface RunEncounter {
+  id: number;
+  run_id: number;
+  location_key: string;
+  location_name: string | null;
+  pokemon_name_key: string | null;
+  pokemon_id: number | null;
+  name_en: string | null;
+  name_fr: string | null;
+  sprite_url: string | null;
+  nickname: string | null;
+  status: EncounterStatus;
+  is_shiny: number;
+  notes: string | null;
+  updated_at: string;
+}
+
+export interface CatchCandidate {
+  pokemon_name_key: string;
+  pokemon_id: number | null;
+  name_en: string | null;
+  name_fr: string | null;
+  type1_key: string | null;
+  type2_key: string | null;
+  sprite_url: string | null;
+  evolution_chain_id: number | null;
+  is_dupe: boolean;
+  is_valid: boolean;
+}
+
+export interface RunGraveyard {
+  run_id: number;
+  dead: RunEncounter[];
+  caught_count: number;
+  dead_count: number;
+  boxed_count: number;
+  failed_count: number;
+  skipped_count: number;
+}
+
+// ── Diff ─────────────────────────────────────────────────────────
+
+export interface LearnsetDiffEntry {
+  move_name_key: string;
+  move_name_en: string | null;
+  move_name_fr: string | null;
+  learn_method: string;
+  level: number;
+}
+
+export interface LevelChange {
+  move_name_key: string;
+  move_name_en: string | null;
+  move_name_fr: string | null;
+  learn_method: string;
+  level_a: number;
+  level_b: number;
+}
+
+export interface AbilityChange {
+  slot: number;
+  is_hidden: number;
+  ability_a: string | null;
+  ability_a_en: string | null;
+  ability_b: string | null;
+  ability_b_en: string | null;
+}
+
+export interface PokemonDiff {
+  pokemon_name_key: string;
+  pokemon_id: number | null;
+  name_en: string | null;
+  name_fr: string | null;
+  learnset_source_a: string | null;
+  learnset_source_b: string | null;
+  ability_source_a: string | null;
+  ability_source_b: string | null;
+  added_moves: LearnsetDiffEntry[];
+  removed_moves: LearnsetDiffEntry[];
+  level_changes: LevelChange[];
+  ability_changes: AbilityChange[];
+}
+
+export interface StatChange {
+  field: string; // "type" | "damage_class" | "power" | "accuracy" | "pp" | "effect"
+  value_a: string | null;
+  value_b: string | null;
+}
+
+export interface MoveDiff {
+  move_name_key: string;
+  name_en: string | null;
+  name_fr: string | null;
+  changes: StatChange[];
+}
+
+export interface GameDiff {
+  game_a: string;
+  game_b: string;
+  pokemon: PokemonDiff[];
+  moves: MoveDiff[];
+}
+
+// ── Edits ────────────────────────────────────────────────────────
+
+export interface LearnsetEntry {
+  move_name_key: string;
+  learn_method: string;
+  level: number;
+}
+
+export interface AbilityOverrideEntry {
+  ability_key: string;
+  slot: number;
+  is_hidden: boolean;
+}
+
+export interface MoveOverrideEntry {
+  name_key: string;
+  power: number | null;
+  accuracy: number | null;
+  type_key: string | null;
+  pp: number | null;
+  damage_class: string | null;
+  effect_en: string | null;
+}
+
+export type GameEditEntity =
+  | "learnset"
+  | "ability"
+  | "pokemon_location"
+  | "move_override"
+  | "item_location";
+
+export interface GameEdit {
+  id: number;
+  game_id: string;
+  entity: GameEditEntity;
+  action: "add" | "update" | "remove";
+  target_key: string;
+  before: unknown | null;
+  after: unknown | null;
+  author: string;
+  created_at: string;
+}
+
+// ── Species ──────────────────────────────────────────────────────
+
+export interface Species {
+  species_id: number;
+  name_key: string;
+  name_en: string | null;
+  name_fr: string | null;
+  name_localized: string | null;
+  genus_en: string | null;
+  genus_fr: string | null;
+  genus_localized: string | null;
+  capture_rate: number | null;
+  base_happiness: number | null;
+  /** Chance of being female in eighths, -1 for genderless. */
+  gender_rate: number | null;
+  growth_rate: string | null;
+  hatch_counter: number | null;
+  is_legendary: boolean;
+  is_mythical: boolean;
+  is_baby: boolean;
+  generation: number | null;
+  evolves_from_species_id: number | null;
+  evolution_chain_id: number | null;
+  egg_groups: string[];
+}
+
+export interface SpeciesFilter {
+  query?: string;
+  is_legendary?: boolean;
+  is_mythical?: boolean;
+  is_baby?: boolean;
+  egg_group?: string;
+  growth_rate?: string;
+  generation?: number;
+  genderless?: boolean;
+}
+
+export type FormCategory =
+  | "default"
+  | "mega"
+  | "gigantamax"
+  | "regional"
+  | "battle"
+  | "cosmetic"
+  | "variant";
+
+export interface PokemonForm {
+  id: number;
+  name_key: string;
+  pokemon_id: number;
+  species_id: number | null;
+  form_name: string;
+  name_en: string | null;
+  name_fr: string | null;
+  form_name_en: string | null;
+  form_name_fr: string | null;
+  is_default: boolean; // default form of its pokemon, not necessarily of the species
+  is_mega: boolean;
+  is_battle_only: boolean;
+  category: FormCategory;
+  regional_tag: "alola" | "galar" | "hisui" | "paldea" | null;
+  version_group: string | null;
+  form_order: number | null;
+  sprite_url: string | null;
+}
+
+export interface FormFilter {
+  categories?: FormCategory[];
+  regional_tag?: string;
+}
+
+// ── Breeding ─────────────────────────────────────────────────────
+
+export interface BreedingCompatibility {
+  compatible: boolean;
+  shared_egg_groups: string[];
+  reason: string | null;
+}
+
+export interface BreedingStep {
+  father: string;
+  mother: string;
+  father_learn_method: string;
+}
+
+export interface BreedingChain {
+  pokemon_name_key: string;
+  move_name_key: string;
+  steps: BreedingStep[];
+}
+
+// ── Experience ───────────────────────────────────────────────────
+
+export interface ExperienceRange {
+  growth_rate: string;
+  from_level: number;
+  to_level: number;
+  from_experience: number;
+  to_experience: number;
+  needed: number;
+}
+
+export interface ExpGainParams {
+  defeated_pokemon: string;
+  defeated_level: number;
+  winner_level: number;
+  generation: number;
+  trainer_battle?: boolean;
+  participants?: number;
+  lucky_egg?: boolean;
+  traded?: boolean;
+}
+
+export interface ExpGain {
+  base_experience: number;
+  formula: "flat" | "scaled";
+  experience: number;
+}
+
+export type EvStat = "hp" | "attack" | "defense" | "special-attack" | "special-defense" | "speed";
+
+export interface EvTrainingTarget {
+  pokemon_name_key: string;
+  pokemon_id: number | null;
+  name_en: string | null;
+  name_fr: string | null;
+  sprite_url: string | null;
+  ev_yield: number;
+  total_ev_yield: number;
+  locations: string[];
+}
+
+// ── Catching ─────────────────────────────────────────────────────
+
+export interface CatchConditions {
+  is_night?: boolean;
+  in_cave?: boolean;
+  on_water?: boolean;
+  fishing?: boolean;
+  already_caught?: boolean;
+}
+
+export interface CatchChanceParams {
+  pokemon_name_key: string;
+  level: number;
+  hp_percent: number;
+  status?: "sleep" | "freeze" | "paralysis" | "poison" | "burn" | null;
+  ball: string;
+  turn?: number;
+  throws?: number;
+  generation: number;
+  conditions?: CatchConditions;
+}
+
+export interface CatchChance {
+  capture_rate: number;
+  formula: "gen3" | "gen5";
+  status_modifier: number;
+  ball_modifiers: number[];
+  per_throw: number[];
+  cumulative: number[];
+}
+
+// ── Sprites ──────────────────────────────────────────────────────
+
+/** Sprites served by the offline cache: artwork, front sprite or item sprite. */
+export type SpriteKind = "pokemon" | "pokemon-sprite" | "item" | `pokemon-set/${string}`;
+
+/** One sprite of a pokemon, e.g. the shiny front sprite of the Emerald set. */
+export interface PokemonSpriteVariant {
+  /** "default", "official-artwork", "home", "showdown", or a game's set ("emerald", ...). */
+  source: string;
+  generation: number | null;
+  animated: boolean;
+  /** "front_default", "front_shiny", "back_female", ... */
+  variant: string;
+  url: string;
+}
+
+/** Sprites of a pokemon, with the set matching a game picked out. */
+export interface PokemonSprites {
+  pokemon_id: number;
+  /** Set the sprites below come from ("default" without a game or a matching set). */
+  source: string;
+  generation: number | null;
+  front_default: string | null;
+  front_shiny: string | null;
+  front_female: string | null;
+  front_shiny_female: string | null;
+  back_default: string | null;
+  back_shiny: string | null;
+  artwork: string | null;
+  artwork_shiny: string | null;
+  /** Animated sprites (Showdown). */
+  animated: string | null;
+  animated_shiny: string | null;
+  variants: PokemonSpriteVariant[];
 }
 
 // ── Settings ─────────────────────────────────────────────────────
@@ -255,4 +903,6 @@ export interface AppSettings {
   lang_item_names: string;
   lang_descriptions: string;
   theme: string;
+  /** "true" to download sprites for offline use during sync. */
+  sync_sprites: string;
 }